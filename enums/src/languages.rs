@@ -6,6 +6,7 @@ mk_langs!(
     (Kotlin, tree_sitter_kotlin_ng),
     (Java, tree_sitter_java),
     (Rust, tree_sitter_rust),
+    (C, tree_sitter_c),
     (Cpp, tree_sitter_cpp),
     (Python, tree_sitter_python),
     (Tsx, tree_sitter_tsx),
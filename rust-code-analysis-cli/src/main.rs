@@ -18,21 +18,24 @@ use rust_code_analysis::LANG;
 
 // Structs
 use rust_code_analysis::{
-    CommentRm, CommentRmCfg, ConcurrentRunner, Count, CountCfg, Dump, DumpCfg, FilesData, Find,
-    FindCfg, Function, FunctionCfg, Metrics, MetricsCfg, OpsCfg, OpsCode, PreprocParser,
-    PreprocResults,
+    Annotations, AnnotationsCfg, ClassDecls, ClassDeclsCfg, CloneReport, CloneResults, CloneTokens,
+    CloneTokensCfg, CommentRm, CommentRmCfg, ConcurrentRunner, Count, CountCfg, Coverage,
+    Dependencies, DependenciesCfg, DependencyGraph, DependencyResults, Dump, DumpCfg, FilesData,
+    Find, FindCfg, Function, FunctionCfg, Hierarchy, HierarchyResults, Metrics, MetricsCfg,
+    MetricsOptions, OpsCfg, OpsCode, PreprocParser, PreprocResults, Rollup,
 };
 
 // Functions
 use rust_code_analysis::{
-    action, fix_includes, get_from_ext, get_function_spaces, get_ops, guess_language, preprocess,
+    action, dump_root, fix_includes, get_commented_code, get_from_ext,
+    get_function_spaces_with_options, get_functions, get_ops, guess_language, preprocess,
     read_file, read_file_with_eol, write_file,
 };
 
 // Traits
 use rust_code_analysis::ParserTrait;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Config {
     dump: bool,
     in_place: bool,
@@ -42,6 +45,9 @@ struct Config {
     language: Option<LANG>,
     function: bool,
     metrics: bool,
+    exclude_commented_code: bool,
+    expand_macros: bool,
+    commented_code: bool,
     ops: bool,
     output_format: Option<Format>,
     output: Option<PathBuf>,
@@ -51,6 +57,14 @@ struct Config {
     preproc_lock: Option<Arc<Mutex<PreprocResults>>>,
     preproc: Option<Arc<PreprocResults>>,
     count_lock: Option<Arc<Mutex<Count>>>,
+    annotations_lock: Option<Arc<Mutex<Annotations>>>,
+    annotation_tags: Vec<String>,
+    dependencies_lock: Option<Arc<Mutex<DependencyResults>>>,
+    clones_lock: Option<Arc<Mutex<CloneResults>>>,
+    classes_lock: Option<Arc<Mutex<HierarchyResults>>>,
+    hierarchy: Option<Arc<Hierarchy>>,
+    coverage: Option<Arc<Coverage>>,
+    rollup_lock: Option<Arc<Mutex<Rollup>>>,
 }
 
 fn mk_globset(elems: Vec<String>) -> GlobSet {
@@ -83,16 +97,46 @@ fn act_on_file(path: PathBuf, cfg: &Config) -> std::io::Result<()> {
     };
 
     let pr = cfg.preproc.clone();
-    if cfg.dump {
+    if let Some(results) = &cfg.classes_lock {
+        let cfg = ClassDeclsCfg {
+            path: path.clone(),
+            results: results.clone(),
+        };
+        action::<ClassDecls>(&language, source, &path, pr, cfg)
+    } else if cfg.dump {
         let cfg = DumpCfg {
             line_start: cfg.line_start,
             line_end: cfg.line_end,
         };
         action::<Dump>(&language, source, &path, pr, cfg)
     } else if cfg.metrics {
-        if let Some(output_format) = &cfg.output_format {
-            if let Some(space) = get_function_spaces(&language, source, &path, pr) {
-                output_format.dump_formats(space, path, cfg.output.as_ref(), cfg.pretty);
+        if cfg.output_format.is_some()
+            || cfg.hierarchy.is_some()
+            || cfg.coverage.is_some()
+            || cfg.rollup_lock.is_some()
+            || cfg.exclude_commented_code
+            || cfg.expand_macros
+        {
+            let options = MetricsOptions {
+                exclude_commented_code: cfg.exclude_commented_code,
+                expand_macros: cfg.expand_macros,
+            };
+            let space = get_function_spaces_with_options(&language, source, &path, pr, &options);
+            if let Some(mut space) = space {
+                if let Some(hierarchy) = &cfg.hierarchy {
+                    hierarchy.annotate(&mut space, &path);
+                }
+                if let Some(coverage) = &cfg.coverage {
+                    coverage.annotate(&mut space, &path);
+                }
+                if let Some(rollup) = &cfg.rollup_lock {
+                    rollup.lock().unwrap().add(&space);
+                }
+                if let Some(output_format) = &cfg.output_format {
+                    output_format.dump_formats(space, path, cfg.output.as_ref(), cfg.pretty);
+                } else {
+                    dump_root(&space)?;
+                }
             }
             Ok(())
         } else {
@@ -116,14 +160,20 @@ fn act_on_file(path: PathBuf, cfg: &Config) -> std::io::Result<()> {
             path,
         };
         let path = cfg.path.clone();
-        if language == LANG::Cpp {
+        if matches!(language, LANG::C | LANG::Cpp) {
             action::<CommentRm>(&LANG::Ccomment, source, &path, pr, cfg)
         } else {
             action::<CommentRm>(&language, source, &path, pr, cfg)
         }
     } else if cfg.function {
-        let cfg = FunctionCfg { path: path.clone() };
-        action::<Function>(&language, source, &path, pr, cfg)
+        if let Some(output_format) = &cfg.output_format {
+            let functions = get_functions(&language, source, &path, pr);
+            output_format.dump_formats(functions, path, cfg.output.as_ref(), cfg.pretty);
+            Ok(())
+        } else {
+            let cfg = FunctionCfg { path: path.clone() };
+            action::<Function>(&language, source, &path, pr, cfg)
+        }
     } else if !cfg.find_filter.is_empty() {
         let cfg = FindCfg {
             path: path.clone(),
@@ -140,7 +190,7 @@ fn act_on_file(path: PathBuf, cfg: &Config) -> std::io::Result<()> {
         action::<Count>(&language, source, &path, pr, cfg)
     } else if cfg.preproc_lock.is_some() {
         if let Some(language) = guess_language(&source, &path).0 {
-            if language == LANG::Cpp {
+            if matches!(language, LANG::C | LANG::Cpp) {
                 let mut results = cfg.preproc_lock.as_ref().unwrap().lock().unwrap();
                 preprocess(
                     &PreprocParser::new(source, &path, None),
@@ -150,6 +200,33 @@ fn act_on_file(path: PathBuf, cfg: &Config) -> std::io::Result<()> {
             }
         }
         Ok(())
+    } else if let Some(results) = &cfg.dependencies_lock {
+        let cfg = DependenciesCfg {
+            path: path.clone(),
+            results: results.clone(),
+        };
+        action::<Dependencies>(&language, source, &path, pr, cfg)
+    } else if let Some(results) = &cfg.clones_lock {
+        let cfg = CloneTokensCfg {
+            path: path.clone(),
+            results: results.clone(),
+        };
+        action::<CloneTokens>(&language, source, &path, pr, cfg)
+    } else if let Some(results) = &cfg.annotations_lock {
+        let cfg = AnnotationsCfg {
+            path: path.clone(),
+            tags: cfg.annotation_tags.clone(),
+            results: results.clone(),
+        };
+        action::<Annotations>(&language, source, &path, pr, cfg)
+    } else if cfg.commented_code {
+        let commented = get_commented_code(&language, source, &path, pr);
+        if let Some(output_format) = &cfg.output_format {
+            output_format.dump_formats(commented, path, cfg.output.as_ref(), cfg.pretty);
+        } else {
+            print!("{commented}");
+        }
+        Ok(())
     } else {
         Ok(())
     }
@@ -195,9 +272,42 @@ struct Opts {
     /// Count nodes of the given type: comma separated list.
     #[clap(long, short = 'C', number_of_values = 1)]
     count: Vec<String>,
+    /// Extract the TODO, FIXME, HACK and XXX annotations written in the comments.
+    #[clap(long, conflicts_with_all = ["metrics", "ops", "dependencies", "clones"])]
+    annotations: bool,
+    /// Tags of the annotations to extract: comma separated list.
+    #[clap(long, value_delimiter = ',', requires = "annotations")]
+    annotation_tags: Vec<String>,
     /// Compute different metrics.
     #[clap(long, short)]
     metrics: bool,
+    /// Compute the inheritance metrics of the classes across all the input files.
+    #[clap(long, requires = "metrics")]
+    inheritance: bool,
+    /// Compute the CRAP score of the functions using a coverage file (lcov or Cobertura XML).
+    #[clap(long, value_parser, requires = "metrics")]
+    coverage: Option<PathBuf>,
+    /// Compute the distributions of the function metrics across all the input files.
+    #[clap(long, requires = "metrics")]
+    rollup: bool,
+    /// Do not count the comments containing code as comment lines.
+    #[clap(long, requires = "metrics")]
+    exclude_commented_code: bool,
+    /// Re-parse the arguments of the macro invocations as code to count their control flow.
+    #[clap(long, requires = "metrics")]
+    expand_macros: bool,
+    /// Find the comments containing code.
+    #[clap(long, conflicts_with_all = ["metrics", "ops", "dependencies", "clones"])]
+    commented_code: bool,
+    /// Compute the dependency graph of the input files and the metrics of their packages.
+    #[clap(long, conflicts_with_all = ["metrics", "ops"])]
+    dependencies: bool,
+    /// Find the code clones across the input files.
+    #[clap(long, conflicts_with_all = ["metrics", "ops", "dependencies"])]
+    clones: bool,
+    /// Minimum number of tokens of a clone.
+    #[clap(long, default_value = "50", requires = "clones")]
+    min_tokens: usize,
     /// Retrieve all operands and operators in a code.
     #[clap(long, conflicts_with = "metrics")]
     ops: bool,
@@ -266,8 +376,52 @@ fn main() {
         Ordering::Less => (None, None),
     };
 
+    let annotations_lock = if opts.annotations {
+        Some(Arc::new(Mutex::new(Annotations::default())))
+    } else {
+        None
+    };
+
+    let dependencies_lock = if opts.dependencies {
+        Some(Arc::new(Mutex::new(DependencyResults::default())))
+    } else {
+        None
+    };
+
+    let clones_lock = if opts.clones {
+        Some(Arc::new(Mutex::new(CloneResults::default())))
+    } else {
+        None
+    };
+
+    let rollup_lock = if opts.rollup {
+        Some(Arc::new(Mutex::new(Rollup::default())))
+    } else {
+        None
+    };
+
+    let coverage = opts.coverage.as_ref().map(|path| {
+        let data = read_file(path).unwrap_or_else(|e| {
+            eprintln!(
+                "Error: Cannot read the coverage file {}: {e}",
+                path.display()
+            );
+            process::exit(1);
+        });
+        Arc::new(Coverage::new(&String::from_utf8_lossy(&data)))
+    });
+
     let output_is_dir = opts.output.as_ref().map(|p| p.is_dir()).unwrap_or(false);
-    if (opts.metrics || opts.ops) && opts.output.is_some() && !output_is_dir {
+    if (opts.metrics
+        || opts.function
+        || opts.ops
+        || opts.dependencies
+        || opts.clones
+        || opts.commented_code
+        || opts.annotations)
+        && opts.output.is_some()
+        && !output_is_dir
+    {
         eprintln!("Error: The output parameter must be a directory");
         process::exit(1);
     }
@@ -309,8 +463,11 @@ fn main() {
         language,
         function: opts.function,
         metrics: opts.metrics,
+        exclude_commented_code: opts.exclude_commented_code,
+        expand_macros: opts.expand_macros,
+        commented_code: opts.commented_code,
         ops: opts.ops,
-        output_format: opts.output_format,
+        output_format: opts.output_format.clone(),
         pretty: opts.pretty,
         output: opts.output.clone(),
         line_start: opts.line_start,
@@ -318,6 +475,47 @@ fn main() {
         preproc_lock: preproc_lock.clone(),
         preproc,
         count_lock: count_lock.clone(),
+        annotations_lock: annotations_lock.clone(),
+        annotation_tags: opts.annotation_tags.clone(),
+        dependencies_lock: dependencies_lock.clone(),
+        clones_lock: clones_lock.clone(),
+        classes_lock: None,
+        hierarchy: None,
+        coverage,
+        rollup_lock: rollup_lock.clone(),
+    };
+
+    let cfg = if opts.inheritance {
+        // Class declarations of all files are needed to build the inheritance graph
+        let classes_lock = Arc::new(Mutex::new(HierarchyResults::default()));
+        let classes_cfg = Config {
+            classes_lock: Some(classes_lock.clone()),
+            ..cfg.clone()
+        };
+        let files_data = FilesData {
+            include: include.clone(),
+            exclude: exclude.clone(),
+            paths: opts.paths.clone(),
+        };
+        if let Err(e) = ConcurrentRunner::new(num_jobs, act_on_file).run(classes_cfg, files_data) {
+            eprintln!("{e:?}");
+            process::exit(1);
+        }
+
+        let results = Arc::try_unwrap(classes_lock).unwrap().into_inner().unwrap();
+        let hierarchy = Hierarchy::new(&results);
+        if !hierarchy.unresolved_bases().is_empty() {
+            eprintln!("Unresolved bases:");
+            for (base, classes) in hierarchy.unresolved_bases() {
+                eprintln!("  - {base}: {classes} derived classes");
+            }
+        }
+        Config {
+            hierarchy: Some(Arc::new(hierarchy)),
+            ..cfg
+        }
+    } else {
+        cfg
     };
 
     let files_data = FilesData {
@@ -342,6 +540,65 @@ fn main() {
         println!("{count}");
     }
 
+    if let Some(rollup) = rollup_lock {
+        let rollup = Arc::try_unwrap(rollup).unwrap().into_inner().unwrap();
+        if let Some(output_format) = &opts.output_format {
+            output_format.dump_formats(
+                rollup,
+                PathBuf::from("rollup"),
+                opts.output.as_ref(),
+                opts.pretty,
+            );
+        } else {
+            print!("{rollup}");
+        }
+    }
+
+    if let Some(dependencies) = dependencies_lock {
+        let results = Arc::try_unwrap(dependencies).unwrap().into_inner().unwrap();
+        let graph = DependencyGraph::new(&results);
+        if let Some(output_format) = &opts.output_format {
+            output_format.dump_formats(
+                graph,
+                PathBuf::from("dependencies"),
+                opts.output.as_ref(),
+                opts.pretty,
+            );
+        } else {
+            print!("{graph}");
+        }
+    }
+
+    if let Some(clones) = clones_lock {
+        let results = Arc::try_unwrap(clones).unwrap().into_inner().unwrap();
+        let report = CloneReport::new(&results, opts.min_tokens);
+        if let Some(output_format) = &opts.output_format {
+            output_format.dump_formats(
+                report,
+                PathBuf::from("clones"),
+                opts.output.as_ref(),
+                opts.pretty,
+            );
+        } else {
+            print!("{report}");
+        }
+    }
+
+    if let Some(annotations) = annotations_lock {
+        let mut results = Arc::try_unwrap(annotations).unwrap().into_inner().unwrap();
+        results.sort();
+        if let Some(output_format) = &opts.output_format {
+            output_format.dump_formats(
+                results,
+                PathBuf::from("annotations"),
+                opts.output.as_ref(),
+                opts.pretty,
+            );
+        } else {
+            print!("{results}");
+        }
+    }
+
     if let Some(preproc) = preproc_lock {
         let mut data = Arc::try_unwrap(preproc).unwrap().into_inner().unwrap();
         fix_includes(&mut data.files, &all_files);
@@ -65,7 +65,7 @@ async fn comment_removal_json(item: web::Json<WebCommentPayload>) -> HttpRespons
     let (language, _) = guess_language(&buf, path);
     if let Some(language) = language {
         let cfg = WebCommentCfg { id: payload.id };
-        let language = if language == LANG::Cpp {
+        let language = if matches!(language, LANG::C | LANG::Cpp) {
             LANG::Ccomment
         } else {
             language
@@ -641,10 +641,27 @@ mod tests {
             "spaces": {"kind": "unit",
                        "start_line": 1,
                        "end_line": 4,
-                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min":1.0, "max":1.0},
-                                   "cognitive": {"sum": 0.0, "average": 0.0, "min":0.0, "max":0.0},
-                                   "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
-                                   "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
+                       "start_column": 1,
+                       "end_column": 1,
+                       "start_byte": 0,
+                       "end_byte": 107,
+                       "header": null,
+                       "body": null,
+                       "exclusive_metrics": {"nargs": {"total_functions": 0.0, "total_closures": 0.0, "average_functions": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "functions_min": 0.0, "functions_max": 0.0, "closures_min": 0.0, "closures_max": 0.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "nexits": {"sum": 0.0, "average": null, "min": 0.0, "max": 0.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "cognitive": {"sum": 0.0, "average": null, "min": 0.0, "max": 0.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "cyclomatic": {"sum": 1.0, "average": 1.0, "min": 1.0, "max": 1.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                             "halstead": {"n1": 0.0, "N1": 0.0, "n2": 0.0, "N2": 0.0, "length": 0.0, "estimated_program_length": null, "purity_ratio": null, "vocabulary": 0.0, "volume": null, "difficulty": null, "level": null, "effort": null, "time": null, "bugs": null},
+                                             "loc": {"sloc": 4.0, "ploc": 0.0, "lloc": 0.0, "cloc": 1.0, "blank": 3.0, "sloc_average": 4.0, "ploc_average": 0.0, "lloc_average": 0.0, "cloc_average": 1.0, "blank_average": 3.0, "sloc_min": 4.0, "sloc_max": 4.0, "cloc_min": 1.0, "cloc_max": 1.0, "ploc_min": 0.0, "ploc_max": 0.0, "lloc_min": 0.0, "lloc_max": 0.0, "blank_min": 3.0, "blank_max": 3.0, "sloc_median": null, "sloc_p90": null, "sloc_p95": null, "sloc_stddev": null, "sloc_histogram": {}},
+                                             "nom": {"functions": 0.0, "closures": 0.0, "functions_average": 0.0, "closures_average": 0.0, "total": 0.0, "average": 0.0, "functions_min": 0.0, "functions_max": 0.0, "closures_min": 0.0, "closures_max": 0.0},
+                                             "mi": {"mi_original": null, "mi_sei": null, "mi_visual_studio": 0.0},
+                                             "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min":1.0, "max":1.0, "median": 1.0, "p90": 1.0, "p95": 1.0, "stddev": 0.0, "histogram": {"1-2": 1}},
+                                   "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                   "cognitive": {"sum": 0.0, "average": 0.0, "min":0.0, "max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                   "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                   "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
                                    "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                 "difficulty": 1.0,
                                                 "effort": 4.754_887_502_163_468,
@@ -659,20 +676,43 @@ mod tests {
                                                 "n2": 1.0,
                                                 "n1": 2.0,
                                                 "volume": 4.754_887_502_163_468},
-                                   "loc": {"cloc": 1.0, "ploc": 2.0, "lloc": 1.0, "sloc": 4.0, "blank": 1.0, "cloc_average": 0.5, "ploc_average": 1.0, "lloc_average": 0.5, "sloc_average": 2.0, "blank_average": 0.5, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0},
+                                   "loc": {"cloc": 1.0, "ploc": 2.0, "lloc": 1.0, "sloc": 4.0, "blank": 1.0, "cloc_average": 0.5, "ploc_average": 1.0, "lloc_average": 0.5, "sloc_average": 2.0, "blank_average": 0.5, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0, "sloc_median": 2.0, "sloc_p90": 2.0, "sloc_p95": 2.0, "sloc_stddev": 0.0, "sloc_histogram": {"2-4": 1}},
                                    "nom": {"functions": 1.0, "closures": 0.0, "functions_average": 0.5, "closures_average": 0.0, "total": 1.0, "average": 0.5, "closures_min": 0.0, "closures_max": 0.0, "functions_min": 0.0, "functions_max": 1.0},
                                    "mi": {"mi_original": 139.974_331_558_152_1,
                                           "mi_sei": 161.414_455_240_662_22,
                                           "mi_visual_studio": 81.856_334_244_533_39},
-                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                   "docs": {"public": 1.0, "documented": 0.0, "undocumented": 1.0, "coverage": 0.0, "undocumented_items": [{"name": "foo", "start_line": 3}]},
+                                   "typing": {"parameters": 0.0, "annotated_parameters": 0.0, "returns": 1.0, "annotated_returns": 0.0, "attributes": 0.0, "annotated_attributes": 0.0, "any": 0.0, "type_ignores": 0.0, "coverage": 0.0}},
                        "name": "test.py",
+                       "qualified_name": null,
+                       "id": null,
                        "spaces": [{"kind": "function",
                                    "start_line": 3,
                                    "end_line": 4,
-                                   "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0, "min":1.0, "max":1.0},
-                                               "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0},
-                                               "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
-                                               "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
+                                   "start_column": 1,
+                                   "end_column": 9,
+                                   "start_byte": 87,
+                                   "end_byte": 106,
+                                   "header": {"start_line": 3, "end_line": 3, "start_column": 1, "end_column": 11, "start_byte": 87, "end_byte": 97},
+                                   "body": {"start_line": 4, "end_line": 4, "start_column": 5, "end_column": 9, "start_byte": 102, "end_byte": 106},
+                                   "exclusive_metrics": {"nargs": {"total_functions": 0.0, "total_closures": 0.0, "average_functions": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "functions_min": 0.0, "functions_max": 0.0, "closures_min": 0.0, "closures_max": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                                         "nexits": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                                         "cognitive": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                                         "cyclomatic": {"sum": 1.0, "average": 1.0, "min": 1.0, "max": 1.0, "median": 1.0, "p90": 1.0, "p95": 1.0, "stddev": 0.0, "histogram": {"1-2": 1}},
+                                                         "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                                         "halstead": {"n1": 2.0, "N1": 2.0, "n2": 1.0, "N2": 1.0, "length": 3.0, "estimated_program_length": 2.0, "purity_ratio": 0.6666666666666666, "vocabulary": 3.0, "volume": 4.754887502163468, "difficulty": 1.0, "level": 1.0, "effort": 4.754887502163468, "time": 0.26416041678685936, "bugs": 0.0009425525573729414},
+                                                         "loc": {"sloc": 2.0, "ploc": 2.0, "lloc": 1.0, "cloc": 0.0, "blank": 0.0, "sloc_average": 2.0, "ploc_average": 2.0, "lloc_average": 1.0, "cloc_average": 0.0, "blank_average": 0.0, "sloc_min": 2.0, "sloc_max": 2.0, "cloc_min": 0.0, "cloc_max": 0.0, "ploc_min": 2.0, "ploc_max": 2.0, "lloc_min": 1.0, "lloc_max": 1.0, "blank_min": 0.0, "blank_max": 0.0, "sloc_median": 2.0, "sloc_p90": 2.0, "sloc_p95": 2.0, "sloc_stddev": 0.0, "sloc_histogram": {"2-4": 1}},
+                                                         "nom": {"functions": 1.0, "closures": 0.0, "functions_average": 1.0, "closures_average": 0.0, "total": 1.0, "average": 1.0, "functions_min": 1.0, "functions_max": 1.0, "closures_min": 0.0, "closures_max": 0.0},
+                                                         "mi": {"mi_original": 151.43331588322323, "mi_sei": 142.87306171748978, "mi_visual_studio": 88.5574946685516},
+                                                         "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                                         "docs": {"public": 1.0, "documented": 0.0, "undocumented": 1.0, "coverage": 0.0, "undocumented_items": [{"name": "foo", "start_line": 3}]},
+                                                         "typing": {"parameters": 0.0, "annotated_parameters": 0.0, "returns": 1.0, "annotated_returns": 0.0, "attributes": 0.0, "annotated_attributes": 0.0, "any": 0.0, "type_ignores": 0.0, "coverage": 0.0}},
+                                   "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0, "min":1.0, "max":1.0, "median": 1.0, "p90": 1.0, "p95": 1.0, "stddev": 0.0, "histogram": {"1-2": 1}},
+                                               "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                               "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                               "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                               "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
                                                "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                             "difficulty": 1.0,
                                                             "effort": 4.754_887_502_163_468,
@@ -687,13 +727,17 @@ mod tests {
                                                             "n2": 1.0,
                                                             "n1": 2.0,
                                                             "volume": 4.754_887_502_163_468},
-                                               "loc": {"cloc": 0.0, "ploc": 2.0, "lloc": 1.0, "sloc": 2.0, "blank": 0.0, "cloc_average": 0.0, "ploc_average": 2.0, "lloc_average": 1.0, "sloc_average": 2.0, "blank_average": 0.0, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0},
+                                               "loc": {"cloc": 0.0, "ploc": 2.0, "lloc": 1.0, "sloc": 2.0, "blank": 0.0, "cloc_average": 0.0, "ploc_average": 2.0, "lloc_average": 1.0, "sloc_average": 2.0, "blank_average": 0.0, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0, "sloc_median": 2.0, "sloc_p90": 2.0, "sloc_p95": 2.0, "sloc_stddev": 0.0, "sloc_histogram": {"2-4": 1}},
                                                "nom": {"functions": 1.0, "closures": 0.0, "functions_average": 1.0, "closures_average": 0.0, "total": 1.0, "average": 1.0, "closures_min": 0.0, "closures_max": 0.0, "functions_min": 1.0, "functions_max": 1.0},
                                                "mi": {"mi_original": 151.433_315_883_223_23,
                                                       "mi_sei": 142.873_061_717_489_78,
                                                       "mi_visual_studio": 88.557_494_668_551_6},
-                                               "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                               "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                               "docs": {"public": 1.0, "documented": 0.0, "undocumented": 1.0, "coverage": 0.0, "undocumented_items": [{"name": "foo", "start_line": 3}]},
+                                               "typing": {"parameters": 0.0, "annotated_parameters": 0.0, "returns": 1.0, "annotated_returns": 0.0, "attributes": 0.0, "annotated_attributes": 0.0, "any": 0.0, "type_ignores": 0.0, "coverage": 0.0}},
                                    "name": "foo",
+                                   "qualified_name": "foo",
+                                   "id": "dcb27518fed9d577",
                                    "spaces": []}]}
         });
 
@@ -723,10 +767,27 @@ mod tests {
             "spaces": {"kind": "unit",
                        "start_line": 1,
                        "end_line": 2,
-                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min":1.0, "max":1.0},
-                                   "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0},
-                                   "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
-                                   "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
+                       "start_column": 1,
+                       "end_column": 1,
+                       "start_byte": 0,
+                       "end_byte": 20,
+                       "header": null,
+                       "body": null,
+                       "exclusive_metrics": {"nargs": {"total_functions": 0.0, "total_closures": 0.0, "average_functions": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "functions_min": 0.0, "functions_max": 0.0, "closures_min": 0.0, "closures_max": 0.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "nexits": {"sum": 0.0, "average": null, "min": 0.0, "max": 0.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "cognitive": {"sum": 0.0, "average": null, "min": 0.0, "max": 0.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "cyclomatic": {"sum": 1.0, "average": 1.0, "min": 1.0, "max": 1.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                             "halstead": {"n1": 0.0, "N1": 0.0, "n2": 0.0, "N2": 0.0, "length": 0.0, "estimated_program_length": null, "purity_ratio": null, "vocabulary": 0.0, "volume": null, "difficulty": null, "level": null, "effort": null, "time": null, "bugs": null},
+                                             "loc": {"sloc": 2.0, "ploc": 0.0, "lloc": 0.0, "cloc": 0.0, "blank": 2.0, "sloc_average": 2.0, "ploc_average": 0.0, "lloc_average": 0.0, "cloc_average": 0.0, "blank_average": 2.0, "sloc_min": 2.0, "sloc_max": 2.0, "cloc_min": 0.0, "cloc_max": 0.0, "ploc_min": 0.0, "ploc_max": 0.0, "lloc_min": 0.0, "lloc_max": 0.0, "blank_min": 2.0, "blank_max": 2.0, "sloc_median": null, "sloc_p90": null, "sloc_p95": null, "sloc_stddev": null, "sloc_histogram": {}},
+                                             "nom": {"functions": 0.0, "closures": 0.0, "functions_average": 0.0, "closures_average": 0.0, "total": 0.0, "average": 0.0, "functions_min": 0.0, "functions_max": 0.0, "closures_min": 0.0, "closures_max": 0.0},
+                                             "mi": {"mi_original": null, "mi_sei": null, "mi_visual_studio": 0.0},
+                                             "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min":1.0, "max":1.0, "median": 1.0, "p90": 1.0, "p95": 1.0, "stddev": 0.0, "histogram": {"1-2": 1}},
+                                   "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                   "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                   "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                   "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
                                    "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                 "difficulty": 1.0,
                                                 "effort": 4.754_887_502_163_468,
@@ -741,13 +802,17 @@ mod tests {
                                                 "n2": 1.0,
                                                 "n1": 2.0,
                                                 "volume": 4.754_887_502_163_468},
-                                   "loc": {"cloc": 0.0, "ploc": 2.0, "lloc": 1.0, "sloc": 2.0, "blank": 0.0, "cloc_average": 0.0, "ploc_average": 1.0, "lloc_average": 0.5, "sloc_average": 1.0, "blank_average": 0.0, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0},
+                                   "loc": {"cloc": 0.0, "ploc": 2.0, "lloc": 1.0, "sloc": 2.0, "blank": 0.0, "cloc_average": 0.0, "ploc_average": 1.0, "lloc_average": 0.5, "sloc_average": 1.0, "blank_average": 0.0, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0, "sloc_median": 2.0, "sloc_p90": 2.0, "sloc_p95": 2.0, "sloc_stddev": 0.0, "sloc_histogram": {"2-4": 1}},
                                    "nom": {"functions": 1.0, "closures": 0.0, "functions_average": 0.5, "closures_average": 0.0, "total": 1.0, "average": 0.5, "closures_min": 0.0, "closures_max": 0.0, "functions_min": 0.0, "functions_max": 1.0},
                                    "mi": {"mi_original": 151.203_315_883_223_2,
                                           "mi_sei": 142.643_061_717_489_76,
                                           "mi_visual_studio": 88.422_991_744_574_97},
-                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                   "docs": {"public": 1.0, "documented": 0.0, "undocumented": 1.0, "coverage": 0.0, "undocumented_items": [{"name": "foo", "start_line": 1}]},
+                                   "typing": {"parameters": 0.0, "annotated_parameters": 0.0, "returns": 1.0, "annotated_returns": 0.0, "attributes": 0.0, "annotated_attributes": 0.0, "any": 0.0, "type_ignores": 0.0, "coverage": 0.0}},
                        "name": "test.py",
+                       "qualified_name": null,
+                       "id": null,
                        "spaces": []}
         });
 
@@ -773,10 +838,27 @@ mod tests {
             "spaces": {"kind": "unit",
                        "start_line": 1,
                        "end_line": 2,
-                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min": 1.0,"max": 1.0},
-                                   "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0},
-                                   "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
-                                   "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
+                       "start_column": 1,
+                       "end_column": 1,
+                       "start_byte": 0,
+                       "end_byte": 20,
+                       "header": null,
+                       "body": null,
+                       "exclusive_metrics": {"nargs": {"total_functions": 0.0, "total_closures": 0.0, "average_functions": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "functions_min": 0.0, "functions_max": 0.0, "closures_min": 0.0, "closures_max": 0.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "nexits": {"sum": 0.0, "average": null, "min": 0.0, "max": 0.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "cognitive": {"sum": 0.0, "average": null, "min": 0.0, "max": 0.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "cyclomatic": {"sum": 1.0, "average": 1.0, "min": 1.0, "max": 1.0, "median": null, "p90": null, "p95": null, "stddev": null, "histogram": {}},
+                                             "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                             "halstead": {"n1": 0.0, "N1": 0.0, "n2": 0.0, "N2": 0.0, "length": 0.0, "estimated_program_length": null, "purity_ratio": null, "vocabulary": 0.0, "volume": null, "difficulty": null, "level": null, "effort": null, "time": null, "bugs": null},
+                                             "loc": {"sloc": 2.0, "ploc": 0.0, "lloc": 0.0, "cloc": 0.0, "blank": 2.0, "sloc_average": 2.0, "ploc_average": 0.0, "lloc_average": 0.0, "cloc_average": 0.0, "blank_average": 2.0, "sloc_min": 2.0, "sloc_max": 2.0, "cloc_min": 0.0, "cloc_max": 0.0, "ploc_min": 0.0, "ploc_max": 0.0, "lloc_min": 0.0, "lloc_max": 0.0, "blank_min": 2.0, "blank_max": 2.0, "sloc_median": null, "sloc_p90": null, "sloc_p95": null, "sloc_stddev": null, "sloc_histogram": {}},
+                                             "nom": {"functions": 0.0, "closures": 0.0, "functions_average": 0.0, "closures_average": 0.0, "total": 0.0, "average": 0.0, "functions_min": 0.0, "functions_max": 0.0, "closures_min": 0.0, "closures_max": 0.0},
+                                             "mi": {"mi_original": null, "mi_sei": null, "mi_visual_studio": 0.0},
+                                             "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min": 1.0,"max": 1.0, "median": 1.0, "p90": 1.0, "p95": 1.0, "stddev": 0.0, "histogram": {"1-2": 1}},
+                                   "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                   "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                   "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                   "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
                                    "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                 "difficulty": 1.0,
                                                 "effort": 4.754_887_502_163_468,
@@ -791,20 +873,43 @@ mod tests {
                                                 "n2": 1.0,
                                                 "n1": 2.0,
                                                 "volume": 4.754_887_502_163_468},
-                                   "loc": {"cloc": 0.0, "ploc": 2.0, "lloc": 1.0, "sloc": 2.0, "blank": 0.0, "cloc_average": 0.0, "ploc_average": 1.0, "lloc_average": 0.5, "sloc_average": 1.0, "blank_average": 0.0, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0},
+                                   "loc": {"cloc": 0.0, "ploc": 2.0, "lloc": 1.0, "sloc": 2.0, "blank": 0.0, "cloc_average": 0.0, "ploc_average": 1.0, "lloc_average": 0.5, "sloc_average": 1.0, "blank_average": 0.0, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0, "sloc_median": 2.0, "sloc_p90": 2.0, "sloc_p95": 2.0, "sloc_stddev": 0.0, "sloc_histogram": {"2-4": 1}},
                                    "nom": {"functions": 1.0, "closures": 0.0, "functions_average": 0.5, "closures_average": 0.0, "total": 1.0, "average": 0.5, "closures_min": 0.0, "closures_max": 0.0, "functions_min": 0.0, "functions_max": 1.0},
                                    "mi": {"mi_original": 151.203_315_883_223_2,
                                           "mi_sei": 142.643_061_717_489_76,
                                           "mi_visual_studio": 88.422_991_744_574_97},
-                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                   "docs": {"public": 1.0, "documented": 0.0, "undocumented": 1.0, "coverage": 0.0, "undocumented_items": [{"name": "foo", "start_line": 1}]},
+                                   "typing": {"parameters": 0.0, "annotated_parameters": 0.0, "returns": 1.0, "annotated_returns": 0.0, "attributes": 0.0, "annotated_attributes": 0.0, "any": 0.0, "type_ignores": 0.0, "coverage": 0.0}},
                        "name": "test.py",
+                       "qualified_name": null,
+                       "id": null,
                        "spaces": [{"kind": "function",
                                    "start_line": 1,
                                    "end_line": 2,
-                                   "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0, "min": 1.0,"max": 1.0},
-                                               "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0},
-                                               "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
-                                               "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
+                                   "start_column": 1,
+                                   "end_column": 9,
+                                   "start_byte": 0,
+                                   "end_byte": 19,
+                                   "header": {"start_line": 1, "end_line": 1, "start_column": 1, "end_column": 11, "start_byte": 0, "end_byte": 10},
+                                   "body": {"start_line": 2, "end_line": 2, "start_column": 5, "end_column": 9, "start_byte": 15, "end_byte": 19},
+                                   "exclusive_metrics": {"nargs": {"total_functions": 0.0, "total_closures": 0.0, "average_functions": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "functions_min": 0.0, "functions_max": 0.0, "closures_min": 0.0, "closures_max": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                                         "nexits": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                                         "cognitive": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                                         "cyclomatic": {"sum": 1.0, "average": 1.0, "min": 1.0, "max": 1.0, "median": 1.0, "p90": 1.0, "p95": 1.0, "stddev": 0.0, "histogram": {"1-2": 1}},
+                                                         "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                                         "halstead": {"n1": 2.0, "N1": 2.0, "n2": 1.0, "N2": 1.0, "length": 3.0, "estimated_program_length": 2.0, "purity_ratio": 0.6666666666666666, "vocabulary": 3.0, "volume": 4.754887502163468, "difficulty": 1.0, "level": 1.0, "effort": 4.754887502163468, "time": 0.26416041678685936, "bugs": 0.0009425525573729414},
+                                                         "loc": {"sloc": 2.0, "ploc": 2.0, "lloc": 1.0, "cloc": 0.0, "blank": 0.0, "sloc_average": 2.0, "ploc_average": 2.0, "lloc_average": 1.0, "cloc_average": 0.0, "blank_average": 0.0, "sloc_min": 2.0, "sloc_max": 2.0, "cloc_min": 0.0, "cloc_max": 0.0, "ploc_min": 2.0, "ploc_max": 2.0, "lloc_min": 1.0, "lloc_max": 1.0, "blank_min": 0.0, "blank_max": 0.0, "sloc_median": 2.0, "sloc_p90": 2.0, "sloc_p95": 2.0, "sloc_stddev": 0.0, "sloc_histogram": {"2-4": 1}},
+                                                         "nom": {"functions": 1.0, "closures": 0.0, "functions_average": 1.0, "closures_average": 0.0, "total": 1.0, "average": 1.0, "functions_min": 1.0, "functions_max": 1.0, "closures_min": 0.0, "closures_max": 0.0},
+                                                         "mi": {"mi_original": 151.43331588322323, "mi_sei": 142.87306171748978, "mi_visual_studio": 88.5574946685516},
+                                                         "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                                         "docs": {"public": 1.0, "documented": 0.0, "undocumented": 1.0, "coverage": 0.0, "undocumented_items": [{"name": "foo", "start_line": 1}]},
+                                                         "typing": {"parameters": 0.0, "annotated_parameters": 0.0, "returns": 1.0, "annotated_returns": 0.0, "attributes": 0.0, "annotated_attributes": 0.0, "any": 0.0, "type_ignores": 0.0, "coverage": 0.0}},
+                                   "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0, "min": 1.0,"max": 1.0, "median": 1.0, "p90": 1.0, "p95": 1.0, "stddev": 0.0, "histogram": {"1-2": 1}},
+                                               "nesting": {"sum": 0.0, "average": 0.0, "min": 0.0, "max": 0.0, "blocks": 0.0, "average_depth": 0.0},
+                                               "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                               "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
+                                               "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0, "median": 0.0, "p90": 0.0, "p95": 0.0, "stddev": 0.0, "histogram": {"0-1": 1}},
                                                "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                             "difficulty": 1.0,
                                                             "effort": 4.754_887_502_163_468,
@@ -819,13 +924,17 @@ mod tests {
                                                             "n2": 1.0,
                                                             "n1": 2.0,
                                                             "volume": 4.754_887_502_163_468},
-                                               "loc": {"cloc": 0.0, "ploc": 2.0, "lloc": 1.0, "sloc": 2.0, "blank": 0.0, "cloc_average": 0.0, "ploc_average": 2.0, "lloc_average": 1.0, "sloc_average": 2.0, "blank_average": 0.0, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0},
+                                               "loc": {"cloc": 0.0, "ploc": 2.0, "lloc": 1.0, "sloc": 2.0, "blank": 0.0, "cloc_average": 0.0, "ploc_average": 2.0, "lloc_average": 1.0, "sloc_average": 2.0, "blank_average": 0.0, "cloc_min": 0.0, "ploc_min": 2.0, "lloc_min": 1.0, "sloc_min": 2.0, "blank_min": 0.0, "cloc_max": 0.0, "ploc_max": 2.0, "lloc_max": 1.0, "sloc_max": 2.0, "blank_max": 0.0, "sloc_median": 2.0, "sloc_p90": 2.0, "sloc_p95": 2.0, "sloc_stddev": 0.0, "sloc_histogram": {"2-4": 1}},
                                                "nom": {"functions": 1.0, "closures": 0.0, "functions_average": 1.0, "closures_average": 0.0, "total": 1.0, "average": 1.0, "closures_min": 0.0, "closures_max": 0.0, "functions_min": 1.0, "functions_max": 1.0},
                                                "mi": {"mi_original": 151.433_315_883_223_23,
                                                       "mi_sei": 142.873_061_717_489_78,
                                                       "mi_visual_studio": 88.557_494_668_551_6},
-                                               "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                               "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                               "docs": {"public": 1.0, "documented": 0.0, "undocumented": 1.0, "coverage": 0.0, "undocumented_items": [{"name": "foo", "start_line": 1}]},
+                                               "typing": {"parameters": 0.0, "annotated_parameters": 0.0, "returns": 1.0, "annotated_returns": 0.0, "attributes": 0.0, "annotated_attributes": 0.0, "any": 0.0, "type_ignores": 0.0, "coverage": 0.0}},
                                    "name": "foo",
+                                   "qualified_name": "foo",
+                                   "id": "dcb27518fed9d577",
                                    "spaces": []}]}
         });
 
@@ -855,13 +964,45 @@ mod tests {
                     "end_line": 2,
                     "error": false,
                     "name": "foo",
-                    "start_line": 1
+                    "qualified_name": "foo",
+                    "id": "dcb27518fed9d577",
+                    "start_line": 1,
+                    "start_column": 1,
+                    "end_column": 9,
+                    "start_byte": 0,
+                    "end_byte": 19,
+                    "header": {"start_line": 1, "end_line": 1, "start_column": 1, "end_column": 11, "start_byte": 0, "end_byte": 10},
+                    "body": {"start_line": 2, "end_line": 2, "start_column": 5, "end_column": 9, "start_byte": 15, "end_byte": 19},
+                    "signature": {
+                        "parameters": [],
+                        "return_type": null,
+                        "visibility": null,
+                        "modifiers": [],
+                        "decorators": [],
+                        "generics": []
+                    }
                 },
                 {
                     "end_line": 5,
                     "error": false,
                     "name": "bar",
-                    "start_line": 4
+                    "qualified_name": "bar",
+                    "id": "003934191339461a",
+                    "start_line": 4,
+                    "start_column": 1,
+                    "end_column": 9,
+                    "start_byte": 21,
+                    "end_byte": 40,
+                    "header": {"start_line": 4, "end_line": 4, "start_column": 1, "end_column": 11, "start_byte": 21, "end_byte": 31},
+                    "body": {"start_line": 5, "end_line": 5, "start_column": 5, "end_column": 9, "start_byte": 36, "end_byte": 40},
+                    "signature": {
+                        "parameters": [],
+                        "return_type": null,
+                        "visibility": null,
+                        "modifiers": [],
+                        "decorators": [],
+                        "generics": []
+                    }
                 }
             ]
         });
@@ -889,13 +1030,45 @@ mod tests {
                     "end_line": 2,
                     "error": false,
                     "name": "foo",
-                    "start_line": 1
+                    "qualified_name": "foo",
+                    "id": "dcb27518fed9d577",
+                    "start_line": 1,
+                    "start_column": 1,
+                    "end_column": 9,
+                    "start_byte": 0,
+                    "end_byte": 19,
+                    "header": {"start_line": 1, "end_line": 1, "start_column": 1, "end_column": 11, "start_byte": 0, "end_byte": 10},
+                    "body": {"start_line": 2, "end_line": 2, "start_column": 5, "end_column": 9, "start_byte": 15, "end_byte": 19},
+                    "signature": {
+                        "parameters": [],
+                        "return_type": null,
+                        "visibility": null,
+                        "modifiers": [],
+                        "decorators": [],
+                        "generics": []
+                    }
                 },
                 {
                     "end_line": 5,
                     "error": false,
                     "name": "bar",
-                    "start_line": 4
+                    "qualified_name": "bar",
+                    "id": "003934191339461a",
+                    "start_line": 4,
+                    "start_column": 1,
+                    "end_column": 9,
+                    "start_byte": 21,
+                    "end_byte": 40,
+                    "header": {"start_line": 4, "end_line": 4, "start_column": 1, "end_column": 11, "start_byte": 21, "end_byte": 31},
+                    "body": {"start_line": 5, "end_line": 5, "start_column": 5, "end_column": 9, "start_byte": 36, "end_byte": 40},
+                    "signature": {
+                        "parameters": [],
+                        "return_type": null,
+                        "visibility": null,
+                        "modifiers": [],
+                        "decorators": [],
+                        "generics": []
+                    }
                 }
             ]
         });
@@ -0,0 +1,277 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::checker::Checker;
+use crate::getter::Getter;
+use crate::traits::*;
+
+/// The tags searched in the comments when no tags are specified.
+pub const DEFAULT_ANNOTATION_TAGS: [&str; 4] = ["TODO", "FIXME", "HACK", "XXX"];
+
+/// A tagged annotation, such as a `TODO`, written in a comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Annotation {
+    /// The file containing an annotation
+    pub file: PathBuf,
+    /// The line of an annotation
+    pub line: usize,
+    /// The tag of an annotation, such as `TODO` or `FIXME`
+    pub tag: String,
+    /// The owner of an annotation, such as `alice` in `TODO(alice)`
+    pub owner: Option<String>,
+    /// The issue referenced by an annotation, such as `123` in `FIXME #123`
+    pub issue: Option<String>,
+    /// The name of the function space containing an annotation
+    ///
+    /// If `None`, an annotation is not contained in a function space
+    pub space: Option<String>,
+    /// The text following the tag of an annotation
+    pub text: String,
+}
+
+impl fmt::Display for Annotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file.display(), self.line, self.tag)?;
+        if let Some(owner) = &self.owner {
+            write!(f, "({owner})")?;
+        }
+        if let Some(issue) = &self.issue {
+            write!(f, " #{issue}")?;
+        }
+        if let Some(space) = &self.space {
+            write!(f, " in {space}")?;
+        }
+        if !self.text.is_empty() {
+            write!(f, ": {}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+fn build_regex(tags: &[String]) -> Regex {
+    let tags = if tags.is_empty() {
+        DEFAULT_ANNOTATION_TAGS.join("|")
+    } else {
+        tags.iter()
+            .map(|tag| regex::escape(tag))
+            .collect::<Vec<_>>()
+            .join("|")
+    };
+    // TAG(owner) #issue: text
+    Regex::new(&format!(
+        r"\b({tags})\b(?:\(([^)]*)\))?:?\s*(?:#(\d+)\b)?[\s:.-]*(.*)"
+    ))
+    .unwrap()
+}
+
+/// Returns the tagged annotations written in the comments of a code.
+///
+/// The `TODO`, `FIXME`, `HACK` and `XXX` tags are searched
+/// when the `tags` slice is empty.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{annotations, ParserTrait, PythonParser};
+///
+/// let source_code = "def f():\n    # TODO(alice): handle errors\n    pass\n";
+/// let path = Path::new("foo.py");
+/// let parser = PythonParser::new(source_code.as_bytes().to_vec(), path, None);
+///
+/// let annotations = annotations(&parser, path, &[]);
+/// assert_eq!(annotations[0].owner.as_deref(), Some("alice"));
+/// assert_eq!(annotations[0].space.as_deref(), Some("f"));
+/// ```
+pub fn annotations<T: ParserTrait>(parser: &T, path: &Path, tags: &[String]) -> Vec<Annotation> {
+    let re = build_regex(tags);
+    let code = parser.get_code();
+    let node = parser.get_root();
+    let mut cursor = node.cursor();
+    let mut stack = vec![(node, None)];
+    let mut children = Vec::new();
+    let mut annotations = Vec::new();
+
+    while let Some((node, space)) = stack.pop() {
+        if T::Checker::is_comment(&node) {
+            let Some(text) = node.utf8_text(code) else {
+                continue;
+            };
+            for (i, line) in text.lines().enumerate() {
+                let Some(cap) = re.captures(line) else {
+                    continue;
+                };
+                let (owner, issue) = match cap.get(2).map(|owner| owner.as_str().trim()) {
+                    // TODO(#123)
+                    Some(owner) if owner.starts_with('#') => (None, Some(&owner[1..])),
+                    Some("") | None => (None, None),
+                    Some(owner) => (Some(owner), None),
+                };
+                let issue = issue.or_else(|| cap.get(3).map(|issue| issue.as_str()));
+                let text = cap[4].trim();
+                annotations.push(Annotation {
+                    file: path.to_path_buf(),
+                    line: node.start_row() + i + 1,
+                    tag: cap[1].to_string(),
+                    owner: owner.map(|owner| owner.to_string()),
+                    issue: issue.map(|issue| issue.to_string()),
+                    space: space.clone(),
+                    text: text.strip_suffix("*/").unwrap_or(text).trim().to_string(),
+                });
+            }
+            continue;
+        }
+
+        let space = if node.parent().is_some()
+            && (T::Checker::is_func(&node) || T::Checker::is_func_space(&node))
+        {
+            T::Getter::get_func_space_name(&node, code).map(|name| name.to_string())
+        } else {
+            space
+        };
+        cursor.reset(&node);
+        if cursor.goto_first_child() {
+            loop {
+                children.push((cursor.node(), space.clone()));
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+            for child in children.drain(..).rev() {
+                stack.push(child);
+            }
+        }
+    }
+    annotations
+}
+
+/// Configuration options for extracting the tagged annotations
+/// written in the comments of a code.
+#[derive(Debug)]
+pub struct AnnotationsCfg {
+    /// Path to the file containing the code
+    pub path: PathBuf,
+    /// Tags to search
+    ///
+    /// If empty, the `TODO`, `FIXME`, `HACK` and `XXX` tags are searched
+    pub tags: Vec<String>,
+    /// Annotations found by each thread
+    pub results: Arc<Mutex<Annotations>>,
+}
+
+/// Tagged annotations found in the comments of some files.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Annotations {
+    /// The annotations found in the files
+    pub annotations: Vec<Annotation>,
+}
+
+impl Annotations {
+    /// Sorts the annotations by file and line.
+    pub fn sort(&mut self) {
+        self.annotations
+            .sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    }
+}
+
+impl Callback for Annotations {
+    type Res = std::io::Result<()>;
+    type Cfg = AnnotationsCfg;
+
+    fn call<T: ParserTrait>(cfg: Self::Cfg, parser: &T) -> Self::Res {
+        let annotations = annotations(parser, &cfg.path, &cfg.tags);
+        cfg.results.lock().unwrap().annotations.extend(annotations);
+        Ok(())
+    }
+}
+
+impl fmt::Display for Annotations {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for annotation in &self.annotations {
+            writeln!(f, "{annotation}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JavascriptParser, PythonParser, RustParser};
+
+    use super::*;
+
+    fn check<T: ParserTrait>(source: &str, filename: &str, tags: &[&str]) -> Vec<Annotation> {
+        let path = PathBuf::from(filename);
+        let parser = T::new(source.as_bytes().to_vec(), &path, None);
+        let tags: Vec<_> = tags.iter().map(|tag| tag.to_string()).collect();
+        annotations(&parser, &path, &tags)
+    }
+
+    #[test]
+    fn python_owner_and_issue() {
+        let annotations = check::<PythonParser>(
+            "# TODO(alice): remove this module
+class A:
+    def f(self):
+        # FIXME #123 handle None
+        return 1  # XXX: magic number
+# todo is not a tag
+",
+            "foo.py",
+            &[],
+        );
+        let found: Vec<_> = annotations.iter().map(|a| a.to_string()).collect();
+        assert_eq!(
+            found,
+            [
+                "foo.py:1: TODO(alice): remove this module",
+                "foo.py:4: FIXME #123 in f: handle None",
+                "foo.py:5: XXX in f: magic number",
+            ]
+        );
+    }
+
+    #[test]
+    fn rust_block_comment() {
+        let annotations = check::<RustParser>(
+            "fn f() {
+    /* Some text.
+     * HACK(#42): work around a bug */
+    let x = 1;
+}
+",
+            "foo.rs",
+            &[],
+        );
+        assert_eq!(annotations.len(), 1);
+        let annotation = &annotations[0];
+        assert_eq!(annotation.line, 3);
+        assert_eq!(annotation.tag, "HACK");
+        assert_eq!(annotation.owner, None);
+        assert_eq!(annotation.issue.as_deref(), Some("42"));
+        assert_eq!(annotation.space.as_deref(), Some("f"));
+        assert_eq!(annotation.text, "work around a bug");
+    }
+
+    #[test]
+    fn javascript_custom_tags() {
+        let annotations = check::<JavascriptParser>(
+            "// TODO: not searched
+// NOTE(bob) keep in sync with the server
+function f() {}
+",
+            "foo.js",
+            &["NOTE"],
+        );
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].tag, "NOTE");
+        assert_eq!(annotations[0].owner.as_deref(), Some("bob"));
+        assert_eq!(annotations[0].space, None);
+        assert_eq!(annotations[0].text, "keep in sync with the server");
+    }
+}
@@ -194,6 +194,72 @@ impl Checker for CcommentCode {
     }
 }
 
+impl Checker for CCode {
+    fn is_comment(node: &Node) -> bool {
+        node.kind_id() == C::Comment
+    }
+
+    fn is_useful_comment(node: &Node, code: &[u8]) -> bool {
+        get_aho_corasick_match(&code[node.start_byte()..node.end_byte()])
+    }
+
+    fn is_func_space(node: &Node) -> bool {
+        match node.kind_id().into() {
+            C::TranslationUnit | C::FunctionDefinition | C::FunctionDefinition2 => true,
+            // `struct A;` and `struct A a;` only refer to a struct
+            C::StructSpecifier => node.child_by_field_name("body").is_some(),
+            _ => false,
+        }
+    }
+
+    fn is_func(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            C::FunctionDefinition | C::FunctionDefinition2
+        )
+    }
+
+    fn is_closure(_: &Node) -> bool {
+        false
+    }
+
+    fn is_call(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            C::CallExpression | C::CallExpression2
+        )
+    }
+
+    fn is_non_arg(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            C::LPAREN | C::LPAREN2 | C::COMMA | C::RPAREN
+        )
+    }
+
+    fn is_string(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            C::StringLiteral | C::ConcatenatedString
+        )
+    }
+
+    fn is_else_if(node: &Node) -> bool {
+        if node.kind_id() != C::IfStatement {
+            return false;
+        }
+        if let Some(parent) = node.parent() {
+            return parent.kind_id() == C::ElseClause;
+        }
+        false
+    }
+
+    #[inline(always)]
+    fn is_primitive(id: u16) -> bool {
+        id == C::PrimitiveType
+    }
+}
+
 impl Checker for CppCode {
     fn is_comment(node: &Node) -> bool {
         node.kind_id() == Cpp::Comment
@@ -213,6 +279,7 @@ impl Checker for CppCode {
                 | Cpp::StructSpecifier
                 | Cpp::ClassSpecifier
                 | Cpp::NamespaceDefinition
+                | Cpp::LambdaExpression
         )
     }
 
@@ -282,7 +349,7 @@ impl Checker for PythonCode {
     fn is_func_space(node: &Node) -> bool {
         matches!(
             node.kind_id().into(),
-            Python::Module | Python::FunctionDefinition | Python::ClassDefinition
+            Python::Module | Python::FunctionDefinition | Python::ClassDefinition | Python::Lambda
         )
     }
 
@@ -328,14 +395,26 @@ impl Checker for JavaCode {
     }
 
     fn is_func_space(node: &Node) -> bool {
-        matches!(
-            node.kind_id().into(),
-            Java::Program | Java::ClassDeclaration | Java::InterfaceDeclaration
-        )
+        match node.kind_id().into() {
+            Java::Program
+            | Java::ClassDeclaration
+            | Java::InterfaceDeclaration
+            | Java::EnumDeclaration
+            | Java::RecordDeclaration
+            | Java::AnnotationTypeDeclaration => true,
+            // An anonymous class: `new Runnable() { ... }`
+            Java::ObjectCreationExpression => node.is_child(Java::ClassBody as u16),
+            _ => false,
+        }
     }
 
     fn is_func(node: &Node) -> bool {
-        node.kind_id() == Java::MethodDeclaration || node.kind_id() == Java::ConstructorDeclaration
+        matches!(
+            node.kind_id().into(),
+            Java::MethodDeclaration
+                | Java::ConstructorDeclaration
+                | Java::CompactConstructorDeclaration
+        )
     }
 
     fn is_closure(node: &Node) -> bool {
@@ -500,7 +579,13 @@ impl Checker for TypescriptCode {
                 | Typescript::ClassDeclaration
                 | Typescript::InterfaceDeclaration
                 | Typescript::ArrowFunction
-        )
+                | Typescript::AbstractClassDeclaration
+                | Typescript::EnumDeclaration
+                | Typescript::TypeAliasDeclaration
+        ) || (matches!(
+            node.kind_id().into(),
+            Typescript::InternalModule | Typescript::Module
+        ) && node.child_by_field_name("body").is_some())
     }
 
     is_js_func_and_closure_checker!(TypescriptParser, Typescript);
@@ -559,7 +644,11 @@ impl Checker for TsxCode {
                 | Tsx::ClassDeclaration
                 | Tsx::InterfaceDeclaration
                 | Tsx::ArrowFunction
-        )
+                | Tsx::AbstractClassDeclaration
+                | Tsx::EnumDeclaration
+                | Tsx::TypeAliasDeclaration
+        ) || (matches!(node.kind_id().into(), Tsx::InternalModule | Tsx::Module)
+            && node.child_by_field_name("body").is_some())
     }
 
     is_js_func_and_closure_checker!(TsxParser, Tsx);
@@ -618,8 +707,10 @@ impl Checker for RustCode {
                 | Rust::FunctionItem
                 | Rust::ImplItem
                 | Rust::TraitItem
+                | Rust::StructItem
+                | Rust::EnumItem
                 | Rust::ClosureExpression
-        )
+        ) || (node.kind_id() == Rust::ModItem && node.child_by_field_name("body").is_some())
     }
 
     fn is_func(node: &Node) -> bool {
@@ -671,12 +762,24 @@ impl Checker for KotlinCode {
         false
     }
 
-    fn is_func_space(_: &Node) -> bool {
-        false
+    fn is_func_space(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Kotlin::SourceFile
+                | Kotlin::ClassDeclaration
+                | Kotlin::ObjectDeclaration
+                | Kotlin::CompanionObject
+                | Kotlin::ObjectLiteral
+        )
     }
 
-    fn is_func(_: &Node) -> bool {
-        false
+    fn is_func(node: &Node) -> bool {
+        match node.kind_id().into() {
+            Kotlin::FunctionDeclaration | Kotlin::SecondaryConstructor => true,
+            // A custom accessor: `get() = field`
+            Kotlin::Getter | Kotlin::Setter => node.is_child(Kotlin::FunctionBody as u16),
+            _ => false,
+        }
     }
 
     fn is_closure(_: &Node) -> bool {
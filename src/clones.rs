@@ -0,0 +1,585 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::Checker;
+use crate::getter::Getter;
+use crate::metrics::halstead::HalsteadType;
+use crate::tools::Fnv1aHasher;
+use crate::traits::*;
+
+// The base of the rolling hash used to fingerprint the token windows
+const BASE: u64 = 1_000_003;
+
+/// A token of a function, as classified by the `Halstead` metric.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct CloneToken {
+    /// The hash of the token where the identifiers and
+    /// the literals are replaced by their kinds
+    pub normalized: u64,
+    /// The hash of the token text
+    pub exact: u64,
+    /// The first line of the token
+    pub start_line: usize,
+    /// The last line of the token
+    pub end_line: usize,
+}
+
+/// The tokens of a function.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CloneFunction {
+    /// The name of the function
+    pub name: Option<String>,
+    /// The tokens of the function
+    pub tokens: Vec<CloneToken>,
+}
+
+/// The tokens of the functions of a file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CloneFile {
+    /// The name of the language of the file
+    pub language: String,
+    /// The number of lines of the file
+    pub lines: usize,
+    /// The functions of the file
+    pub functions: Vec<CloneFunction>,
+}
+
+/// The tokens of the functions of a series of files.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CloneResults {
+    /// The tokens of the functions of each file
+    pub files: HashMap<PathBuf, CloneFile>,
+}
+
+fn hash<H: Hash>(value: H) -> u64 {
+    let mut hasher = Fnv1aHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn get_tokens<T: ParserTrait>(node: &crate::node::Node, code: &[u8], tokens: &mut Vec<CloneToken>) {
+    let mut cursor = node.cursor();
+    let mut stack = vec![*node];
+
+    while let Some(node) = stack.pop() {
+        let text = &code[node.start_byte()..node.end_byte()];
+        let typ = T::Getter::get_op_type(&node);
+        match typ {
+            HalsteadType::Operator | HalsteadType::Operand => {
+                let operand = matches!(typ, HalsteadType::Operand);
+                tokens.push(CloneToken {
+                    normalized: hash((operand, node.kind_id())),
+                    exact: hash((operand, node.kind_id(), text)),
+                    start_line: node.start_row() + 1,
+                    end_line: node.end_row() + 1,
+                });
+                // The content of an operand, such as a string, is not tokenized
+                if operand {
+                    continue;
+                }
+            }
+            HalsteadType::Unknown => {}
+        }
+
+        cursor.reset(&node);
+        if cursor.goto_first_child() {
+            let start = stack.len();
+            loop {
+                stack.push(cursor.node());
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+            stack[start..].reverse();
+        }
+    }
+}
+
+/// Returns the tokens of the functions contained in a code.
+///
+/// A function nested in another one is tokenized with the function containing it.
+pub fn get_clone_tokens<T: ParserTrait>(parser: &T) -> CloneFile {
+    let code = parser.get_code();
+    let root = parser.get_root();
+    let mut cursor = root.cursor();
+    let mut stack = vec![root];
+    let mut file = CloneFile {
+        language: parser.get_language().get_name().to_string(),
+        lines: code.split(|c| *c == b'\n').count() - usize::from(code.ends_with(b"\n")),
+        functions: Vec::new(),
+    };
+
+    while let Some(node) = stack.pop() {
+        if T::Checker::is_func(&node) {
+            let mut function = CloneFunction {
+                name: T::Getter::get_func_space_name(&node, code).map(|name| name.to_string()),
+                tokens: Vec::new(),
+            };
+            get_tokens::<T>(&node, code, &mut function.tokens);
+            file.functions.push(function);
+            continue;
+        }
+
+        cursor.reset(&node);
+        if cursor.goto_first_child() {
+            let start = stack.len();
+            loop {
+                stack.push(cursor.node());
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+            stack[start..].reverse();
+        }
+    }
+
+    file
+}
+
+/// Configuration options for collecting the tokens of the functions of a code.
+#[derive(Debug)]
+pub struct CloneTokensCfg {
+    /// Path to the file containing the code
+    pub path: PathBuf,
+    /// The tokens of the files analyzed so far
+    pub results: Arc<Mutex<CloneResults>>,
+}
+
+pub struct CloneTokens {
+    _guard: (),
+}
+
+impl Callback for CloneTokens {
+    type Res = std::io::Result<()>;
+    type Cfg = CloneTokensCfg;
+
+    fn call<T: ParserTrait>(cfg: Self::Cfg, parser: &T) -> Self::Res {
+        let file = get_clone_tokens(parser);
+        let mut results = cfg.results.lock().unwrap();
+        results.files.insert(cfg.path, file);
+        Ok(())
+    }
+}
+
+/// A code fragment belonging to a clone group.
+#[derive(Clone, Debug, Serialize)]
+pub struct CloneFragment {
+    /// The path of the file containing the fragment
+    pub path: PathBuf,
+    /// The name of the function containing the fragment
+    pub function: Option<String>,
+    /// The first line of the fragment
+    pub start_line: usize,
+    /// The last line of the fragment
+    pub end_line: usize,
+}
+
+/// A group of code fragments having the same sequence of tokens.
+#[derive(Clone, Debug, Serialize)]
+pub struct CloneGroup {
+    /// The number of tokens of each fragment
+    pub tokens: usize,
+    /// The ratio of the tokens with the same text in all the fragments.
+    ///
+    /// It is 1 for exact copies, and lower when some identifiers
+    /// or literals have been renamed.
+    pub similarity: f64,
+    /// The fragments of the group
+    pub fragments: Vec<CloneFragment>,
+}
+
+/// The lines of a file contained in a clone.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FileDuplication {
+    /// The number of lines of the file
+    pub lines: usize,
+    /// The number of lines of the file contained in at least one clone
+    pub duplicated_lines: usize,
+    /// The percentage of duplicated lines
+    pub percentage: f64,
+}
+
+/// The clones found in a series of files.
+#[derive(Debug, Default, Serialize)]
+pub struct CloneReport {
+    /// The clone groups, from the largest one
+    pub groups: Vec<CloneGroup>,
+    /// The duplicated lines of each file
+    pub files: BTreeMap<PathBuf, FileDuplication>,
+}
+
+// The position of a token: file, function and offset
+type Location = (usize, usize, usize);
+
+impl CloneReport {
+    /// Finds the clones among the functions of a series of files.
+    ///
+    /// Two fragments are clones when they have the same sequence
+    /// of at least `min_tokens` tokens, ignoring the names of the identifiers
+    /// and the values of the literals, and are written in the same language.
+    pub fn new(results: &CloneResults, min_tokens: usize) -> Self {
+        let min_tokens = min_tokens.max(1);
+        let mut paths = results.files.keys().collect::<Vec<_>>();
+        paths.sort();
+        let files = paths
+            .iter()
+            .map(|path| &results.files[*path])
+            .collect::<Vec<_>>();
+        let tokens =
+            |(file, function, offset): Location| &files[file].functions[function].tokens[offset..];
+
+        // Fingerprints all the windows of `min_tokens` tokens
+        let power = (1..min_tokens).fold(1u64, |power, _| power.wrapping_mul(BASE));
+        let mut windows: HashMap<(&str, u64), Vec<Location>> = HashMap::new();
+        for (i, file) in files.iter().enumerate() {
+            for (j, function) in file.functions.iter().enumerate() {
+                if function.tokens.len() < min_tokens {
+                    continue;
+                }
+                let mut fingerprint = 0u64;
+                for (k, token) in function.tokens.iter().enumerate() {
+                    if k >= min_tokens {
+                        let first = function.tokens[k - min_tokens].normalized;
+                        fingerprint = fingerprint.wrapping_sub(first.wrapping_mul(power));
+                    }
+                    fingerprint = fingerprint
+                        .wrapping_mul(BASE)
+                        .wrapping_add(token.normalized);
+                    if k + 1 >= min_tokens {
+                        windows
+                            .entry((file.language.as_str(), fingerprint))
+                            .or_default()
+                            .push((i, j, k + 1 - min_tokens));
+                    }
+                }
+            }
+        }
+
+        // Extends the windows with the same fingerprint into maximal clone pairs,
+        // grouping the fragments with the same sequence of tokens
+        let mut groups: BTreeMap<(Reverse<usize>, u64), BTreeSet<Location>> = BTreeMap::new();
+        for locations in windows.values().filter(|locations| locations.len() > 1) {
+            for (n, a) in locations.iter().enumerate() {
+                for b in locations[n + 1..].iter() {
+                    let (ta, tb) = (tokens(*a), tokens(*b));
+                    // The pair is contained in a pair starting before
+                    if a.2 > 0 && b.2 > 0 {
+                        let (pa, pb) = (tokens((a.0, a.1, a.2 - 1)), tokens((b.0, b.1, b.2 - 1)));
+                        if pa[0].normalized == pb[0].normalized {
+                            continue;
+                        }
+                    }
+                    let mut len = ta
+                        .iter()
+                        .zip(tb.iter())
+                        .take_while(|(x, y)| x.normalized == y.normalized)
+                        .count();
+                    // The fragments of a function must not overlap
+                    if a.0 == b.0 && a.1 == b.1 {
+                        len = len.min(b.2.abs_diff(a.2));
+                    }
+                    if len < min_tokens {
+                        continue;
+                    }
+                    let key = hash(ta[..len].iter().map(|t| t.normalized).collect::<Vec<_>>());
+                    let group = groups.entry((Reverse(len), key)).or_default();
+                    group.insert(*a);
+                    group.insert(*b);
+                }
+            }
+        }
+
+        let mut report = Self::default();
+        let mut duplicated: HashMap<usize, BTreeSet<usize>> = HashMap::new();
+        let mut reported: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+        for ((Reverse(len), _), locations) in groups {
+            // In a repetitive code, the fragments of a function can overlap
+            let mut kept: Vec<Location> = Vec::new();
+            for location in locations {
+                if kept.last().is_none_or(|last| {
+                    (last.0, last.1) != (location.0, location.1) || last.2 + len <= location.2
+                }) {
+                    kept.push(location);
+                }
+            }
+            // A shorter alignment of the fragments of a larger clone is not reported
+            let covered = |location: &Location| {
+                reported
+                    .get(&(location.0, location.1))
+                    .is_some_and(|ranges| {
+                        ranges
+                            .iter()
+                            .any(|(start, end)| *start <= location.2 && location.2 + len <= *end)
+                    })
+            };
+            if kept.len() < 2 || kept.iter().all(covered) {
+                continue;
+            }
+            for location in kept.iter() {
+                reported
+                    .entry((location.0, location.1))
+                    .or_default()
+                    .push((location.2, location.2 + len));
+            }
+            let locations = kept;
+            let first = &tokens(locations[0])[..len];
+            let mut similarity = 1.;
+            let mut fragments = Vec::new();
+            for location in locations.iter() {
+                let fragment = &tokens(*location)[..len];
+                let same = first
+                    .iter()
+                    .zip(fragment.iter())
+                    .filter(|(x, y)| x.exact == y.exact)
+                    .count();
+                similarity = f64::min(similarity, same as f64 / len as f64);
+
+                let start_line = fragment[0].start_line;
+                let end_line = fragment
+                    .iter()
+                    .map(|t| t.end_line)
+                    .max()
+                    .unwrap_or(start_line);
+                duplicated
+                    .entry(location.0)
+                    .or_default()
+                    .extend(start_line..=end_line);
+                fragments.push(CloneFragment {
+                    path: paths[location.0].clone(),
+                    function: files[location.0].functions[location.1].name.clone(),
+                    start_line,
+                    end_line,
+                });
+            }
+            report.groups.push(CloneGroup {
+                tokens: len,
+                similarity,
+                fragments,
+            });
+        }
+        report.groups.sort_by(|a, b| {
+            b.tokens.cmp(&a.tokens).then_with(|| {
+                let (a, b) = (&a.fragments[0], &b.fragments[0]);
+                (&a.path, a.start_line).cmp(&(&b.path, b.start_line))
+            })
+        });
+
+        for (i, file) in files.iter().enumerate() {
+            let duplicated_lines = duplicated.get(&i).map_or(0, |lines| lines.len());
+            report.files.insert(
+                paths[i].clone(),
+                FileDuplication {
+                    lines: file.lines,
+                    duplicated_lines,
+                    percentage: if file.lines == 0 {
+                        0.
+                    } else {
+                        duplicated_lines as f64 * 100. / file.lines as f64
+                    },
+                },
+            );
+        }
+
+        report
+    }
+}
+
+/// Finds the clones contained in a code.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{find_clones, ParserTrait, PythonParser};
+///
+/// let source_code = "def f(a):\n    return a + 1\n\ndef g(b):\n    return b + 1\n";
+///
+/// // The path to a dummy file used to contain the source code
+/// let path = Path::new("foo.py");
+/// let source_as_vec = source_code.as_bytes().to_vec();
+///
+/// // The parser of the code, in this case a Python parser
+/// let parser = PythonParser::new(source_as_vec, &path, None);
+///
+/// // Finds the clones of at least 5 tokens
+/// let report = find_clones(&parser, &path, 5);
+/// assert_eq!(report.groups.len(), 1);
+/// ```
+pub fn find_clones<T: ParserTrait>(parser: &T, path: &Path, min_tokens: usize) -> CloneReport {
+    let mut results = CloneResults::default();
+    results
+        .files
+        .insert(path.to_path_buf(), get_clone_tokens(parser));
+    CloneReport::new(&results, min_tokens)
+}
+
+impl fmt::Display for CloneReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for group in self.groups.iter() {
+            writeln!(
+                f,
+                "Clone: {} tokens, similarity: {}",
+                group.tokens, group.similarity
+            )?;
+            for fragment in group.fragments.iter() {
+                writeln!(
+                    f,
+                    "  - {}:{}-{} ({})",
+                    fragment.path.display(),
+                    fragment.start_line,
+                    fragment.end_line,
+                    fragment.function.as_deref().unwrap_or("<anonymous>")
+                )?;
+            }
+        }
+        for (path, file) in self.files.iter() {
+            writeln!(
+                f,
+                "{}: lines: {}, duplicated_lines: {}, percentage: {}",
+                path.display(),
+                file.lines,
+                file.duplicated_lines,
+                file.percentage
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JavaParser, PythonParser, RustParser};
+
+    use super::*;
+
+    fn collect<T: ParserTrait>(results: &mut CloneResults, path: &str, source: &str) {
+        let path = Path::new(path);
+        let parser = T::new(source.as_bytes().to_vec(), path, None);
+        results
+            .files
+            .insert(path.to_path_buf(), get_clone_tokens(&parser));
+    }
+
+    #[test]
+    fn python_renamed_clone() {
+        let source = "def f(a, b):
+    if a > b:
+        return a - b
+    return b - a
+
+def g(x, y):
+    if x > y:
+        return x - y
+    return y - x
+
+def h(x):
+    return [x]
+";
+        let path = Path::new("foo.py");
+        let parser = PythonParser::new(source.as_bytes().to_vec(), path, None);
+        let report = find_clones(&parser, path, 10);
+
+        insta::assert_json_snapshot!(
+            report,
+            @r###"
+            {
+              "groups": [
+                {
+                  "tokens": 17,
+                  "similarity": 0.47058823529411764,
+                  "fragments": [
+                    {
+                      "path": "foo.py",
+                      "function": "f",
+                      "start_line": 1,
+                      "end_line": 4
+                    },
+                    {
+                      "path": "foo.py",
+                      "function": "g",
+                      "start_line": 6,
+                      "end_line": 9
+                    }
+                  ]
+                }
+              ],
+              "files": {
+                "foo.py": {
+                  "lines": 12,
+                  "duplicated_lines": 8,
+                  "percentage": 66.66666666666667
+                }
+              }
+            }"###
+        );
+    }
+
+    #[test]
+    fn java_exact_clone_across_files() {
+        let mut results = CloneResults::default();
+        collect::<JavaParser>(
+            &mut results,
+            "a/A.java",
+            "class A {
+                 int sum(int[] values) {
+                     int total = 0;
+                     for (int v : values) {
+                         total += v;
+                     }
+                     return total;
+                 }
+             }",
+        );
+        collect::<JavaParser>(
+            &mut results,
+            "b/B.java",
+            "class B {
+                 void log() {
+                     System.out.println(42);
+                 }
+                 int sum(int[] values) {
+                     int total = 0;
+                     for (int v : values) {
+                         total += v;
+                     }
+                     return total;
+                 }
+             }",
+        );
+        let report = CloneReport::new(&results, 10);
+
+        assert_eq!(report.groups.len(), 1);
+        let group = &report.groups[0];
+        assert_eq!(group.similarity, 1.);
+        let fragments = group
+            .fragments
+            .iter()
+            .map(|f| (f.path.to_str().unwrap(), f.start_line, f.end_line))
+            .collect::<Vec<_>>();
+        assert_eq!(fragments, [("a/A.java", 2, 7), ("b/B.java", 5, 10)]);
+        assert_eq!(report.files[Path::new("a/A.java")].duplicated_lines, 6);
+        assert_eq!(report.files[Path::new("b/B.java")].duplicated_lines, 6);
+    }
+
+    #[test]
+    fn short_and_different_functions() {
+        let source = "fn f(a: u32) -> u32 { a + 1 }
+fn g(b: u32) -> u32 { b + 1 }
+fn h(c: &str) -> bool { c.is_empty() }";
+        let path = Path::new("foo.rs");
+        let parser = RustParser::new(source.as_bytes().to_vec(), path, None);
+
+        assert!(find_clones(&parser, path, 50).groups.is_empty());
+        let report = find_clones(&parser, path, 8);
+        assert_eq!(report.groups.len(), 1);
+        assert_eq!(report.groups[0].fragments.len(), 2);
+        assert_eq!(report.files[path].duplicated_lines, 2);
+    }
+}
@@ -0,0 +1,356 @@
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::Checker;
+use crate::node::Node;
+use crate::traits::*;
+
+// The maximum ratio of syntax errors to the nodes of a parsed comment
+const MAX_ERROR_RATIO: f64 = 0.1;
+// The minimum number of nodes of a parsed comment, so that a single word,
+// parsed as an identifier, is not considered code
+const MIN_NODES: usize = 3;
+// The prefixes of the comments driving tools, such as type checkers
+// and linters, which are never considered code
+const PRAGMAS: &[&str] = &["type:", "noqa", "pylint:", "NOLINT", "eslint-disable"];
+
+/// A comment, or a block of consecutive comments, containing code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeComment {
+    /// The first line of a comment
+    pub start_line: usize,
+    /// The last line of a comment
+    pub end_line: usize,
+    /// The first byte of a comment
+    pub start_byte: usize,
+    /// The byte following the end of a comment
+    pub end_byte: usize,
+    /// The number of statements parsed from a comment
+    pub statements: usize,
+    /// The text of a comment without the comment markers
+    pub code: String,
+}
+
+/// The commented-out code of a file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommentedCode {
+    /// The name of a file
+    pub name: Option<String>,
+    /// The number of lines of commented-out code
+    pub lines: usize,
+    /// The comments containing code
+    pub comments: Vec<CodeComment>,
+}
+
+impl fmt::Display for CommentedCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.comments.is_empty() {
+            return Ok(());
+        }
+        writeln!(
+            f,
+            "{}: {} lines of commented-out code",
+            self.name.as_deref().unwrap_or_default(),
+            self.lines
+        )?;
+        for comment in &self.comments {
+            writeln!(
+                f,
+                "  - lines {}-{}: {} statements",
+                comment.start_line, comment.end_line, comment.statements
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Checks whether only blanks precede a node on its first line
+fn is_on_own_line(node: &Node, code: &[u8]) -> bool {
+    code[..node.start_byte()]
+        .iter()
+        .rev()
+        .take_while(|c| **c != b'\n')
+        .all(|c| c.is_ascii_whitespace())
+}
+
+// Returns the last row of a node, since the line comments of some grammars
+// contain the line break ending them
+fn last_row(node: &Node) -> usize {
+    match node.end_position() {
+        (row, 0) if row > node.start_row() => row - 1,
+        (row, _) => row,
+    }
+}
+
+// Removes the comment markers from each line of a comment and
+// the indentation shared by all its lines.
+fn strip_comment_markers(text: &str) -> String {
+    let lines: Vec<&str> = text
+        .lines()
+        .map(|line| {
+            let line = line.trim_end();
+            let trimmed = line.trim_start();
+            let line = if let Some(rest) = trimmed.strip_prefix("/*") {
+                rest.trim_start_matches(['*', '!'])
+            } else if trimmed.starts_with("//") {
+                trimmed.trim_start_matches('/').trim_start_matches('!')
+            } else if trimmed.starts_with('#') {
+                trimmed.trim_start_matches('#')
+            } else if trimmed.starts_with("*/") {
+                ""
+            } else if let Some(rest) = trimmed.strip_prefix('*') {
+                rest
+            } else {
+                line
+            };
+            line.strip_suffix("*/").unwrap_or(line).trim_end()
+        })
+        .collect();
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Checks whether a comment is a directive for a tool
+fn is_pragma(text: &str) -> bool {
+    let text = strip_comment_markers(text);
+    let text = text.trim_start();
+    PRAGMAS.iter().any(|pragma| text.starts_with(pragma))
+}
+
+// Parses the text of a comment, returning the number of its statements
+// when it is code.
+fn parse_comment<T: ParserTrait>(text: &str, path: &Path) -> Option<usize> {
+    if text.trim().is_empty() {
+        return None;
+    }
+    let parser = T::new(text.as_bytes().to_vec(), path, None);
+    let root = parser.get_root();
+
+    let mut nodes = 0;
+    let mut errors = 0;
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.is_error() {
+            errors += 1;
+        }
+        if node.is_named() {
+            nodes += 1;
+        }
+        stack.extend(node.children());
+    }
+    let statements = root
+        .children()
+        .filter(|node| node.is_named() && !node.is_error() && !T::Checker::is_comment(node))
+        .count();
+
+    (statements > 0 && nodes > MIN_NODES && errors as f64 <= MAX_ERROR_RATIO * nodes as f64)
+        .then_some(statements)
+}
+
+/// Returns the comments of a code containing code written in the
+/// same language.
+///
+/// Only the comments on their own lines are considered, except for the
+/// directives for tools such as `# type: ignore` or `// NOLINT`.
+/// Consecutive comments are analyzed as a single block,
+/// then the comment markers are removed and the text is parsed again:
+/// a comment is considered code when the parsed text contains
+/// few syntax errors.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{commented_code, ParserTrait, PythonParser};
+///
+/// let source_code = "# x = compute(1, 2)\n# print(x)\ny = 1\n";
+/// let path = Path::new("foo.py");
+/// let parser = PythonParser::new(source_code.as_bytes().to_vec(), path, None);
+///
+/// let commented = commented_code(&parser, path);
+/// assert_eq!(commented.lines, 2);
+/// ```
+pub fn commented_code<T: ParserTrait>(parser: &T, path: &Path) -> CommentedCode {
+    let code = parser.get_code();
+    let root = parser.get_root();
+
+    // Collect the blocks of consecutive comments
+    let mut blocks: Vec<Vec<Node>> = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if T::Checker::is_comment(&node) {
+            // Trailing comments and pragmas are never commented-out code
+            if !is_on_own_line(&node, code) || node.utf8_text(code).is_some_and(is_pragma) {
+                continue;
+            }
+            let extends_last = blocks
+                .last()
+                .and_then(|block| block.last())
+                .is_some_and(|last| {
+                    last_row(last) + 1 == node.start_row()
+                        && node
+                            .previous_sibling()
+                            .is_some_and(|prev| prev.id() == last.id())
+                });
+            if extends_last {
+                blocks.last_mut().unwrap().push(node);
+            } else {
+                blocks.push(vec![node]);
+            }
+            continue;
+        }
+        let mut children: Vec<_> = node.children().collect();
+        children.reverse();
+        stack.extend(children);
+    }
+
+    let mut commented = CommentedCode {
+        name: path.to_str().map(|name| name.to_string()),
+        ..Default::default()
+    };
+    for block in blocks {
+        let (first, last) = (block[0], block[block.len() - 1]);
+        let text = block
+            .iter()
+            .filter_map(|node| node.utf8_text(code))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let stripped = strip_comment_markers(&text);
+        if let Some(statements) = parse_comment::<T>(&stripped, path) {
+            let start_line = first.start_row() + 1;
+            let end_line = last_row(&last) + 1;
+            commented.lines += end_line - start_line + 1;
+            commented.comments.push(CodeComment {
+                start_line,
+                end_line,
+                start_byte: first.start_byte(),
+                end_byte: last.end_byte(),
+                statements,
+                code: stripped,
+            });
+        }
+    }
+
+    commented
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{JavascriptParser, PythonParser, RustParser};
+
+    use super::*;
+
+    fn check<T: ParserTrait>(source: &str, filename: &str) -> CommentedCode {
+        let path = PathBuf::from(filename);
+        let parser = T::new(source.as_bytes().to_vec(), &path, None);
+        commented_code(&parser, &path)
+    }
+
+    #[test]
+    fn python_blocks() {
+        let commented = check::<PythonParser>(
+            "# Compute the sum of the values.
+def f(values):
+    # total = 0
+    # for v in values:
+    #     total += v
+    return sum(values)  # TODO
+",
+            "foo.py",
+        );
+        assert_eq!(commented.lines, 3);
+        assert_eq!(commented.comments.len(), 1);
+        let comment = &commented.comments[0];
+        assert_eq!((comment.start_line, comment.end_line), (3, 5));
+        assert_eq!(comment.statements, 2);
+        assert_eq!(comment.code, "total = 0\nfor v in values:\n    total += v");
+    }
+
+    #[test]
+    fn rust_line_and_block_comments() {
+        let commented = check::<RustParser>(
+            "/// Returns the answer.
+fn f() -> u32 {
+    // let x = compute(1, 2);
+    // println!(\"{}\", x);
+
+    /* if x > 1 {
+        return x;
+    } */
+
+    // The answer is known.
+    42
+}
+",
+            "foo.rs",
+        );
+        let lines: Vec<_> = commented
+            .comments
+            .iter()
+            .map(|comment| (comment.start_line, comment.end_line))
+            .collect();
+        assert_eq!(lines, [(3, 4), (6, 8)]);
+        assert_eq!(commented.lines, 5);
+    }
+
+    #[test]
+    fn javascript_prose() {
+        let commented = check::<JavascriptParser>(
+            "// This function does nothing.
+// See the documentation for more details.
+function f() {}
+/**
+ * Returns a value.
+ * @returns {number} the value
+ */
+function g() { return 1; }
+// g();
+",
+            "foo.js",
+        );
+        assert_eq!(commented.comments.len(), 1);
+        assert_eq!(commented.comments[0].start_line, 9);
+    }
+
+    #[test]
+    fn python_pragmas() {
+        let commented = check::<PythonParser>(
+            "# pylint: disable=invalid-name
+x = f()  # type: ignore
+y = g(x)  # z = h(y)
+# type: (int) -> int
+# noqa: E501
+",
+            "foo.py",
+        );
+        assert!(commented.comments.is_empty());
+        assert_eq!(commented.lines, 0);
+    }
+
+    #[test]
+    fn javascript_pragmas() {
+        let commented = check::<JavascriptParser>(
+            "// eslint-disable-next-line no-undef
+f(x);
+g(y); // h(z);
+// NOLINT(whitespace/braces)
+",
+            "foo.js",
+        );
+        assert!(commented.comments.is_empty());
+    }
+}
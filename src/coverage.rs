@@ -0,0 +1,354 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::crap;
+use crate::spaces::{FuncSpace, SpaceKind};
+
+/// The coverage data of a line.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LineCoverage {
+    /// The number of times a line has been executed
+    pub hits: u64,
+    /// The number of branches starting from a line
+    pub branches: usize,
+    /// The number of branches starting from a line taken at least once
+    pub covered_branches: usize,
+}
+
+/// The coverage data of a file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileCoverage {
+    /// The coverage data of the lines of a file, indexed by line number
+    pub lines: BTreeMap<usize, LineCoverage>,
+}
+
+impl FileCoverage {
+    fn add_line(&mut self, line: usize, hits: u64) {
+        let entry = self.lines.entry(line).or_default();
+        entry.hits = entry.hits.max(hits);
+    }
+
+    fn add_branches(&mut self, line: usize, branches: usize, covered_branches: usize) {
+        let entry = self.lines.entry(line).or_default();
+        entry.branches = entry.branches.max(branches);
+        entry.covered_branches = entry.covered_branches.max(covered_branches);
+    }
+
+    // Returns the number of lines, covered lines, branches and covered branches
+    // contained in the range from `start_line` to `end_line`.
+    fn range(&self, start_line: usize, end_line: usize) -> (usize, usize, usize, usize) {
+        if start_line == 0 || start_line > end_line {
+            return (0, 0, 0, 0);
+        }
+        self.lines
+            .range(start_line..=end_line)
+            .fold((0, 0, 0, 0), |(l, cl, b, cb), (_, line)| {
+                (
+                    l + 1,
+                    cl + usize::from(line.hits > 0),
+                    b + line.branches,
+                    cb + line.covered_branches,
+                )
+            })
+    }
+}
+
+/// The coverage data of the files contained in a coverage report.
+///
+/// The `lcov` and the `Cobertura` XML formats are supported.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Coverage {
+    /// The coverage data of each file, indexed by the path
+    /// written in the coverage report
+    pub files: HashMap<PathBuf, FileCoverage>,
+}
+
+static RE_XML_TAG: OnceLock<Regex> = OnceLock::new();
+static RE_XML_ATTR: OnceLock<Regex> = OnceLock::new();
+static RE_CONDITIONS: OnceLock<Regex> = OnceLock::new();
+
+// Regular expressions
+const XML_TAG_EXPRESSION: &str = r"<(/?)(class|line)\b([^>]*)>";
+const XML_ATTR_EXPRESSION: &str = r#"([\w-]+)\s*=\s*"([^"]*)""#;
+const CONDITIONS_EXPRESSION: &str = r"\((\d+)/(\d+)\)";
+
+fn get_attrs(attrs: &str) -> HashMap<&str, &str> {
+    RE_XML_ATTR
+        .get_or_init(|| Regex::new(XML_ATTR_EXPRESSION).unwrap())
+        .captures_iter(attrs)
+        .filter_map(|cap| Some((cap.get(1)?.as_str(), cap.get(2)?.as_str())))
+        .collect()
+}
+
+impl Coverage {
+    /// Parses a coverage report, detecting whether it is written
+    /// in the `Cobertura` XML format or in the `lcov` format.
+    pub fn new(data: &str) -> Self {
+        if data.trim_start().starts_with('<') {
+            Self::from_cobertura(data)
+        } else {
+            Self::from_lcov(data)
+        }
+    }
+
+    /// Parses a coverage report written in the `lcov` format.
+    pub fn from_lcov(data: &str) -> Self {
+        let mut coverage = Self::default();
+        let mut current: Option<(PathBuf, FileCoverage)> = None;
+        let mut branches: BTreeMap<(usize, String, String), bool> = BTreeMap::new();
+
+        for line in data.lines() {
+            let line = line.trim();
+            let (key, value) = line.split_once(':').unwrap_or((line, ""));
+            match key {
+                "SF" => {
+                    current = Some((PathBuf::from(value), FileCoverage::default()));
+                    branches.clear();
+                }
+                "DA" => {
+                    let mut fields = value.split(',');
+                    if let (Some((_, file)), Some(Ok(number)), Some(Ok(hits))) = (
+                        current.as_mut(),
+                        fields.next().map(str::parse::<usize>),
+                        fields.next().map(str::parse::<u64>),
+                    ) {
+                        file.add_line(number, hits);
+                    }
+                }
+                "BRDA" => {
+                    let fields: Vec<_> = value.split(',').collect();
+                    if let [number, block, branch, taken] = fields[..] {
+                        if let Ok(number) = number.parse::<usize>() {
+                            let taken = taken.parse::<u64>().is_ok_and(|taken| taken > 0);
+                            *branches
+                                .entry((number, block.to_string(), branch.to_string()))
+                                .or_default() |= taken;
+                        }
+                    }
+                }
+                "end_of_record" => {
+                    if let Some((path, mut file)) = current.take() {
+                        for ((number, _, _), taken) in branches.iter() {
+                            let entry = file.lines.entry(*number).or_default();
+                            entry.branches += 1;
+                            entry.covered_branches += usize::from(*taken);
+                        }
+                        branches.clear();
+                        coverage.add_file(path, file);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        coverage
+    }
+
+    /// Parses a coverage report written in the `Cobertura` XML format.
+    pub fn from_cobertura(data: &str) -> Self {
+        let mut coverage = Self::default();
+        let mut current: Option<(PathBuf, FileCoverage)> = None;
+
+        let re_tag = RE_XML_TAG.get_or_init(|| Regex::new(XML_TAG_EXPRESSION).unwrap());
+        let re_conditions =
+            RE_CONDITIONS.get_or_init(|| Regex::new(CONDITIONS_EXPRESSION).unwrap());
+        for cap in re_tag.captures_iter(data) {
+            let closing = !cap[1].is_empty();
+            match (&cap[2], closing) {
+                ("class", false) => {
+                    let attrs = get_attrs(&cap[3]);
+                    current = attrs
+                        .get("filename")
+                        .map(|filename| (PathBuf::from(filename), FileCoverage::default()));
+                    if cap[3].ends_with('/') {
+                        if let Some((path, file)) = current.take() {
+                            coverage.add_file(path, file);
+                        }
+                    }
+                }
+                ("class", true) => {
+                    if let Some((path, file)) = current.take() {
+                        coverage.add_file(path, file);
+                    }
+                }
+                ("line", false) => {
+                    let Some((_, file)) = current.as_mut() else {
+                        continue;
+                    };
+                    let attrs = get_attrs(&cap[3]);
+                    let (Some(Ok(number)), Some(Ok(hits))) = (
+                        attrs.get("number").map(|n| n.parse::<usize>()),
+                        attrs.get("hits").map(|h| h.parse::<u64>()),
+                    ) else {
+                        continue;
+                    };
+                    file.add_line(number, hits);
+                    if attrs.get("branch") == Some(&"true") {
+                        // condition-coverage="50% (1/2)"
+                        let conditions = attrs.get("condition-coverage").and_then(|c| {
+                            let cap = re_conditions.captures(c)?;
+                            Some((cap[2].parse().ok()?, cap[1].parse().ok()?))
+                        });
+                        if let Some((branches, covered_branches)) = conditions {
+                            file.add_branches(number, branches, covered_branches);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        coverage
+    }
+
+    fn add_file(&mut self, path: PathBuf, file: FileCoverage) {
+        let entry = self.files.entry(path).or_default();
+        for (number, line) in file.lines {
+            entry.add_line(number, line.hits);
+            entry.add_branches(number, line.branches, line.covered_branches);
+        }
+    }
+
+    /// Returns the coverage data of a file.
+    ///
+    /// The paths written in a coverage report are often relative
+    /// to a different directory, so when there is not an exact match
+    /// the longest path being a suffix of the other one is chosen.
+    pub fn get(&self, path: &Path) -> Option<&FileCoverage> {
+        self.files.get(path).or_else(|| {
+            self.files
+                .iter()
+                .filter(|(file, _)| path.ends_with(file) || file.ends_with(path))
+                .max_by_key(|(file, _)| (file.components().count(), *file))
+                .map(|(_, coverage)| coverage)
+        })
+    }
+
+    /// Sets the `Crap` metric of the function spaces
+    /// contained in the function space of a file.
+    pub fn annotate(&self, space: &mut FuncSpace, path: &Path) {
+        if let Some(file) = self.get(path) {
+            Self::annotate_space(file, space);
+        }
+    }
+
+    fn annotate_space(file: &FileCoverage, space: &mut FuncSpace) -> crap::Stats {
+        let mut stats = crap::Stats::default();
+        let (lines, covered_lines, branches, covered_branches) =
+            file.range(space.start_line, space.end_line);
+        stats.set_coverage(lines, covered_lines, branches, covered_branches);
+        if matches!(space.kind, SpaceKind::Function | SpaceKind::Component) {
+            stats.compute_function(space.metrics.cyclomatic.cyclomatic());
+        }
+        for subspace in space.spaces.iter_mut() {
+            stats.merge(&Self::annotate_space(file, subspace));
+        }
+        space.metrics.crap = stats.clone();
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{metrics, ParserTrait, PythonParser};
+
+    use super::*;
+
+    const SOURCE: &str = "def f(a):
+    if a:
+        return 1
+    return 2
+
+def g(a):
+    return a
+";
+
+    fn annotate(coverage: &Coverage) -> FuncSpace {
+        let path = Path::new("/project/src/foo.py");
+        let parser = PythonParser::new(SOURCE.as_bytes().to_vec(), path, None);
+        let mut space = metrics(&parser, path).unwrap();
+        coverage.annotate(&mut space, path);
+        space
+    }
+
+    #[test]
+    fn lcov_crap() {
+        let coverage = Coverage::new(
+            "TN:
+SF:src/foo.py
+DA:2,1
+DA:3,0
+DA:4,1
+DA:7,0
+BRDA:2,0,0,0
+BRDA:2,0,1,1
+end_of_record
+",
+        );
+        let space = annotate(&coverage);
+
+        // f: cyclomatic = 2, coverage = 2/3
+        let f = &space.spaces[0].metrics.crap;
+        assert_eq!(f.lines(), 3.);
+        assert_eq!(f.covered_lines(), 2.);
+        assert_eq!(f.branches(), 2.);
+        assert_eq!(f.covered_branches(), 1.);
+        assert!((f.crap() - (4. / 27. + 2.)).abs() < 1e-9);
+
+        // g: cyclomatic = 1, coverage = 0
+        let g = &space.spaces[1].metrics.crap;
+        assert_eq!(g.crap(), 2.);
+
+        let unit = &space.metrics.crap;
+        assert_eq!(unit.lines(), 4.);
+        assert_eq!(unit.crap_max(), f.crap());
+        assert!((unit.crap_sum() - (4. / 27. + 4.)).abs() < 1e-9);
+        assert!((unit.crap_average() - (4. / 27. + 4.) / 2.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cobertura_crap() {
+        let coverage = Coverage::new(
+            r#"<?xml version="1.0" ?>
+<coverage version="7.4" line-rate="0.75" branch-rate="1">
+  <sources><source>/project</source></sources>
+  <packages>
+    <package name="src" line-rate="0.75">
+      <classes>
+        <class name="foo.py" filename="src/foo.py" line-rate="0.75">
+          <methods/>
+          <lines>
+            <line number="2" hits="1" branch="true" condition-coverage="100% (2/2)"/>
+            <line number="3" hits="1"/>
+            <line number="4" hits="0"/>
+            <line number="7" hits="1"/>
+          </lines>
+        </class>
+      </classes>
+    </package>
+  </packages>
+</coverage>
+"#,
+        );
+        let space = annotate(&coverage);
+
+        let f = &space.spaces[0].metrics.crap;
+        assert_eq!(f.line_coverage(), 2. / 3.);
+        assert_eq!(f.branch_coverage(), 1.);
+
+        let g = &space.spaces[1].metrics.crap;
+        assert_eq!(g.line_coverage(), 1.);
+        assert_eq!(g.crap(), 1.);
+    }
+
+    #[test]
+    fn missing_file() {
+        let coverage = Coverage::new("SF:src/bar.py\nDA:1,1\nend_of_record\n");
+        let space = annotate(&coverage);
+        assert!(space.metrics.crap.is_disabled());
+    }
+}
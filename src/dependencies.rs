@@ -0,0 +1,758 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ffi::OsString;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use petgraph::{algo::kosaraju_scc, graph::NodeIndex, Graph};
+use serde::{Deserialize, Serialize};
+
+use crate::getter::Getter;
+use crate::langs::*;
+use crate::tools::{get_paths_dist, guess_file, normalize_path};
+use crate::traits::*;
+
+/// Imports and declared types of a file.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DependencyFile {
+    /// The name of the language of the file
+    pub language: String,
+    /// The modules, packages or files imported by the file,
+    /// as they are written in the code
+    pub imports: Vec<String>,
+    /// The number of types declared in the file
+    pub types: usize,
+    /// The number of abstract types, such as interfaces,
+    /// declared in the file
+    pub abstract_types: usize,
+}
+
+/// Imports and declared types of a series of files.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DependencyResults {
+    /// The imports and declared types of each file
+    pub files: HashMap<PathBuf, DependencyFile>,
+}
+
+/// Returns the imports and declared types contained in a code.
+pub fn get_dependencies<T: ParserTrait>(parser: &T) -> DependencyFile {
+    let code = parser.get_code();
+    let node = parser.get_root();
+    let mut cursor = node.cursor();
+    let mut stack = Vec::new();
+    let mut file = DependencyFile {
+        language: parser.get_language().get_name().to_string(),
+        ..Default::default()
+    };
+    let mut imports = Vec::new();
+
+    stack.push(node);
+
+    while let Some(node) = stack.pop() {
+        for import in T::Getter::get_imports(&node, code) {
+            imports.push((node.start_byte(), import));
+        }
+        if let Some(is_abstract) = T::Getter::get_abstractness(&node, code) {
+            file.types += 1;
+            if is_abstract {
+                file.abstract_types += 1;
+            }
+        }
+
+        cursor.reset(&node);
+        if cursor.goto_first_child() {
+            loop {
+                stack.push(cursor.node());
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    imports.sort_by_key(|(start, _)| *start);
+    file.imports = imports.into_iter().map(|(_, import)| import).collect();
+    file
+}
+
+/// Configuration options for collecting the imports and declared types of a code.
+#[derive(Debug)]
+pub struct DependenciesCfg {
+    /// Path to the file containing the code
+    pub path: PathBuf,
+    /// The imports and declared types of the files analyzed so far
+    pub results: Arc<Mutex<DependencyResults>>,
+}
+
+pub struct Dependencies {
+    _guard: (),
+}
+
+impl Callback for Dependencies {
+    type Res = std::io::Result<()>;
+    type Cfg = DependenciesCfg;
+
+    fn call<T: ParserTrait>(cfg: Self::Cfg, parser: &T) -> Self::Res {
+        let file = get_dependencies(parser);
+        let mut results = cfg.results.lock().unwrap();
+        results.files.insert(cfg.path, file);
+        Ok(())
+    }
+}
+
+/// The Martin's metrics of a package.
+///
+/// A package is the directory containing a series of files,
+/// and the couplings are counted in files.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct PackageMetrics {
+    /// The number of files in the package
+    pub files: usize,
+    /// The number of types declared in the package
+    pub types: usize,
+    /// The number of abstract types declared in the package
+    pub abstract_types: usize,
+    /// Afferent coupling: the number of files outside the package
+    /// depending on files inside the package
+    pub ca: usize,
+    /// Efferent coupling: the number of files inside the package
+    /// depending on files outside the package
+    pub ce: usize,
+    /// Instability: `Ce / (Ca + Ce)`, or 0 for an isolated package
+    pub instability: f64,
+    /// Abstractness: the ratio of abstract types to all the types
+    /// of the package, or 0 for a package without types
+    pub abstractness: f64,
+    /// Distance from the main sequence: `|A + I - 1|`
+    pub distance: f64,
+}
+
+/// The dependency graph of a series of files and the metrics of their packages.
+///
+/// An import is resolved only when it refers to one of the analyzed files,
+/// so the dependencies on external libraries are reported as unresolved.
+#[derive(Debug, Default, Serialize)]
+pub struct DependencyGraph {
+    /// The files each file depends on
+    pub files: BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+    /// The imports of each file which have not been resolved
+    pub unresolved: BTreeMap<PathBuf, Vec<String>>,
+    /// The metrics of each package
+    pub packages: BTreeMap<PathBuf, PackageMetrics>,
+    /// The packages which depend on each other
+    pub cycles: Vec<Vec<PathBuf>>,
+}
+
+// The analyzed files, indexed by file name
+struct FileIndex<'a> {
+    paths: HashSet<&'a Path>,
+    names: HashMap<String, Vec<PathBuf>>,
+}
+
+impl<'a> FileIndex<'a> {
+    fn new(results: &'a DependencyResults) -> Self {
+        let mut names: HashMap<String, Vec<PathBuf>> = HashMap::new();
+        for path in results.files.keys() {
+            if let Some(name) = path.file_name() {
+                names
+                    .entry(name.to_string_lossy().to_string())
+                    .or_default()
+                    .push(path.clone());
+            }
+        }
+        Self {
+            paths: results.files.keys().map(|path| path.as_path()).collect(),
+            names,
+        }
+    }
+
+    fn get(&self, path: &Path) -> Option<PathBuf> {
+        self.paths.get(path).map(|path| path.to_path_buf())
+    }
+
+    // Returns the files ending with the given relative path
+    fn with_suffix(&self, suffix: &Path) -> Vec<PathBuf> {
+        suffix
+            .file_name()
+            .and_then(|name| self.names.get(name.to_string_lossy().as_ref()))
+            .map(|paths| {
+                paths
+                    .iter()
+                    .filter(|path| path.ends_with(suffix))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // Returns the files with the given extension contained
+    // in the directories ending with the given relative path
+    fn in_dir(&self, dir: &Path, ext: &str) -> Vec<PathBuf> {
+        let mut paths = self
+            .paths
+            .iter()
+            .filter(|path| {
+                path.extension().is_some_and(|e| e == ext)
+                    && path.parent().is_some_and(|parent| parent.ends_with(dir))
+            })
+            .map(|path| path.to_path_buf())
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths
+    }
+}
+
+// Appends a suffix to a path without replacing its extension
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = OsString::from(path);
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+// Keeps the candidate closest to the importing file
+fn closest(path: &Path, mut candidates: Vec<PathBuf>) -> Vec<PathBuf> {
+    candidates.sort();
+    candidates
+        .into_iter()
+        .min_by_key(|candidate| get_paths_dist(path, candidate).unwrap_or(usize::MAX))
+        .into_iter()
+        .collect()
+}
+
+fn resolve_python(path: &Path, import: &str, index: &FileIndex) -> Vec<PathBuf> {
+    let find = |segments: &[&str], base: Option<&Path>| {
+        let module = segments.iter().collect::<PathBuf>();
+        let candidates = [with_suffix(&module, ".py"), module.join("__init__.py")];
+        match base {
+            Some(base) => candidates
+                .iter()
+                .find_map(|candidate| index.get(&normalize_path(base.join(candidate))))
+                .into_iter()
+                .collect(),
+            None => candidates
+                .iter()
+                .filter(|_| !segments.is_empty())
+                .map(|candidate| index.with_suffix(candidate))
+                .find(|found| !found.is_empty())
+                .unwrap_or_default(),
+        }
+    };
+
+    // `from ..a import b` is relative to the parent package
+    let dots = import.chars().take_while(|c| *c == '.').count();
+    let base = (dots > 0).then(|| {
+        let mut base = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        for _ in 1..dots {
+            base.push("..");
+        }
+        base
+    });
+    let rest = &import[dots..];
+    let segments = rest
+        .split('.')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+
+    // The last segments may be objects of a module rather than modules
+    for k in (0..=segments.len()).rev() {
+        let found = find(&segments[..k], base.as_deref());
+        if !found.is_empty() {
+            return closest(path, found);
+        }
+    }
+    Vec::new()
+}
+
+fn resolve_js(path: &Path, import: &str, index: &FileIndex) -> Vec<PathBuf> {
+    const EXTENSIONS: &[&str] = &["", ".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mjs", ".cjs"];
+
+    // Bare specifiers refer to packages
+    if !matches!(import, "." | "..")
+        && !import.starts_with("./")
+        && !import.starts_with("../")
+        && !import.starts_with('/')
+    {
+        return Vec::new();
+    }
+    let base = normalize_path(path.parent().unwrap_or(Path::new("")).join(import));
+    EXTENSIONS
+        .iter()
+        .map(|ext| with_suffix(&base, ext))
+        .chain(
+            EXTENSIONS[1..]
+                .iter()
+                .map(|ext| with_suffix(&base.join("index"), ext)),
+        )
+        .find_map(|candidate| index.get(&candidate))
+        .into_iter()
+        .collect()
+}
+
+fn resolve_jvm(path: &Path, import: &str, ext: &str, index: &FileIndex) -> Vec<PathBuf> {
+    let (import, wildcard) = match import.strip_suffix(".*") {
+        Some(import) => (import, true),
+        None => (import, false),
+    };
+    let segments = import.split('.').collect::<Vec<_>>();
+
+    // `import a.b.*` imports all the types of a package
+    if wildcard {
+        let found = index.in_dir(&segments.iter().collect::<PathBuf>(), ext);
+        if !found.is_empty() {
+            return found;
+        }
+    }
+    // `import static a.b.C.m` imports a member of a type
+    for k in (1..=segments.len()).rev() {
+        let found = index.with_suffix(&with_suffix(
+            &segments[..k].iter().collect::<PathBuf>(),
+            &format!(".{ext}"),
+        ));
+        if !found.is_empty() {
+            return closest(path, found);
+        }
+    }
+    // A file can declare types with another name than its own one
+    if !wildcard && segments.len() > 1 {
+        let dir = segments[..segments.len() - 1].iter().collect::<PathBuf>();
+        return index.in_dir(&dir, ext);
+    }
+    Vec::new()
+}
+
+fn resolve_rust(path: &Path, import: &str, index: &FileIndex) -> Vec<PathBuf> {
+    const ROOTS: &[&str] = &["lib.rs", "main.rs", "mod.rs"];
+
+    let module_file = |dir: &Path| {
+        [
+            dir.join("mod.rs"),
+            with_suffix(dir, ".rs"),
+            dir.join("lib.rs"),
+            dir.join("main.rs"),
+        ]
+        .iter()
+        .find_map(|candidate| index.get(candidate))
+    };
+
+    let parent = path.parent().unwrap_or(Path::new(""));
+    let is_root = path
+        .file_name()
+        .is_some_and(|name| ROOTS.iter().any(|root| name == *root));
+    // The directory containing the submodules of the current module
+    let module_dir = match (is_root, path.file_stem()) {
+        (false, Some(stem)) => parent.join(stem),
+        _ => parent.to_path_buf(),
+    };
+
+    let mut segments = import.split("::").collect::<Vec<_>>();
+    if segments.last() == Some(&"self") {
+        segments.pop();
+    }
+    // A path starting with another name refers either to a submodule
+    // of the current module or to an external crate
+    let mut min_len = 0;
+    let (mut dir, mut segments) = match segments.first() {
+        Some(&"crate") => {
+            let root = path
+                .ancestors()
+                .skip(1)
+                .find(|dir| {
+                    index.get(&dir.join("lib.rs")).is_some()
+                        || index.get(&dir.join("main.rs")).is_some()
+                })
+                .unwrap_or(parent);
+            (root.to_path_buf(), &segments[1..])
+        }
+        Some(&"self") => (module_dir, &segments[1..]),
+        Some(&"super") => (module_dir, &segments[..]),
+        _ => {
+            min_len = 1;
+            (module_dir, &segments[..])
+        }
+    };
+    while segments.first() == Some(&"super") {
+        dir.pop();
+        segments = &segments[1..];
+    }
+
+    // The last segments may be items of a module rather than modules
+    for k in (min_len..=segments.len()).rev() {
+        let module = segments[..k].iter().fold(dir.clone(), |dir, s| dir.join(s));
+        if let Some(found) = module_file(&module) {
+            return vec![found];
+        }
+    }
+    Vec::new()
+}
+
+fn resolve_cpp(path: &Path, import: &str, index: &FileIndex) -> Vec<PathBuf> {
+    let import = import.trim_start_matches('<').trim_end_matches('>');
+    if Path::new(import).file_name().is_none() {
+        return Vec::new();
+    }
+    guess_file(path, import, &index.names)
+}
+
+fn resolve(path: &Path, language: &str, import: &str, index: &FileIndex) -> Vec<PathBuf> {
+    let language = LANG::into_enum_iter().find(|lang| lang.get_name() == language);
+    match language {
+        Some(LANG::Python) => resolve_python(path, import, index),
+        Some(LANG::Mozjs | LANG::Javascript | LANG::Typescript | LANG::Tsx) => {
+            resolve_js(path, import, index)
+        }
+        Some(LANG::Java) => resolve_jvm(path, import, "java", index),
+        Some(LANG::Kotlin) => resolve_jvm(path, import, "kt", index),
+        Some(LANG::Rust) => resolve_rust(path, import, index),
+        Some(LANG::C | LANG::Cpp) => resolve_cpp(path, import, index),
+        _ => Vec::new(),
+    }
+}
+
+// Returns the package containing a file
+fn package(path: &Path) -> PathBuf {
+    path.parent().unwrap_or(Path::new("")).to_path_buf()
+}
+
+impl DependencyGraph {
+    /// Builds the dependency graph of a series of files.
+    pub fn new(results: &DependencyResults) -> Self {
+        let index = FileIndex::new(results);
+        let mut graph = Self::default();
+
+        for (path, file) in results.files.iter() {
+            let dependencies = graph.files.entry(path.clone()).or_default();
+            for import in file.imports.iter() {
+                let found = resolve(path, &file.language, import, &index);
+                if found.is_empty() {
+                    graph
+                        .unresolved
+                        .entry(path.clone())
+                        .or_default()
+                        .push(import.clone());
+                }
+                dependencies.extend(found.into_iter().filter(|found| found != path));
+            }
+        }
+
+        for (path, file) in results.files.iter() {
+            let package = graph.packages.entry(package(path)).or_default();
+            package.files += 1;
+            package.types += file.types;
+            package.abstract_types += file.abstract_types;
+        }
+
+        // An edge goes from a package to one of its dependencies
+        let mut g = Graph::new();
+        let nodes: HashMap<PathBuf, NodeIndex> = graph
+            .packages
+            .keys()
+            .map(|package| (package.clone(), g.add_node(package.clone())))
+            .collect();
+        let mut afferent: HashMap<PathBuf, HashSet<&Path>> = HashMap::new();
+        for (path, dependencies) in graph.files.iter() {
+            let from = package(path);
+            let mut efferent = false;
+            for dependency in dependencies {
+                let to = package(dependency);
+                if to != from {
+                    efferent = true;
+                    afferent.entry(to.clone()).or_default().insert(path);
+                    g.update_edge(nodes[&from], nodes[&to], ());
+                }
+            }
+            if efferent {
+                graph.packages.get_mut(&from).unwrap().ce += 1;
+            }
+        }
+
+        for (path, package) in graph.packages.iter_mut() {
+            package.ca = afferent.get(path).map_or(0, |files| files.len());
+            let couplings = package.ca + package.ce;
+            package.instability = if couplings == 0 {
+                0.
+            } else {
+                package.ce as f64 / couplings as f64
+            };
+            package.abstractness = if package.types == 0 {
+                0.
+            } else {
+                package.abstract_types as f64 / package.types as f64
+            };
+            package.distance = (package.abstractness + package.instability - 1.).abs();
+        }
+
+        graph.cycles = kosaraju_scc(&g)
+            .into_iter()
+            .filter(|component| component.len() > 1)
+            .map(|component| {
+                let mut packages = component
+                    .into_iter()
+                    .map(|node| g[node].clone())
+                    .collect::<Vec<_>>();
+                packages.sort();
+                packages
+            })
+            .collect();
+        graph.cycles.sort();
+
+        graph
+    }
+}
+
+impl fmt::Display for DependencyGraph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (path, package) in self.packages.iter() {
+            writeln!(
+                f,
+                "{}: files: {}, types: {}, abstract_types: {}, ca: {}, ce: {}, instability: {}, abstractness: {}, distance: {}",
+                path.display(),
+                package.files,
+                package.types,
+                package.abstract_types,
+                package.ca,
+                package.ce,
+                package.instability,
+                package.abstractness,
+                package.distance
+            )?;
+        }
+        for cycle in self.cycles.iter() {
+            let cycle = cycle
+                .iter()
+                .map(|package| package.display().to_string())
+                .collect::<Vec<_>>();
+            writeln!(f, "Cycle: {}", cycle.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JavaParser, PythonParser, RustParser, TypescriptParser};
+
+    use super::*;
+
+    fn collect<T: ParserTrait>(results: &mut DependencyResults, path: &str, source: &str) {
+        let path = Path::new(path);
+        let parser = T::new(source.as_bytes().to_vec(), path, None);
+        results
+            .files
+            .insert(path.to_path_buf(), get_dependencies(&parser));
+    }
+
+    fn dependencies<'a>(graph: &'a DependencyGraph, path: &str) -> Vec<&'a str> {
+        graph.files[Path::new(path)]
+            .iter()
+            .map(|path| path.to_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn python_imports() {
+        let source = "import os.path, a.b as c
+from . import d
+from ..e import f, g as h
+from .i.j import *
+from k import l";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), Path::new("foo.py"), None);
+        let file = get_dependencies(&parser);
+
+        assert_eq!(
+            file.imports,
+            ["os.path", "a.b", ".d", "..e.f", "..e.g", ".i.j", "k.l"]
+        );
+    }
+
+    #[test]
+    fn python_packages() {
+        let mut results = DependencyResults::default();
+        collect::<PythonParser>(
+            &mut results,
+            "pkg/core/base.py",
+            "from abc import ABC
+class Base(ABC): pass
+class Impl(Base): pass",
+        );
+        collect::<PythonParser>(&mut results, "pkg/core/__init__.py", "");
+        collect::<PythonParser>(
+            &mut results,
+            "pkg/app/main.py",
+            "import json
+from ..core import base
+from pkg.core.base import Impl",
+        );
+        let graph = DependencyGraph::new(&results);
+
+        assert_eq!(
+            dependencies(&graph, "pkg/app/main.py"),
+            ["pkg/core/base.py"]
+        );
+        assert_eq!(graph.unresolved[Path::new("pkg/app/main.py")], ["json"]);
+        insta::assert_json_snapshot!(
+            graph.packages,
+            @r###"
+            {
+              "pkg/app": {
+                "files": 1,
+                "types": 0,
+                "abstract_types": 0,
+                "ca": 0,
+                "ce": 1,
+                "instability": 1.0,
+                "abstractness": 0.0,
+                "distance": 0.0
+              },
+              "pkg/core": {
+                "files": 2,
+                "types": 2,
+                "abstract_types": 1,
+                "ca": 1,
+                "ce": 0,
+                "instability": 0.0,
+                "abstractness": 0.5,
+                "distance": 0.5
+              }
+            }"###
+        );
+    }
+
+    #[test]
+    fn java_packages() {
+        let mut results = DependencyResults::default();
+        collect::<JavaParser>(
+            &mut results,
+            "src/org/foo/api/Service.java",
+            "package org.foo.api;
+             public interface Service {}",
+        );
+        collect::<JavaParser>(
+            &mut results,
+            "src/org/foo/api/Base.java",
+            "package org.foo.api;
+             public abstract class Base implements Service {}",
+        );
+        collect::<JavaParser>(
+            &mut results,
+            "src/org/foo/impl/Impl.java",
+            "package org.foo.impl;
+             import java.util.List;
+             import org.foo.api.*;
+             import static org.foo.api.Base.helper;
+             public class Impl extends Base {}",
+        );
+        let graph = DependencyGraph::new(&results);
+
+        assert_eq!(
+            dependencies(&graph, "src/org/foo/impl/Impl.java"),
+            ["src/org/foo/api/Base.java", "src/org/foo/api/Service.java"]
+        );
+        assert_eq!(
+            graph.unresolved[Path::new("src/org/foo/impl/Impl.java")],
+            ["java.util.List"]
+        );
+        let api = &graph.packages[Path::new("src/org/foo/api")];
+        assert_eq!(
+            (api.ca, api.ce, api.types, api.abstract_types),
+            (1, 0, 2, 2)
+        );
+        assert_eq!(
+            (api.instability, api.abstractness, api.distance),
+            (0., 1., 0.)
+        );
+        let implementation = &graph.packages[Path::new("src/org/foo/impl")];
+        assert_eq!((implementation.ca, implementation.ce), (0, 1));
+        assert_eq!(implementation.distance, 0.);
+    }
+
+    #[test]
+    fn typescript_imports() {
+        let mut results = DependencyResults::default();
+        collect::<TypescriptParser>(
+            &mut results,
+            "src/model/index.ts",
+            "export interface Shape {}
+             export abstract class Base implements Shape {}
+             export * from './circle';",
+        );
+        collect::<TypescriptParser>(
+            &mut results,
+            "src/model/circle.ts",
+            "import { Base } from '.';
+             export class Circle extends Base {}",
+        );
+        collect::<TypescriptParser>(
+            &mut results,
+            "src/app.ts",
+            "import { Circle } from './model/circle';
+             import * as fs from 'fs';
+             const model = require('./model');
+             const lazy = import('./missing');",
+        );
+        let graph = DependencyGraph::new(&results);
+
+        assert_eq!(
+            dependencies(&graph, "src/app.ts"),
+            ["src/model/circle.ts", "src/model/index.ts"]
+        );
+        assert_eq!(
+            graph.unresolved[Path::new("src/app.ts")],
+            ["fs", "./missing"]
+        );
+        assert_eq!(
+            dependencies(&graph, "src/model/circle.ts"),
+            ["src/model/index.ts"]
+        );
+        let model = &graph.packages[Path::new("src/model")];
+        assert_eq!((model.types, model.abstract_types), (3, 2));
+        assert_eq!((model.ca, model.ce), (1, 0));
+    }
+
+    #[test]
+    fn rust_modules() {
+        let mut results = DependencyResults::default();
+        collect::<RustParser>(
+            &mut results,
+            "src/lib.rs",
+            "mod metrics;
+             mod tools;
+             pub use crate::metrics::*;",
+        );
+        collect::<RustParser>(
+            &mut results,
+            "src/metrics/mod.rs",
+            "pub mod loc;
+             pub trait Metric {}",
+        );
+        collect::<RustParser>(
+            &mut results,
+            "src/metrics/loc.rs",
+            "use std::fmt;
+             use super::{Metric, super::tools::{self, read_file as read}};
+             pub struct Stats;",
+        );
+        collect::<RustParser>(&mut results, "src/tools.rs", "");
+        let graph = DependencyGraph::new(&results);
+
+        assert_eq!(
+            dependencies(&graph, "src/lib.rs"),
+            ["src/metrics/mod.rs", "src/tools.rs"]
+        );
+        assert_eq!(
+            dependencies(&graph, "src/metrics/loc.rs"),
+            ["src/metrics/mod.rs", "src/tools.rs"]
+        );
+        assert_eq!(
+            graph.unresolved[Path::new("src/metrics/loc.rs")],
+            ["std::fmt"]
+        );
+        assert_eq!(
+            graph.cycles,
+            [[PathBuf::from("src"), PathBuf::from("src/metrics")]]
+        );
+        let metrics = &graph.packages[Path::new("src/metrics")];
+        assert_eq!((metrics.types, metrics.abstract_types), (2, 1));
+        assert_eq!((metrics.ca, metrics.ce), (1, 1));
+        assert_eq!(metrics.instability, 0.5);
+    }
+}
@@ -8,23 +8,109 @@ use crate::traits::*;
 
 use crate::checker::Checker;
 use crate::getter::Getter;
+use crate::spaces::{get_header_and_body, get_qualified_name, get_stable_id, CodeSpan};
 
 use crate::tools::{color, intense_color};
 
+/// A parameter of a function.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Parameter {
+    /// The name of a parameter as it is written
+    ///
+    /// Variadic parameters keep their markers, such as `*args` or `...rest`,
+    /// while destructured parameters are named after their pattern
+    pub name: Option<String>,
+    /// The type of a parameter
+    ///
+    /// If `None`, the type of a parameter is not written
+    #[serde(rename = "type")]
+    pub typ: Option<String>,
+    /// The default value of a parameter
+    pub default: Option<String>,
+}
+
+/// The signature of a function.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Signature {
+    /// The parameters of a function
+    pub parameters: Vec<Parameter>,
+    /// The return type of a function
+    ///
+    /// If `None`, the return type of a function is not written
+    pub return_type: Option<String>,
+    /// The visibility of a function, such as `pub` or `private`
+    ///
+    /// If `None`, the visibility of a function is not written
+    pub visibility: Option<String>,
+    /// The modifiers of a function, such as `async`, `generator`,
+    /// `const` or `static`
+    pub modifiers: Vec<String>,
+    /// The decorators, annotations or attributes of a function,
+    /// without their markers
+    pub decorators: Vec<String>,
+    /// The generic parameters of a function
+    pub generics: Vec<String>,
+}
+
+/// The template declaring a function or a class, such as a `C++` template.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Template {
+    /// The number of template parameters
+    pub parameters: usize,
+    /// Whether a template specializes another template,
+    /// such as `template <> class A<int>`
+    pub specialization: bool,
+}
+
 /// Function span data.
 #[derive(Debug, Serialize)]
 pub struct FunctionSpan {
     /// The function name
     pub name: String,
+    /// The qualified name of a function
+    ///
+    /// If `None`, an error is occurred in determining
+    /// the qualified name of a function
+    pub qualified_name: Option<String>,
+    /// The stable identifier of a function, derived from its qualified name
+    pub id: Option<String>,
     /// The first line of a function
     pub start_line: usize,
     /// The last line of a function
     pub end_line: usize,
+    /// The first column of a function
+    pub start_column: usize,
+    /// The column following the end of a function
+    pub end_column: usize,
+    /// The first byte of a function
+    pub start_byte: usize,
+    /// The byte following the end of a function
+    pub end_byte: usize,
+    /// The span of the header of a function, such as its name
+    /// and its parameters
+    ///
+    /// If `None`, a function has no body
+    pub header: Option<CodeSpan>,
+    /// The span of the body of a function
+    ///
+    /// If `None`, a function has no body
+    pub body: Option<CodeSpan>,
+    /// The signature of a function
+    pub signature: Signature,
     /// If `true`, an error is occurred in determining the span
     /// of a function
     pub error: bool,
 }
 
+/// The function spans of a file.
+#[derive(Debug, Serialize)]
+pub struct FunctionSpans {
+    /// The name of a file
+    pub name: Option<String>,
+    /// The span and the signature of each function in a file
+    pub spans: Vec<FunctionSpan>,
+}
+
 /// Detects the span of each function in a code.
 ///
 /// Returns a vector containing the [`FunctionSpan`] of each function
@@ -36,23 +122,25 @@ pub fn function<T: ParserTrait>(parser: &T) -> Vec<FunctionSpan> {
     let mut spans = Vec::new();
     root.act_on_node(&mut |n| {
         if T::Checker::is_func(n) {
-            let start_line = n.start_row() + 1;
-            let end_line = n.end_row() + 1;
-            if let Some(name) = T::Getter::get_func_name(n, code) {
-                spans.push(FunctionSpan {
-                    name: name.to_string(),
-                    start_line,
-                    end_line,
-                    error: false,
-                });
-            } else {
-                spans.push(FunctionSpan {
-                    name: "".to_string(),
-                    start_line,
-                    end_line,
-                    error: true,
-                });
-            }
+            let span = CodeSpan::new(n, n);
+            let (header, body) = get_header_and_body::<T>(n);
+            let name = T::Getter::get_func_name(n, code);
+            let qualified_name = name.and_then(|_| get_qualified_name::<T>(n, code));
+            spans.push(FunctionSpan {
+                name: name.unwrap_or_default().to_string(),
+                id: qualified_name.as_deref().map(get_stable_id),
+                qualified_name,
+                start_line: span.start_line,
+                end_line: span.end_line,
+                start_column: span.start_column,
+                end_column: span.end_column,
+                start_byte: span.start_byte,
+                end_byte: span.end_byte,
+                header,
+                body,
+                signature: T::Getter::get_signature(n, code),
+                error: name.is_none(),
+            });
         }
     });
 
@@ -131,3 +219,171 @@ impl Callback for Function {
         dump_spans(function(parser), cfg.path)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{CParser, JavaParser, PythonParser, RustParser, TypescriptParser};
+
+    use super::*;
+
+    fn check<T: ParserTrait>(source: &str, filename: &str) -> Vec<FunctionSpan> {
+        let path = PathBuf::from(filename);
+        let parser = T::new(source.as_bytes().to_vec(), &path, None);
+        function(&parser)
+    }
+
+    fn parameter(name: &str, typ: Option<&str>, default: Option<&str>) -> Parameter {
+        Parameter {
+            name: Some(name.to_string()),
+            typ: typ.map(|typ| typ.to_string()),
+            default: default.map(|default| default.to_string()),
+        }
+    }
+
+    #[test]
+    fn python_signature() {
+        let spans = check::<PythonParser>(
+            "class A:
+    @staticmethod
+    @cache(1)
+    async def f(a, b: int, c=1, *args, d: str = 'x', **kw) -> int:
+        yield a
+",
+            "foo.py",
+        );
+        let signature = &spans[0].signature;
+        assert_eq!(
+            signature.parameters,
+            [
+                parameter("a", None, None),
+                parameter("b", Some("int"), None),
+                parameter("c", None, Some("1")),
+                parameter("*args", None, None),
+                parameter("d", Some("str"), Some("'x'")),
+                parameter("**kw", None, None),
+            ]
+        );
+        assert_eq!(signature.return_type.as_deref(), Some("int"));
+        assert_eq!(signature.modifiers, ["async", "generator", "static"]);
+        assert_eq!(signature.decorators, ["staticmethod", "cache(1)"]);
+        assert_eq!((spans[0].start_line, spans[0].start_column), (4, 5));
+    }
+
+    #[test]
+    fn typescript_signature() {
+        let spans = check::<TypescriptParser>(
+            "class A {
+  @log()
+  private static async *gen<T>(a: number, b?: string, c = 1, ...rest: T[]): AsyncGenerator<T> {}
+}
+const f = ({ x }) => x; const g = y => y;
+",
+            "foo.ts",
+        );
+        let signature = &spans[0].signature;
+        assert_eq!(
+            signature.parameters,
+            [
+                parameter("a", Some("number"), None),
+                parameter("b", Some("string"), None),
+                parameter("c", None, Some("1")),
+                parameter("...rest", Some("T[]"), None),
+            ]
+        );
+        assert_eq!(signature.return_type.as_deref(), Some("AsyncGenerator<T>"));
+        assert_eq!(signature.visibility.as_deref(), Some("private"));
+        assert_eq!(signature.modifiers, ["static", "async", "generator"]);
+        assert_eq!(signature.decorators, ["log()"]);
+        assert_eq!(signature.generics, ["T"]);
+
+        assert_eq!(
+            spans[1].signature.parameters,
+            [parameter("{ x }", None, None)]
+        );
+        assert_eq!(spans[2].signature.parameters, [parameter("y", None, None)]);
+        // Two functions on the same line are told apart by their columns
+        assert_eq!(spans[1].start_line, spans[2].start_line);
+        assert_eq!((spans[1].start_column, spans[1].end_column), (11, 23));
+        assert_eq!((spans[2].start_byte, spans[2].end_byte), (152, 158));
+    }
+
+    #[test]
+    fn rust_signature() {
+        let spans = check::<RustParser>(
+            "/// Returns a value.
+#[inline]
+pub(crate) const unsafe fn f<'a, T: Clone>(&'a self, mut x: u32) -> &'a T {}
+",
+            "foo.rs",
+        );
+        let signature = &spans[0].signature;
+        assert_eq!(
+            signature.parameters,
+            [
+                parameter("&'a self", None, None),
+                parameter("x", Some("u32"), None)
+            ]
+        );
+        assert_eq!(signature.return_type.as_deref(), Some("&'a T"));
+        assert_eq!(signature.visibility.as_deref(), Some("pub(crate)"));
+        assert_eq!(signature.modifiers, ["const", "unsafe"]);
+        assert_eq!(signature.decorators, ["inline"]);
+        assert_eq!(signature.generics, ["'a", "T: Clone"]);
+    }
+
+    #[test]
+    fn java_signature() {
+        let spans = check::<JavaParser>(
+            "class A {
+    @Override
+    public static <T> int[] f(final int x, String... y) { return null; }
+    A() {}
+}",
+            "foo.java",
+        );
+        let signature = &spans[0].signature;
+        assert_eq!(
+            signature.parameters,
+            [
+                parameter("x", Some("int"), None),
+                parameter("y", Some("String..."), None)
+            ]
+        );
+        assert_eq!(signature.return_type.as_deref(), Some("int[]"));
+        assert_eq!(signature.visibility.as_deref(), Some("public"));
+        assert_eq!(signature.modifiers, ["static"]);
+        assert_eq!(signature.decorators, ["Override"]);
+        assert_eq!(signature.generics, ["T"]);
+
+        assert_eq!(spans[1].signature, Signature::default());
+    }
+
+    #[test]
+    fn c_signature() {
+        let spans = check::<CParser>(
+            "static inline const char *f(int x, const char *y[], ...) { return 0; }
+int g(void) { return 1; }",
+            "foo.c",
+        );
+        let signature = &spans[0].signature;
+        assert_eq!(
+            signature.parameters,
+            [
+                parameter("x", Some("int"), None),
+                parameter("y", Some("const char *[]"), None),
+                Parameter {
+                    name: None,
+                    typ: Some("...".to_string()),
+                    default: None,
+                },
+            ]
+        );
+        assert_eq!(signature.return_type.as_deref(), Some("const char *"));
+        assert_eq!(signature.visibility.as_deref(), Some("private"));
+        assert_eq!(signature.modifiers, ["static", "inline"]);
+
+        assert!(spans[1].signature.parameters.is_empty());
+        assert_eq!(spans[1].signature.return_type.as_deref(), Some("int"));
+        assert_eq!(spans[1].signature.visibility.as_deref(), Some("public"));
+    }
+}
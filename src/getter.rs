@@ -20,6 +20,326 @@ macro_rules! get_operator {
     };
 }
 
+macro_rules! get_js_imports {
+    ($language:ident) => {
+        fn get_imports(node: &Node, code: &[u8]) -> Vec<String> {
+            use $language::*;
+
+            // `import x from "y"`, `export * from "y"`, `require("y")` and `import("y")`
+            let source = match node.kind_id().into() {
+                ImportStatement | ExportStatement => node.child_by_field_name("source"),
+                CallExpression => node
+                    .child_by_field_name("function")
+                    .filter(|function| {
+                        function.kind_id() == Import
+                            || (function.kind_id() == Identifier
+                                && function.utf8_text(code) == Some("require"))
+                    })
+                    .and_then(|_| node.child_by_field_name("arguments"))
+                    .and_then(|arguments| arguments.first_child(|id| id == String)),
+                _ => None,
+            };
+            source
+                .and_then(|source| source.utf8_text(code))
+                .filter(|source| source.len() >= 2)
+                .map(|source| vec![source[1..source.len() - 1].to_string()])
+                .unwrap_or_default()
+        }
+    };
+}
+
+macro_rules! get_js_func_space_name {
+    ($language:ident, $field:ident, $field_name:literal) => {
+        fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+            use $language::*;
+
+            if let Some(name) = node.child_by_field_name("name") {
+                return name.utf8_text(code);
+            }
+            // An anonymous function is named after the target it is bound to
+            let target = node
+                .parent()
+                .and_then(|parent| match parent.kind_id().into() {
+                    // `{ foo: function() {} }`
+                    Pair => parent.child_by_field_name("key"),
+                    // `var foo = function() {}`
+                    VariableDeclarator => parent.child_by_field_name("name"),
+                    // `obj.foo = function() {}`
+                    AssignmentExpression => parent.child_by_field_name("left"),
+                    // `class A { foo = () => {} }`
+                    $field => parent.child_by_field_name($field_name),
+                    _ => None,
+                });
+            match target {
+                Some(target) => target
+                    .utf8_text(code)
+                    .map(|name| name.trim_matches(|c| c == '"' || c == '\'')),
+                None => Some("<anonymous>"),
+            }
+        }
+    };
+}
+
+macro_rules! get_js_signature {
+    ($language:ident, $field:ident $(, $accessibility:ident, $override:ident, $required:ident, $optional:ident)?) => {
+        fn get_signature(node: &Node, code: &[u8]) -> Signature {
+            use $language::*;
+
+            fn text(node: Option<Node>, code: &[u8]) -> Option<std::string::String> {
+                node.and_then(|node| node.utf8_text(code))
+                    .map(collapse_whitespace)
+            }
+
+            fn get_parameter(node: &Node, code: &[u8]) -> Option<crate::Parameter> {
+                let kind = node.kind_id();
+                $(
+                    // `a: number = 1` and `a?: number`
+                    if kind == $required || kind == $optional {
+                        return Some(crate::Parameter {
+                            name: text(node.child_by_field_name("pattern"), code),
+                            typ: text(
+                                node.child_by_field_name("type")
+                                    .and_then(|annotation| annotation.children().find(|t| t.is_named())),
+                                code,
+                            ),
+                            default: text(node.child_by_field_name("value"), code),
+                        });
+                    }
+                )?
+                match kind.into() {
+                    Identifier | RestPattern | ObjectPattern | ArrayPattern => Some(crate::Parameter {
+                        name: text(Some(*node), code),
+                        typ: None,
+                        default: None,
+                    }),
+                    // `a = 1`
+                    AssignmentPattern => Some(crate::Parameter {
+                        name: text(node.child_by_field_name("left"), code),
+                        typ: None,
+                        default: text(node.child_by_field_name("right"), code),
+                    }),
+                    _ => None,
+                }
+            }
+
+            if !matches!(
+                node.kind_id().into(),
+                FunctionDeclaration
+                    | FunctionExpression
+                    | ArrowFunction
+                    | MethodDefinition
+                    | GeneratorFunction
+                    | GeneratorFunctionDeclaration
+            ) {
+                return Signature::default();
+            }
+
+            let parameters = match node.child_by_field_name("parameter") {
+                // `x => x`
+                Some(parameter) => get_parameter(&parameter, code).into_iter().collect(),
+                None => node
+                    .child_by_field_name("parameters")
+                    .map(|parameters| {
+                        parameters
+                            .children()
+                            .filter_map(|parameter| get_parameter(&parameter, code))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            };
+
+            let mut signature = Signature {
+                parameters,
+                return_type: text(
+                    node.child_by_field_name("return_type")
+                        .and_then(|annotation| annotation.children().find(|t| t.is_named())),
+                    code,
+                ),
+                generics: get_named_children_text(node.child_by_field_name("type_parameters"), code),
+                ..Signature::default()
+            };
+
+            // A function bound to a class field is declared by the field
+            let owner = node
+                .parent()
+                .filter(|parent| parent.kind_id() == $field)
+                .unwrap_or(*node);
+            let name = owner
+                .child_by_field_name("name")
+                .or_else(|| owner.child_by_field_name("property"));
+            if name.is_some_and(|name| name.kind_id() == PrivatePropertyIdentifier) {
+                signature.visibility = Some("private".to_string());
+            }
+
+            // The decorators of a method are either its children or its previous siblings
+            let mut decorators = Vec::new();
+            let mut sibling = owner.previous_sibling();
+            while let Some(previous) = sibling.filter(|previous| previous.kind_id() == Decorator) {
+                decorators.push(previous);
+                sibling = previous.previous_sibling();
+            }
+            decorators.reverse();
+
+            let mut children: Vec<_> = owner.children().collect();
+            if owner.id() != node.id() {
+                children.extend(node.children());
+            }
+            for child in children {
+                let kind = child.kind_id();
+                $(
+                    if kind == $accessibility {
+                        signature.visibility = text(Some(child), code);
+                        continue;
+                    }
+                    if kind == $override {
+                        signature.modifiers.push("override".to_string());
+                        continue;
+                    }
+                )?
+                match kind.into() {
+                    Async => signature.modifiers.push("async".to_string()),
+                    STAR => signature.modifiers.push("generator".to_string()),
+                    Static => signature.modifiers.push("static".to_string()),
+                    Get => signature.modifiers.push("get".to_string()),
+                    Set => signature.modifiers.push("set".to_string()),
+                    Decorator => decorators.push(child),
+                    _ => {}
+                }
+            }
+            signature.decorators = decorators
+                .iter()
+                .filter_map(|decorator| decorator.utf8_text(code))
+                .map(|decorator| collapse_whitespace(decorator.trim_start_matches('@')))
+                .collect();
+
+            signature
+        }
+    };
+}
+
+macro_rules! get_ts_qualified_names {
+    ($language:ident) => {
+        fn get_namespace_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+            use $language::*;
+
+            // `namespace N {}` and `module M {}`
+            match node.kind_id().into() {
+                InternalModule | Module => node
+                    .child_by_field_name("name")
+                    .and_then(|name| name.utf8_text(code)),
+                _ => None,
+            }
+        }
+
+        fn get_func_signature(node: &Node, code: &[u8]) -> Option<Vec<String>> {
+            use $language::*;
+
+            // An untyped parameter is written with its name, or `_` when
+            // it is a destructuring pattern, instead of an invented type
+            let get_name = |pattern: Option<Node>| {
+                pattern
+                    .filter(|pattern| pattern.kind_id() == Identifier)
+                    .and_then(|pattern| pattern.utf8_text(code))
+                    .unwrap_or("_")
+                    .to_string()
+            };
+
+            // The parameter of `x => x` has no type
+            if let Some(parameter) = node.child_by_field_name("parameter") {
+                return Some(vec![get_name(Some(parameter))]);
+            }
+            let parameters = node.child_by_field_name("parameters")?;
+            Some(
+                parameters
+                    .children()
+                    .filter(|parameter| {
+                        matches!(
+                            parameter.kind_id().into(),
+                            RequiredParameter | OptionalParameter
+                        )
+                    })
+                    .map(|parameter| {
+                        let pattern = parameter.child_by_field_name("pattern");
+                        let rest = pattern.is_some_and(|pattern| pattern.kind_id() == RestPattern);
+                        let typ = parameter
+                            .child_by_field_name("type")
+                            .and_then(|annotation| annotation.children().find(|t| t.is_named()))
+                            .and_then(|typ| typ.utf8_text(code))
+                            .map_or_else(
+                                || {
+                                    get_name(if rest {
+                                        pattern.and_then(|rest| {
+                                            rest.children().find(|name| name.is_named())
+                                        })
+                                    } else {
+                                        pattern
+                                    })
+                                },
+                                collapse_whitespace,
+                            );
+                        if rest {
+                            format!("...{typ}")
+                        } else {
+                            typ
+                        }
+                    })
+                    .collect(),
+            )
+        }
+    };
+}
+
+// Returns the text of the named children of a node, such as
+// the generic parameters of a function
+fn get_named_children_text(node: Option<Node>, code: &[u8]) -> Vec<String> {
+    node.map(|node| {
+        node.children()
+            .filter(|child| child.is_named())
+            .filter_map(|child| child.utf8_text(code))
+            .map(collapse_whitespace)
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+// Checks whether a node contains a node with the given kind,
+// without searching in the nested nodes, such as the nested functions
+pub(crate) fn has_descendant(
+    node: &Node,
+    is_kind: fn(u16) -> bool,
+    is_nested: fn(u16) -> bool,
+) -> bool {
+    let mut stack: Vec<_> = node.children().collect();
+    while let Some(node) = stack.pop() {
+        if is_kind(node.kind_id()) {
+            return true;
+        }
+        if !is_nested(node.kind_id()) {
+            stack.extend(node.children());
+        }
+    }
+    false
+}
+
+// Checks whether a function is a `React` function component, that is
+// a function named in `PascalCase` which renders some `JSX` elements
+fn is_component<T: Getter>(
+    node: &Node,
+    code: &[u8],
+    is_jsx: fn(u16) -> bool,
+    is_function: fn(u16) -> bool,
+) -> bool {
+    T::get_func_space_name(node, code)
+        .and_then(|name| name.chars().next())
+        .is_some_and(|first| first.is_ascii_uppercase())
+        && has_descendant(node, is_jsx, is_function)
+}
+
+#[inline(always)]
+pub(crate) fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 pub trait Getter {
     fn get_func_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
         Self::get_func_space_name(node, code)
@@ -35,10 +355,96 @@ pub trait Getter {
         }
     }
 
-    fn get_space_kind(_node: &Node) -> SpaceKind {
+    fn get_space_kind(_node: &Node, _code: &[u8]) -> SpaceKind {
         SpaceKind::Unknown
     }
 
+    // Returns the direct bases of a class declaration as they are written,
+    // `None` if the node does not declare a class
+    fn get_class_bases<'a>(_node: &Node, _code: &'a [u8]) -> Option<Vec<&'a str>> {
+        None
+    }
+
+    // Returns the modules, packages or files imported by a node
+    fn get_imports(_node: &Node, _code: &[u8]) -> Vec<String> {
+        Vec::new()
+    }
+
+    // Returns `Some(true)` if a node declares an abstract type, such as an interface,
+    // `Some(false)` if it declares a concrete type and `None` otherwise
+    fn get_abstractness(_node: &Node, _code: &[u8]) -> Option<bool> {
+        None
+    }
+
+    // Returns the name of a node grouping spaces without being a space,
+    // such as a module, `None` otherwise
+    fn get_namespace_name<'a>(_node: &Node, _code: &'a [u8]) -> Option<&'a str> {
+        None
+    }
+
+    // Returns the package declared at the root of a file, if any
+    fn get_package_name<'a>(_root: &Node, _code: &'a [u8]) -> Option<&'a str> {
+        None
+    }
+
+    // Returns the name of a space as it appears in the qualified names
+    // of the spaces it contains
+    fn get_qualified_space_name(node: &Node, code: &[u8]) -> Option<String> {
+        Self::get_func_space_name(node, code).map(collapse_whitespace)
+    }
+
+    // Returns the types of the parameters of a function as they are written,
+    // `None` if they are not written or if the node is not a function
+    fn get_func_signature(_node: &Node, _code: &[u8]) -> Option<Vec<String>> {
+        None
+    }
+
+    // Returns the signature of a function, which is empty
+    // if the node is not a function
+    fn get_signature(_node: &Node, _code: &[u8]) -> Signature {
+        Signature::default()
+    }
+
+    // Returns the decorators of a space, such as the ones of a `Python`
+    // function or class, without their markers
+    fn get_decorators(_node: &Node, _code: &[u8]) -> Vec<String> {
+        Vec::new()
+    }
+
+    // Returns the template declaring a space, such as a `C++` template
+    // function or class, `None` if a space is not a template
+    fn get_template(_node: &Node) -> Option<Template> {
+        None
+    }
+
+    // Returns the number of captures listed by a closure, such as
+    // a `C++` lambda, `None` if a node is not a closure with a capture list
+    fn get_captures(_node: &Node) -> Option<usize> {
+        None
+    }
+
+    // Returns the body of a space, `None` if a space has no body
+    fn get_space_body<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+        node.child_by_field_name("body")
+    }
+
+    // Returns the separator of the names composing a qualified name
+    fn get_qualified_name_separator() -> &'static str {
+        "."
+    }
+
+    // Returns the arguments of a macro invocation which can be re-parsed
+    // as code, `None` if the node is not a macro invocation
+    fn get_macro_arguments<'a>(_node: &Node<'a>) -> Option<Node<'a>> {
+        None
+    }
+
+    // Returns the prefixes and the suffixes wrapping the arguments of
+    // a macro invocation to re-parse them, in the order they are tried
+    fn get_macro_wrappers() -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+
     fn get_op_type(_node: &Node) -> HalsteadType {
         HalsteadType::Unknown
     }
@@ -49,15 +455,196 @@ pub trait Getter {
 }
 
 impl Getter for PythonCode {
-    fn get_space_kind(node: &Node) -> SpaceKind {
+    fn get_signature(node: &Node, code: &[u8]) -> Signature {
+        use Python::{Async, ClassDefinition, FunctionDefinition, Lambda, Yield};
+
+        if node.kind_id() != FunctionDefinition {
+            return Signature::default();
+        }
+        let parameters = node
+            .child_by_field_name("parameters")
+            .map(|parameters| {
+                parameters
+                    .children()
+                    .filter_map(|parameter| get_python_parameter(&parameter, code))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let decorators = Self::get_decorators(node, code);
+
+        let mut modifiers = Vec::new();
+        if node.is_child(Async as u16) {
+            modifiers.push("async".to_string());
+        }
+        if has_descendant(
+            node,
+            |id| id == Yield,
+            |id| matches!(id.into(), FunctionDefinition | Lambda | ClassDefinition),
+        ) {
+            modifiers.push("generator".to_string());
+        }
+        if decorators
+            .iter()
+            .any(|decorator| decorator == "staticmethod")
+        {
+            modifiers.push("static".to_string());
+        }
+
+        Signature {
+            parameters,
+            return_type: node
+                .child_by_field_name("return_type")
+                .and_then(|typ| typ.utf8_text(code))
+                .map(collapse_whitespace),
+            visibility: None,
+            modifiers,
+            decorators,
+            generics: get_named_children_text(node.child_by_field_name("type_parameters"), code),
+        }
+    }
+
+    fn get_decorators(node: &Node, code: &[u8]) -> Vec<String> {
+        use Python::{ClassDefinition, DecoratedDefinition, Decorator, FunctionDefinition};
+
+        if !matches!(node.kind_id().into(), FunctionDefinition | ClassDefinition) {
+            return Vec::new();
+        }
+        node.parent()
+            .filter(|parent| parent.kind_id() == DecoratedDefinition)
+            .map(|parent| {
+                parent
+                    .children()
+                    .filter(|decorator| decorator.kind_id() == Decorator)
+                    .filter_map(|decorator| decorator.utf8_text(code))
+                    .map(|decorator| collapse_whitespace(decorator.trim_start_matches('@')))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+        if let Some(name) = node.child_by_field_name("name") {
+            return name.utf8_text(code);
+        }
+        // A lambda is named after the variable it is assigned to: `f = lambda: 0`
+        node.parent()
+            .filter(|parent| {
+                node.kind_id() == Python::Lambda && parent.kind_id() == Python::Assignment
+            })
+            .and_then(|parent| parent.child_by_field_name("left"))
+            .map_or(Some("<anonymous>"), |target| target.utf8_text(code))
+    }
+
+    fn get_space_kind(node: &Node, _code: &[u8]) -> SpaceKind {
         match node.kind_id().into() {
-            Python::FunctionDefinition => SpaceKind::Function,
+            Python::FunctionDefinition | Python::Lambda => SpaceKind::Function,
             Python::ClassDefinition => SpaceKind::Class,
             Python::Module => SpaceKind::Unit,
             _ => SpaceKind::Unknown,
         }
     }
 
+    fn get_class_bases<'a>(node: &Node, code: &'a [u8]) -> Option<Vec<&'a str>> {
+        if node.kind_id() != Python::ClassDefinition {
+            return None;
+        }
+        // Keyword arguments, such as `metaclass=M`, are not bases
+        // and `object` is the implicit base of every class
+        Some(
+            node.child_by_field_name("superclasses")
+                .map(|superclasses| {
+                    superclasses
+                        .children()
+                        .filter(|base| {
+                            matches!(
+                                base.kind_id().into(),
+                                Python::Identifier | Python::Attribute
+                            )
+                        })
+                        .filter_map(|base| base.utf8_text(code))
+                        .filter(|base| *base != "object")
+                        .collect()
+                })
+                .unwrap_or_default(),
+        )
+    }
+
+    fn get_imports(node: &Node, code: &[u8]) -> Vec<String> {
+        use Python::{AliasedImport, DottedName, Import, ImportFromStatement, ImportStatement};
+
+        match node.kind_id().into() {
+            // `import a.b, c as d`
+            ImportStatement => node
+                .children()
+                .filter_map(|name| match name.kind_id().into() {
+                    DottedName => Some(name),
+                    AliasedImport => name.child_by_field_name("name"),
+                    _ => None,
+                })
+                .filter_map(|name| name.utf8_text(code))
+                .map(|name| name.to_string())
+                .collect(),
+            // `from .a import b, c`: the imported names can be either modules
+            // of a package or objects of a module, so they are kept with the module
+            ImportFromStatement => {
+                let Some(module) = node
+                    .child_by_field_name("module_name")
+                    .and_then(|module| module.utf8_text(code))
+                else {
+                    return Vec::new();
+                };
+                let names = node
+                    .children()
+                    .skip_while(|name| name.kind_id() != Import)
+                    .filter_map(|name| match name.kind_id().into() {
+                        DottedName => Some(name),
+                        AliasedImport => name.child_by_field_name("name"),
+                        _ => None,
+                    })
+                    .filter_map(|name| name.utf8_text(code))
+                    .map(|name| {
+                        if module.ends_with('.') {
+                            format!("{module}{name}")
+                        } else {
+                            format!("{module}.{name}")
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                if names.is_empty() {
+                    vec![module.to_string()]
+                } else {
+                    names
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_abstractness(node: &Node, code: &[u8]) -> Option<bool> {
+        use Python::{ClassDefinition, KeywordArgument};
+
+        if node.kind_id() != ClassDefinition {
+            return None;
+        }
+        // `class A(ABC)`, `class A(Protocol)` or `class A(metaclass=ABCMeta)`
+        let is_abstract = node
+            .child_by_field_name("superclasses")
+            .map(|superclasses| {
+                superclasses.children().any(|base| {
+                    let base = match base.kind_id().into() {
+                        KeywordArgument => base.child_by_field_name("value"),
+                        _ => Some(base),
+                    };
+                    base.and_then(|base| base.utf8_text(code))
+                        .map(|base| base.rsplit('.').next().unwrap_or(base))
+                        .is_some_and(|base| matches!(base, "ABC" | "ABCMeta" | "Protocol"))
+                })
+            })
+            .unwrap_or(false);
+        Some(is_abstract)
+    }
+
     fn get_op_type(node: &Node) -> HalsteadType {
         use Python::*;
 
@@ -91,11 +678,68 @@ impl Getter for PythonCode {
     }
 }
 
+// Returns a parameter of a function definition
+fn get_python_parameter(node: &Node, code: &[u8]) -> Option<Parameter> {
+    use Python::{
+        DefaultParameter, DictionarySplatPattern, Identifier, ListSplatPattern, TuplePattern,
+        TypedDefaultParameter, TypedParameter,
+    };
+
+    let text = |node: Option<Node>| {
+        node.and_then(|node| node.utf8_text(code))
+            .map(collapse_whitespace)
+    };
+    let (name, typ, default) = match node.kind_id().into() {
+        Identifier | ListSplatPattern | DictionarySplatPattern | TuplePattern => {
+            (Some(*node), None, None)
+        }
+        // `a: int`
+        TypedParameter => (
+            node.children().find(|name| name.is_named()),
+            node.child_by_field_name("type"),
+            None,
+        ),
+        // `a=1` and `a: int = 1`
+        DefaultParameter | TypedDefaultParameter => (
+            node.child_by_field_name("name"),
+            node.child_by_field_name("type"),
+            node.child_by_field_name("value"),
+        ),
+        _ => return None,
+    };
+    Some(Parameter {
+        name: text(name),
+        typ: text(typ),
+        default: text(default),
+    })
+}
+
 impl Getter for MozjsCode {
-    fn get_space_kind(node: &Node) -> SpaceKind {
+    fn get_space_kind(node: &Node, code: &[u8]) -> SpaceKind {
         use Mozjs::*;
 
         match node.kind_id().into() {
+            FunctionExpression | FunctionDeclaration | ArrowFunction
+                if is_component::<Self>(
+                    node,
+                    code,
+                    |id| matches!(id.into(), JsxElement | JsxSelfClosingElement),
+                    |id| {
+                        matches!(
+                            id.into(),
+                            FunctionExpression
+                                | FunctionDeclaration
+                                | ArrowFunction
+                                | GeneratorFunction
+                                | GeneratorFunctionDeclaration
+                                | Class
+                                | ClassDeclaration
+                        )
+                    },
+                ) =>
+            {
+                SpaceKind::Component
+            }
             FunctionExpression
             | MethodDefinition
             | GeneratorFunction
@@ -108,33 +752,7 @@ impl Getter for MozjsCode {
         }
     }
 
-    fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
-        if let Some(name) = node.child_by_field_name("name") {
-            let code = &code[name.start_byte()..name.end_byte()];
-            std::str::from_utf8(code).ok()
-        } else {
-            // We can be in a pair: foo: function() {}
-            // Or in a variable declaration: var aFun = function() {}
-            if let Some(parent) = node.parent() {
-                match parent.kind_id().into() {
-                    Mozjs::Pair => {
-                        if let Some(name) = parent.child_by_field_name("key") {
-                            let code = &code[name.start_byte()..name.end_byte()];
-                            return std::str::from_utf8(code).ok();
-                        }
-                    }
-                    Mozjs::VariableDeclarator => {
-                        if let Some(name) = parent.child_by_field_name("name") {
-                            let code = &code[name.start_byte()..name.end_byte()];
-                            return std::str::from_utf8(code).ok();
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            Some("<anonymous>")
-        }
-    }
+    get_js_func_space_name!(Mozjs, FieldDefinition, "property");
 
     fn get_op_type(node: &Node) -> HalsteadType {
         use Mozjs::*;
@@ -157,13 +775,45 @@ impl Getter for MozjsCode {
     }
 
     get_operator!(Mozjs);
+
+    get_js_imports!(Mozjs);
+
+    get_js_signature!(Mozjs, FieldDefinition);
+
+    fn get_abstractness(node: &Node, _code: &[u8]) -> Option<bool> {
+        match node.kind_id().into() {
+            Mozjs::Class | Mozjs::ClassDeclaration => Some(false),
+            _ => None,
+        }
+    }
 }
 
 impl Getter for JavascriptCode {
-    fn get_space_kind(node: &Node) -> SpaceKind {
+    fn get_space_kind(node: &Node, code: &[u8]) -> SpaceKind {
         use Javascript::*;
 
         match node.kind_id().into() {
+            FunctionExpression | FunctionDeclaration | ArrowFunction
+                if is_component::<Self>(
+                    node,
+                    code,
+                    |id| matches!(id.into(), JsxElement | JsxSelfClosingElement),
+                    |id| {
+                        matches!(
+                            id.into(),
+                            FunctionExpression
+                                | FunctionDeclaration
+                                | ArrowFunction
+                                | GeneratorFunction
+                                | GeneratorFunctionDeclaration
+                                | Class
+                                | ClassDeclaration
+                        )
+                    },
+                ) =>
+            {
+                SpaceKind::Component
+            }
             FunctionExpression
             | MethodDefinition
             | GeneratorFunction
@@ -176,33 +826,7 @@ impl Getter for JavascriptCode {
         }
     }
 
-    fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
-        if let Some(name) = node.child_by_field_name("name") {
-            let code = &code[name.start_byte()..name.end_byte()];
-            std::str::from_utf8(code).ok()
-        } else {
-            // We can be in a pair: foo: function() {}
-            // Or in a variable declaration: var aFun = function() {}
-            if let Some(parent) = node.parent() {
-                match parent.kind_id().into() {
-                    Mozjs::Pair => {
-                        if let Some(name) = parent.child_by_field_name("key") {
-                            let code = &code[name.start_byte()..name.end_byte()];
-                            return std::str::from_utf8(code).ok();
-                        }
-                    }
-                    Mozjs::VariableDeclarator => {
-                        if let Some(name) = parent.child_by_field_name("name") {
-                            let code = &code[name.start_byte()..name.end_byte()];
-                            return std::str::from_utf8(code).ok();
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            Some("<anonymous>")
-        }
-    }
+    get_js_func_space_name!(Javascript, FieldDefinition, "property");
 
     fn get_op_type(node: &Node) -> HalsteadType {
         use Javascript::*;
@@ -225,10 +849,21 @@ impl Getter for JavascriptCode {
     }
 
     get_operator!(Javascript);
+
+    get_js_imports!(Javascript);
+
+    get_js_signature!(Javascript, FieldDefinition);
+
+    fn get_abstractness(node: &Node, _code: &[u8]) -> Option<bool> {
+        match node.kind_id().into() {
+            Javascript::Class | Javascript::ClassDeclaration => Some(false),
+            _ => None,
+        }
+    }
 }
 
 impl Getter for TypescriptCode {
-    fn get_space_kind(node: &Node) -> SpaceKind {
+    fn get_space_kind(node: &Node, _code: &[u8]) -> SpaceKind {
         use Typescript::*;
 
         match node.kind_id().into() {
@@ -238,41 +873,47 @@ impl Getter for TypescriptCode {
             | FunctionDeclaration
             | GeneratorFunctionDeclaration
             | ArrowFunction => SpaceKind::Function,
-            Class | ClassDeclaration => SpaceKind::Class,
+            Class | ClassDeclaration | AbstractClassDeclaration => SpaceKind::Class,
             InterfaceDeclaration => SpaceKind::Interface,
+            EnumDeclaration => SpaceKind::Enum,
+            TypeAliasDeclaration => SpaceKind::TypeAlias,
+            // `namespace N {}` and `module M {}`
+            InternalModule | Module => SpaceKind::Namespace,
             Program => SpaceKind::Unit,
             _ => SpaceKind::Unknown,
         }
     }
 
-    fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
-        if let Some(name) = node.child_by_field_name("name") {
-            let code = &code[name.start_byte()..name.end_byte()];
-            std::str::from_utf8(code).ok()
-        } else {
-            // We can be in a pair: foo: function() {}
-            // Or in a variable declaration: var aFun = function() {}
-            if let Some(parent) = node.parent() {
-                match parent.kind_id().into() {
-                    Mozjs::Pair => {
-                        if let Some(name) = parent.child_by_field_name("key") {
-                            let code = &code[name.start_byte()..name.end_byte()];
-                            return std::str::from_utf8(code).ok();
-                        }
-                    }
-                    Mozjs::VariableDeclarator => {
-                        if let Some(name) = parent.child_by_field_name("name") {
-                            let code = &code[name.start_byte()..name.end_byte()];
-                            return std::str::from_utf8(code).ok();
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            Some("<anonymous>")
-        }
+    fn get_class_bases<'a>(node: &Node, code: &'a [u8]) -> Option<Vec<&'a str>> {
+        use Typescript::*;
+
+        let clauses = match node.kind_id().into() {
+            Class | ClassDeclaration | AbstractClassDeclaration => node
+                .first_child(|id| id == ClassHeritage)
+                .map(|heritage| heritage.children().collect::<Vec<_>>()),
+            InterfaceDeclaration => node
+                .first_child(|id| id == ExtendsTypeClause)
+                .map(|clause| vec![clause]),
+            _ => return None,
+        };
+        Some(
+            clauses
+                .unwrap_or_default()
+                .iter()
+                .flat_map(|clause| clause.children())
+                .filter(|base| {
+                    !matches!(
+                        base.kind_id().into(),
+                        Extends | Implements | COMMA | TypeArguments
+                    )
+                })
+                .filter_map(|base| base.utf8_text(code))
+                .collect(),
+        )
     }
 
+    get_js_func_space_name!(Typescript, PublicFieldDefinition, "name");
+
     fn get_op_type(node: &Node) -> HalsteadType {
         use Typescript::*;
 
@@ -294,54 +935,104 @@ impl Getter for TypescriptCode {
     }
 
     get_operator!(Typescript);
+
+    get_js_imports!(Typescript);
+
+    get_js_signature!(
+        Typescript,
+        PublicFieldDefinition,
+        AccessibilityModifier,
+        OverrideModifier,
+        RequiredParameter,
+        OptionalParameter
+    );
+
+    get_ts_qualified_names!(Typescript);
+
+    fn get_abstractness(node: &Node, _code: &[u8]) -> Option<bool> {
+        use Typescript::*;
+
+        match node.kind_id().into() {
+            Class | ClassDeclaration => Some(false),
+            AbstractClassDeclaration | InterfaceDeclaration => Some(true),
+            _ => None,
+        }
+    }
 }
 
 impl Getter for TsxCode {
-    fn get_space_kind(node: &Node) -> SpaceKind {
+    fn get_space_kind(node: &Node, code: &[u8]) -> SpaceKind {
         use Tsx::*;
 
         match node.kind_id().into() {
+            FunctionExpression | FunctionDeclaration | ArrowFunction
+                if is_component::<Self>(
+                    node,
+                    code,
+                    |id| matches!(id.into(), JsxElement | JsxSelfClosingElement),
+                    |id| {
+                        matches!(
+                            id.into(),
+                            FunctionExpression
+                                | FunctionDeclaration
+                                | ArrowFunction
+                                | GeneratorFunction
+                                | GeneratorFunctionDeclaration
+                                | Class
+                                | ClassDeclaration
+                        )
+                    },
+                ) =>
+            {
+                SpaceKind::Component
+            }
             FunctionExpression
             | MethodDefinition
             | GeneratorFunction
             | FunctionDeclaration
             | GeneratorFunctionDeclaration
             | ArrowFunction => SpaceKind::Function,
-            Class | ClassDeclaration => SpaceKind::Class,
+            Class | ClassDeclaration | AbstractClassDeclaration => SpaceKind::Class,
             InterfaceDeclaration => SpaceKind::Interface,
+            EnumDeclaration => SpaceKind::Enum,
+            TypeAliasDeclaration => SpaceKind::TypeAlias,
+            // `namespace N {}` and `module M {}`
+            InternalModule | Module => SpaceKind::Namespace,
             Program => SpaceKind::Unit,
             _ => SpaceKind::Unknown,
         }
     }
 
-    fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
-        if let Some(name) = node.child_by_field_name("name") {
-            let code = &code[name.start_byte()..name.end_byte()];
-            std::str::from_utf8(code).ok()
-        } else {
-            // We can be in a pair: foo: function() {}
-            // Or in a variable declaration: var aFun = function() {}
-            if let Some(parent) = node.parent() {
-                match parent.kind_id().into() {
-                    Mozjs::Pair => {
-                        if let Some(name) = parent.child_by_field_name("key") {
-                            let code = &code[name.start_byte()..name.end_byte()];
-                            return std::str::from_utf8(code).ok();
-                        }
-                    }
-                    Mozjs::VariableDeclarator => {
-                        if let Some(name) = parent.child_by_field_name("name") {
-                            let code = &code[name.start_byte()..name.end_byte()];
-                            return std::str::from_utf8(code).ok();
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            Some("<anonymous>")
-        }
+    fn get_class_bases<'a>(node: &Node, code: &'a [u8]) -> Option<Vec<&'a str>> {
+        use Tsx::*;
+
+        let clauses = match node.kind_id().into() {
+            Class | ClassDeclaration | AbstractClassDeclaration => node
+                .first_child(|id| id == ClassHeritage)
+                .map(|heritage| heritage.children().collect::<Vec<_>>()),
+            InterfaceDeclaration => node
+                .first_child(|id| id == ExtendsTypeClause)
+                .map(|clause| vec![clause]),
+            _ => return None,
+        };
+        Some(
+            clauses
+                .unwrap_or_default()
+                .iter()
+                .flat_map(|clause| clause.children())
+                .filter(|base| {
+                    !matches!(
+                        base.kind_id().into(),
+                        Extends | Implements | COMMA | TypeArguments
+                    )
+                })
+                .filter_map(|base| base.utf8_text(code))
+                .collect(),
+        )
     }
 
+    get_js_func_space_name!(Tsx, PublicFieldDefinition, "name");
+
     fn get_op_type(node: &Node) -> HalsteadType {
         use Tsx::*;
 
@@ -363,6 +1054,29 @@ impl Getter for TsxCode {
     }
 
     get_operator!(Tsx);
+
+    get_js_imports!(Tsx);
+
+    get_js_signature!(
+        Tsx,
+        PublicFieldDefinition,
+        AccessibilityModifier,
+        OverrideModifier,
+        RequiredParameter,
+        OptionalParameter
+    );
+
+    get_ts_qualified_names!(Tsx);
+
+    fn get_abstractness(node: &Node, _code: &[u8]) -> Option<bool> {
+        use Tsx::*;
+
+        match node.kind_id().into() {
+            Class | ClassDeclaration => Some(false),
+            AbstractClassDeclaration | InterfaceDeclaration => Some(true),
+            _ => None,
+        }
+    }
 }
 
 impl Getter for RustCode {
@@ -376,22 +1090,210 @@ impl Getter for RustCode {
             let code = &code[name.start_byte()..name.end_byte()];
             std::str::from_utf8(code).ok()
         } else {
-            Some("<anonymous>")
+            // A closure is named after the variable it is bound to:
+            // `let f = || {}` or `f = || {}`
+            let target = node
+                .parent()
+                .filter(|_| node.kind_id() == Rust::ClosureExpression)
+                .and_then(|parent| match parent.kind_id().into() {
+                    Rust::LetDeclaration => parent.child_by_field_name("pattern"),
+                    Rust::AssignmentExpression => parent.child_by_field_name("left"),
+                    _ => None,
+                });
+            match target {
+                Some(target) => target.utf8_text(code),
+                None => Some("<anonymous>"),
+            }
         }
     }
 
-    fn get_space_kind(node: &Node) -> SpaceKind {
+    fn get_space_kind(node: &Node, _code: &[u8]) -> SpaceKind {
         use Rust::*;
 
         match node.kind_id().into() {
             FunctionItem | ClosureExpression => SpaceKind::Function,
+            StructItem => SpaceKind::Struct,
+            EnumItem => SpaceKind::Enum,
             TraitItem => SpaceKind::Trait,
             ImplItem => SpaceKind::Impl,
+            ModItem => SpaceKind::Namespace,
             SourceFile => SpaceKind::Unit,
             _ => SpaceKind::Unknown,
         }
     }
 
+    fn get_imports(node: &Node, code: &[u8]) -> Vec<String> {
+        use Rust::*;
+
+        let mut paths = Vec::new();
+        match node.kind_id().into() {
+            UseDeclaration => {
+                if let Some(argument) = node.child_by_field_name("argument") {
+                    get_rust_use_paths(&argument, code, "", &mut paths);
+                }
+            }
+            // `mod a;` declares a module contained in another file
+            ModItem if node.child_by_field_name("body").is_none() => {
+                if let Some(name) = node
+                    .child_by_field_name("name")
+                    .and_then(|name| name.utf8_text(code))
+                {
+                    paths.push(format!("self::{name}"));
+                }
+            }
+            _ => {}
+        }
+        paths
+    }
+
+    fn get_abstractness(node: &Node, _code: &[u8]) -> Option<bool> {
+        use Rust::*;
+
+        match node.kind_id().into() {
+            TraitItem => Some(true),
+            StructItem | EnumItem | UnionItem => Some(false),
+            _ => None,
+        }
+    }
+
+    fn get_namespace_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+        if node.kind_id() != Rust::ModItem {
+            return None;
+        }
+        node.child_by_field_name("name")
+            .and_then(|name| name.utf8_text(code))
+    }
+
+    fn get_qualified_space_name(node: &Node, code: &[u8]) -> Option<String> {
+        // `impl T for A` is named `<A as T>` and `impl A` is named
+        // `<impl A>`, so that it is distinguished from the struct `A`
+        if node.kind_id() == Rust::ImplItem {
+            let typ = node
+                .child_by_field_name("type")
+                .and_then(|typ| typ.utf8_text(code))?;
+            let name = match node
+                .child_by_field_name("trait")
+                .and_then(|tr| tr.utf8_text(code))
+            {
+                Some(tr) => format!("<{typ} as {tr}>"),
+                None => format!("<impl {typ}>"),
+            };
+            return Some(collapse_whitespace(&name));
+        }
+        Self::get_func_space_name(node, code).map(collapse_whitespace)
+    }
+
+    fn get_func_signature(node: &Node, code: &[u8]) -> Option<Vec<String>> {
+        use Rust::*;
+
+        if node.kind_id() != FunctionItem {
+            return None;
+        }
+        let parameters = node.child_by_field_name("parameters")?;
+        Some(
+            parameters
+                .children()
+                .filter_map(|parameter| match parameter.kind_id().into() {
+                    SelfParameter => parameter.utf8_text(code),
+                    Parameter => parameter
+                        .child_by_field_name("type")
+                        .and_then(|typ| typ.utf8_text(code)),
+                    VariadicParameter => Some("..."),
+                    _ => None,
+                })
+                .map(collapse_whitespace)
+                .collect(),
+        )
+    }
+
+    fn get_qualified_name_separator() -> &'static str {
+        "::"
+    }
+
+    fn get_macro_arguments<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+        if node.kind_id() != Rust::MacroInvocation {
+            return None;
+        }
+        node.children()
+            .find(|child| child.kind_id() == Rust::TokenTree)
+    }
+
+    fn get_macro_wrappers() -> &'static [(&'static str, &'static str)] {
+        // `quote! { if a { b } }` contains statements,
+        // `assert!(a || b, "c")` contains expressions
+        &[("fn f() {", "}"), ("fn f() {(", ");}")]
+    }
+
+    fn get_signature(node: &Node, code: &[u8]) -> Signature {
+        use Rust::*;
+
+        if node.kind_id() != FunctionItem {
+            return Signature::default();
+        }
+        let text = |node: Option<Node>| {
+            node.and_then(|node| node.utf8_text(code))
+                .map(collapse_whitespace)
+        };
+        let parameters = node
+            .child_by_field_name("parameters")
+            .map(|parameters| {
+                parameters
+                    .children()
+                    .filter_map(|parameter| match parameter.kind_id().into() {
+                        // `&self` and `mut self`
+                        SelfParameter => Some((Some(parameter), None)),
+                        Parameter => Some((
+                            parameter.child_by_field_name("pattern"),
+                            parameter.child_by_field_name("type"),
+                        )),
+                        VariadicParameter => Some((Some(parameter), None)),
+                        _ => None,
+                    })
+                    .map(|(name, typ)| crate::Parameter {
+                        name: text(name),
+                        typ: text(typ),
+                        default: None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // The outer attributes precede a function, as its doc comments
+        let mut decorators = Vec::new();
+        let mut sibling = node.previous_sibling();
+        while let Some(previous) = sibling {
+            match previous.kind_id().into() {
+                AttributeItem => {
+                    decorators.extend(text(previous.first_child(|id| id == Attribute)))
+                }
+                LineComment | BlockComment => {}
+                _ => break,
+            }
+            sibling = previous.previous_sibling();
+        }
+        decorators.reverse();
+
+        // `const`, `async`, `unsafe` and `extern "C"`
+        let modifiers = node
+            .first_child(|id| id == FunctionModifiers)
+            .map(|modifiers| {
+                modifiers
+                    .children()
+                    .filter_map(|modifier| text(Some(modifier)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Signature {
+            parameters,
+            return_type: text(node.child_by_field_name("return_type")),
+            visibility: text(node.first_child(|id| id == VisibilityModifier)),
+            modifiers,
+            decorators,
+            generics: get_named_children_text(node.child_by_field_name("type_parameters"), code),
+        }
+    }
+
     fn get_op_type(node: &Node) -> HalsteadType {
         use Rust::*;
 
@@ -429,6 +1331,58 @@ impl Getter for RustCode {
     get_operator!(Rust);
 }
 
+// Expands a `Rust` use tree into the paths it imports
+fn get_rust_use_paths(node: &Node, code: &[u8], prefix: &str, paths: &mut Vec<String>) {
+    use Rust::*;
+
+    let join = |path: &str| {
+        if prefix.is_empty() {
+            path.to_string()
+        } else {
+            format!("{prefix}::{path}")
+        }
+    };
+    match node.kind_id().into() {
+        // `a::{b, c}`
+        ScopedUseList => {
+            let prefix = node
+                .child_by_field_name("path")
+                .and_then(|path| path.utf8_text(code))
+                .map_or_else(|| prefix.to_string(), join);
+            if let Some(list) = node.child_by_field_name("list") {
+                get_rust_use_paths(&list, code, &prefix, paths);
+            }
+        }
+        UseList => {
+            for item in node.children() {
+                get_rust_use_paths(&item, code, prefix, paths);
+            }
+        }
+        // `a as b`
+        UseAsClause => {
+            if let Some(path) = node.child_by_field_name("path") {
+                get_rust_use_paths(&path, code, prefix, paths);
+            }
+        }
+        // `a::*`
+        UseWildcard => {
+            if let Some(path) = node
+                .child(0)
+                .filter(|path| path.kind_id() != STAR)
+                .and_then(|path| path.utf8_text(code))
+            {
+                paths.push(join(path));
+            }
+        }
+        ScopedIdentifier | Identifier | Crate | Zelf | Super => {
+            if let Some(path) = node.utf8_text(code) {
+                paths.push(join(path));
+            }
+        }
+        _ => {}
+    }
+}
+
 impl Getter for CppCode {
     fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
         match node.kind_id().into() {
@@ -467,6 +1421,23 @@ impl Getter for CppCode {
                     }
                 }
             }
+            // A lambda is named after the variable it is bound to:
+            // `auto f = [] {}` or `f = [] {}`
+            Cpp::LambdaExpression => {
+                let target = node
+                    .parent()
+                    .and_then(|parent| match parent.kind_id().into() {
+                        Cpp::InitDeclarator => parent.child_by_field_name("declarator"),
+                        Cpp::AssignmentExpression | Cpp::AssignmentExpression2 => {
+                            parent.child_by_field_name("left")
+                        }
+                        _ => None,
+                    });
+                return match target {
+                    Some(target) => target.utf8_text(code),
+                    None => Some("<anonymous>"),
+                };
+            }
             _ => {
                 if let Some(name) = node.child_by_field_name("name") {
                     let code = &code[name.start_byte()..name.end_byte()];
@@ -477,11 +1448,13 @@ impl Getter for CppCode {
         None
     }
 
-    fn get_space_kind(node: &Node) -> SpaceKind {
+    fn get_space_kind(node: &Node, _code: &[u8]) -> SpaceKind {
         use Cpp::*;
 
         match node.kind_id().into() {
-            FunctionDefinition | FunctionDefinition2 | FunctionDefinition3 => SpaceKind::Function,
+            FunctionDefinition | FunctionDefinition2 | FunctionDefinition3 | LambdaExpression => {
+                SpaceKind::Function
+            }
             StructSpecifier => SpaceKind::Struct,
             ClassSpecifier => SpaceKind::Class,
             NamespaceDefinition => SpaceKind::Namespace,
@@ -490,6 +1463,233 @@ impl Getter for CppCode {
         }
     }
 
+    fn get_class_bases<'a>(node: &Node, code: &'a [u8]) -> Option<Vec<&'a str>> {
+        use Cpp::*;
+
+        // Forward declarations have no body
+        if !matches!(
+            node.kind_id().into(),
+            ClassSpecifier | StructSpecifier | UnionSpecifier
+        ) || node.child_by_field_name("body").is_none()
+        {
+            return None;
+        }
+        Some(
+            node.first_child(|id| id == BaseClassClause)
+                .map(|clause| {
+                    clause
+                        .children()
+                        .filter(|base| {
+                            matches!(
+                                base.kind_id().into(),
+                                TypeIdentifier
+                                    | TemplateType
+                                    | QualifiedIdentifier
+                                    | QualifiedIdentifier2
+                                    | QualifiedIdentifier3
+                                    | QualifiedIdentifier4
+                            )
+                        })
+                        .filter_map(|base| base.utf8_text(code))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        )
+    }
+
+    fn get_imports(node: &Node, code: &[u8]) -> Vec<String> {
+        use Cpp::*;
+
+        // System includes keep their angle brackets
+        node.child_by_field_name("path")
+            .filter(|_| node.kind_id() == PreprocInclude)
+            .and_then(|path| path.utf8_text(code))
+            .map(|path| {
+                if path.starts_with('"') && path.len() >= 2 {
+                    vec![path[1..path.len() - 1].to_string()]
+                } else {
+                    vec![path.to_string()]
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_abstractness(node: &Node, _code: &[u8]) -> Option<bool> {
+        use Cpp::*;
+
+        if !matches!(
+            node.kind_id().into(),
+            ClassSpecifier | StructSpecifier | UnionSpecifier
+        ) {
+            return None;
+        }
+        // A class with a pure virtual method: `virtual void f() = 0;`
+        let body = node.child_by_field_name("body")?;
+        Some(
+            body.children()
+                .filter(|member| member.kind_id() == FieldDeclaration)
+                .any(|member| {
+                    member.is_child(Virtual as u16)
+                        && member
+                            .child_by_field_name("default_value")
+                            .is_some_and(|value| value.kind_id() == NumberLiteral)
+                }),
+        )
+    }
+
+    fn get_template(node: &Node) -> Option<Template> {
+        use Cpp::*;
+
+        let declaration = node
+            .parent()
+            .filter(|parent| parent.kind_id() == TemplateDeclaration)?;
+        let name = match node.kind_id().into() {
+            ClassSpecifier | StructSpecifier | UnionSpecifier => node.child_by_field_name("name"),
+            FunctionDefinition | FunctionDefinition2 | FunctionDefinition3 => {
+                // The name of `void A<T>::f()` is `f`
+                let mut name = get_cpp_function_declarator(node)?.child_by_field_name("declarator");
+                while let Some(qualified) = name.filter(|name| {
+                    matches!(
+                        name.kind_id().into(),
+                        QualifiedIdentifier
+                            | QualifiedIdentifier2
+                            | QualifiedIdentifier3
+                            | QualifiedIdentifier4
+                    )
+                }) {
+                    name = qualified.child_by_field_name("name");
+                }
+                name
+            }
+            _ => return None,
+        };
+        Some(crate::Template {
+            parameters: declaration
+                .child_by_field_name("parameters")
+                .map_or(0, |parameters| {
+                    parameters
+                        .children()
+                        .filter(|parameter| parameter.is_named() && parameter.kind_id() != Comment)
+                        .count()
+                }),
+            // `class A<int>` and `void f<int>()`
+            specialization: name.is_some_and(|name| {
+                matches!(
+                    name.kind_id().into(),
+                    TemplateType | TemplateFunction | TemplateMethod
+                )
+            }),
+        })
+    }
+
+    fn get_captures(node: &Node) -> Option<usize> {
+        // `[=, &a, b = 1]`
+        node.child_by_field_name("captures")
+            .filter(|_| node.kind_id() == Cpp::LambdaExpression)
+            .map(|captures| {
+                captures
+                    .children()
+                    .filter(|capture| capture.is_named() && capture.kind_id() != Cpp::Comment)
+                    .count()
+            })
+    }
+
+    fn get_func_signature(node: &Node, code: &[u8]) -> Option<Vec<String>> {
+        Some(
+            get_cpp_parameters(node, code)?
+                .into_iter()
+                .filter_map(|parameter| parameter.typ)
+                .collect(),
+        )
+    }
+
+    fn get_signature(node: &Node, code: &[u8]) -> Signature {
+        use Cpp::*;
+
+        let Some(function_declarator) = get_cpp_function_declarator(node) else {
+            return Signature::default();
+        };
+        // A template function is declared by its parent
+        let owner = node
+            .parent()
+            .filter(|parent| parent.kind_id() == TemplateDeclaration)
+            .unwrap_or(*node);
+
+        // The declarator of `int *f()` contains the pointer of the return type,
+        // while `auto f() -> int` has a trailing return type
+        let return_type = match function_declarator.first_child(|id| id == TrailingReturnType) {
+            Some(trailing) => trailing
+                .children()
+                .find(|typ| typ.is_named())
+                .and_then(|typ| typ.utf8_text(code))
+                .map(collapse_whitespace),
+            None => node.child_by_field_name("type").and_then(|typ| {
+                let declarator = node.child_by_field_name("declarator")?;
+                let typ = [
+                    &code[typ.start_byte()..typ.end_byte()],
+                    b" ",
+                    &code[declarator.start_byte()..function_declarator.start_byte()],
+                ]
+                .concat();
+                std::str::from_utf8(&typ).ok().map(collapse_whitespace)
+            }),
+        };
+
+        let modifiers = node
+            .children()
+            .filter(|modifier| {
+                matches!(
+                    modifier.kind_id().into(),
+                    StorageClassSpecifier | Virtual | TypeQualifier
+                )
+            })
+            .chain(function_declarator.children().filter(|modifier| {
+                matches!(
+                    modifier.kind_id().into(),
+                    TypeQualifier | VirtualSpecifier | Noexcept
+                )
+            }))
+            .filter_map(|modifier| modifier.utf8_text(code))
+            .map(collapse_whitespace)
+            .collect();
+
+        // `[[nodiscard]]`
+        let decorators = node
+            .children()
+            .filter(|attribute| attribute.kind_id() == AttributeDeclaration)
+            .filter_map(|attribute| attribute.utf8_text(code))
+            .map(|attribute| {
+                collapse_whitespace(attribute.trim_start_matches("[[").trim_end_matches("]]"))
+            })
+            .collect();
+
+        let generics = owner
+            .child_by_field_name("parameters")
+            .filter(|_| owner.kind_id() == TemplateDeclaration)
+            .map(|parameters| {
+                parameters
+                    .children()
+                    .filter(|parameter| parameter.is_named())
+                    .filter_map(|parameter| parameter.utf8_text(code))
+                    .map(collapse_whitespace)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Signature {
+            parameters: get_cpp_parameters(node, code).unwrap_or_default(),
+            return_type,
+            visibility: get_cpp_visibility(&owner, code),
+            modifiers,
+            decorators,
+            generics,
+        }
+    }
+
+    fn get_qualified_name_separator() -> &'static str {
+        "::"
+    }
+
     fn get_op_type(node: &Node) -> HalsteadType {
         use Cpp::*;
 
@@ -500,7 +1700,8 @@ impl Getter for CppCode {
             | DASHGT | PLUS | PLUSPLUS | SLASH | PERCENT | PIPE | AMP | LTLT | TILDE | LT
             | LTEQ | EQEQ | BANGEQ | GTEQ | GT | GT2 | PLUSEQ | BANG | STAREQ | SLASHEQ
             | PERCENTEQ | GTGTEQ | LTLTEQ | AMPEQ | CARET | CARETEQ | PIPEEQ | LBRACK | LBRACE
-            | QMARK | COLONCOLON | PrimitiveType | TypeSpecifier | Sizeof => HalsteadType::Operator,
+            | QMARK | COLONCOLON | PrimitiveType | TypeSpecifier | Sizeof | CoAwait | CoReturn
+            | CoYield => HalsteadType::Operator,
             Identifier | TypeIdentifier | FieldIdentifier | RawStringLiteral | StringLiteral
             | NumberLiteral | True | False | Null | DOTDOTDOT => HalsteadType::Operand,
             NamespaceIdentifier => match node.parent() {
@@ -516,22 +1717,484 @@ impl Getter for CppCode {
     get_operator!(Cpp);
 }
 
+// Returns the function declarator of a function definition
+fn get_cpp_function_declarator<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    use Cpp::*;
+
+    if !matches!(
+        node.kind_id().into(),
+        FunctionDefinition | FunctionDefinition2 | FunctionDefinition3
+    ) {
+        return None;
+    }
+    node.child_by_field_name("declarator")?
+        .first_occurrence(|id| {
+            FunctionDeclarator == id || FunctionDeclarator2 == id || FunctionDeclarator3 == id
+        })
+}
+
+// Returns the parameters of a function definition
+fn get_cpp_parameters(node: &Node, code: &[u8]) -> Option<Vec<Parameter>> {
+    use Cpp::*;
+
+    let parameters = get_cpp_function_declarator(node)?.child_by_field_name("parameters")?;
+    Some(
+        parameters
+            .children()
+            .filter_map(|parameter| match parameter.kind_id().into() {
+                DOTDOTDOT => Some(Parameter {
+                    name: None,
+                    typ: Some("...".to_string()),
+                    default: None,
+                }),
+                ParameterDeclaration
+                | OptionalParameterDeclaration
+                | VariadicParameterDeclaration => {
+                    // The type of a parameter is its text without
+                    // its name and its default value
+                    let declarator = parameter.child_by_field_name("declarator");
+                    let default = parameter.child_by_field_name("default_value");
+                    let end = if default.is_some() {
+                        declarator
+                            .or_else(|| parameter.child_by_field_name("type"))?
+                            .end_byte()
+                    } else {
+                        parameter.end_byte()
+                    };
+                    let start = parameter.start_byte();
+                    let name = declarator
+                        .and_then(|declarator| declarator.first_occurrence(|id| id == Identifier));
+                    let typ = match name {
+                        Some(name) => {
+                            [&code[start..name.start_byte()], &code[name.end_byte()..end]].concat()
+                        }
+                        None => code[start..end].to_vec(),
+                    };
+                    Some(Parameter {
+                        name: name
+                            .and_then(|name| name.utf8_text(code))
+                            .map(str::to_string),
+                        typ: std::str::from_utf8(&typ).ok().map(collapse_whitespace),
+                        default: default
+                            .and_then(|default| default.utf8_text(code))
+                            .map(collapse_whitespace),
+                    })
+                }
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+// Returns the access specifier preceding a member of a class,
+// or the default access of the members of its class
+fn get_cpp_visibility(node: &Node, code: &[u8]) -> Option<String> {
+    use Cpp::*;
+
+    let mut sibling = node.previous_sibling();
+    while let Some(previous) = sibling {
+        if previous.kind_id() == AccessSpecifier {
+            return previous
+                .utf8_text(code)
+                .map(|access| access.trim_end_matches(':').trim().to_string());
+        }
+        sibling = previous.previous_sibling();
+    }
+    let members = node
+        .parent()
+        .filter(|parent| parent.kind_id() == FieldDeclarationList)?;
+    match members.parent()?.kind_id().into() {
+        ClassSpecifier => Some("private".to_string()),
+        StructSpecifier | UnionSpecifier => Some("public".to_string()),
+        _ => None,
+    }
+}
+
+impl Getter for CCode {
+    fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+        match node.kind_id().into() {
+            C::FunctionDefinition | C::FunctionDefinition2 => get_c_function_declarator(node)
+                .and_then(|declarator| declarator.child_by_field_name("declarator"))
+                .and_then(|name| name.utf8_text(code)),
+            _ => node
+                .child_by_field_name("name")
+                .and_then(|name| name.utf8_text(code)),
+        }
+    }
+
+    fn get_space_kind(node: &Node, _code: &[u8]) -> SpaceKind {
+        use C::*;
+
+        match node.kind_id().into() {
+            FunctionDefinition | FunctionDefinition2 => SpaceKind::Function,
+            StructSpecifier => SpaceKind::Struct,
+            TranslationUnit => SpaceKind::Unit,
+            _ => SpaceKind::Unknown,
+        }
+    }
+
+    fn get_imports(node: &Node, code: &[u8]) -> Vec<String> {
+        use C::*;
+
+        // System includes keep their angle brackets
+        node.child_by_field_name("path")
+            .filter(|_| node.kind_id() == PreprocInclude)
+            .and_then(|path| path.utf8_text(code))
+            .map(|path| {
+                if path.starts_with('"') && path.len() >= 2 {
+                    vec![path[1..path.len() - 1].to_string()]
+                } else {
+                    vec![path.to_string()]
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_func_signature(node: &Node, code: &[u8]) -> Option<Vec<String>> {
+        Some(
+            get_c_parameters(node, code)?
+                .into_iter()
+                .filter_map(|parameter| parameter.typ)
+                .collect(),
+        )
+    }
+
+    fn get_signature(node: &Node, code: &[u8]) -> Signature {
+        use C::*;
+
+        let Some(function_declarator) = get_c_function_declarator(node) else {
+            return Signature::default();
+        };
+
+        // The qualifiers of `const char *f()` belong to the return type,
+        // while its declarator contains the pointer of the return type
+        let return_type = node.child_by_field_name("type").and_then(|typ| {
+            let declarator = node.child_by_field_name("declarator")?;
+            let qualifiers = node
+                .children()
+                .filter(|qualifier| qualifier.kind_id() == TypeQualifier)
+                .flat_map(|qualifier| [&code[qualifier.start_byte()..qualifier.end_byte()], b" "]);
+            let typ = qualifiers
+                .chain([
+                    &code[typ.start_byte()..typ.end_byte()],
+                    b" ",
+                    &code[declarator.start_byte()..function_declarator.start_byte()],
+                ])
+                .collect::<Vec<_>>()
+                .concat();
+            std::str::from_utf8(&typ).ok().map(collapse_whitespace)
+        });
+
+        let modifiers = node
+            .children()
+            .filter(|modifier| modifier.kind_id() == StorageClassSpecifier)
+            .filter_map(|modifier| modifier.utf8_text(code))
+            .map(collapse_whitespace)
+            .collect::<Vec<_>>();
+
+        // A static function is private to its translation unit
+        let visibility = if modifiers.iter().any(|modifier| modifier == "static") {
+            "private"
+        } else {
+            "public"
+        };
+
+        Signature {
+            parameters: get_c_parameters(node, code).unwrap_or_default(),
+            return_type,
+            visibility: Some(visibility.to_string()),
+            modifiers,
+            decorators: Vec::new(),
+            generics: Vec::new(),
+        }
+    }
+
+    fn get_op_type(node: &Node) -> HalsteadType {
+        use C::*;
+
+        match node.kind_id().into() {
+            DOT | LPAREN | LPAREN2 | COMMA | STAR | GTGT | COLON | SEMI | Return | Break
+            | Continue | If | Else | Switch | Case | Default | For | While | Goto | Do | EQ
+            | AMPAMP | PIPEPIPE | DASH | DASHDASH | DASHGT | PLUS | PLUSPLUS | SLASH | PERCENT
+            | PIPE | AMP | LTLT | TILDE | LT | LTEQ | EQEQ | BANGEQ | GTEQ | GT | PLUSEQ
+            | DASHEQ | BANG | STAREQ | SLASHEQ | PERCENTEQ | GTGTEQ | LTLTEQ | AMPEQ | CARET
+            | CARETEQ | PIPEEQ | LBRACK | LBRACE | QMARK | PrimitiveType | Sizeof => {
+                HalsteadType::Operator
+            }
+            Identifier | TypeIdentifier | FieldIdentifier | StringLiteral | CharLiteral
+            | NumberLiteral | True | False | Null | DOTDOTDOT => HalsteadType::Operand,
+            _ => HalsteadType::Unknown,
+        }
+    }
+
+    get_operator!(C);
+}
+
+// Returns the function declarator of a function definition
+fn get_c_function_declarator<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    use C::*;
+
+    if !matches!(
+        node.kind_id().into(),
+        FunctionDefinition | FunctionDefinition2
+    ) {
+        return None;
+    }
+    node.child_by_field_name("declarator")?
+        .first_occurrence(|id| {
+            matches!(
+                id.into(),
+                FunctionDeclarator
+                    | FunctionDeclarator2
+                    | FunctionDeclarator3
+                    | FunctionDeclarator4
+                    | FunctionDeclarator5
+            )
+        })
+}
+
+// Returns the parameters of a function definition
+fn get_c_parameters(node: &Node, code: &[u8]) -> Option<Vec<Parameter>> {
+    use C::*;
+
+    let parameters = get_c_function_declarator(node)?.child_by_field_name("parameters")?;
+    Some(
+        parameters
+            .children()
+            .filter_map(|parameter| match parameter.kind_id().into() {
+                VariadicParameter => Some(Parameter {
+                    name: None,
+                    typ: Some("...".to_string()),
+                    default: None,
+                }),
+                // `void` in `int f(void)` is not a parameter
+                ParameterDeclaration
+                    if parameter.child_by_field_name("declarator").is_some()
+                        || parameter.utf8_text(code) != Some("void") =>
+                {
+                    // The type of a parameter is its text without its name
+                    let name = parameter
+                        .child_by_field_name("declarator")
+                        .and_then(|declarator| declarator.first_occurrence(|id| id == Identifier));
+                    let (start, end) = (parameter.start_byte(), parameter.end_byte());
+                    let typ = match name {
+                        Some(name) => {
+                            [&code[start..name.start_byte()], &code[name.end_byte()..end]].concat()
+                        }
+                        None => code[start..end].to_vec(),
+                    };
+                    Some(Parameter {
+                        name: name
+                            .and_then(|name| name.utf8_text(code))
+                            .map(str::to_string),
+                        typ: std::str::from_utf8(&typ).ok().map(collapse_whitespace),
+                        default: None,
+                    })
+                }
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
 impl Getter for PreprocCode {}
 impl Getter for CcommentCode {}
 
 impl Getter for JavaCode {
-    fn get_space_kind(node: &Node) -> SpaceKind {
+    fn get_space_kind(node: &Node, _code: &[u8]) -> SpaceKind {
         use Java::*;
 
         match node.kind_id().into() {
-            ClassDeclaration => SpaceKind::Class,
-            MethodDeclaration | ConstructorDeclaration | LambdaExpression => SpaceKind::Function,
-            InterfaceDeclaration => SpaceKind::Interface,
+            ClassDeclaration | RecordDeclaration => SpaceKind::Class,
+            ObjectCreationExpression if node.is_child(ClassBody as u16) => SpaceKind::Class,
+            MethodDeclaration
+            | ConstructorDeclaration
+            | CompactConstructorDeclaration
+            | LambdaExpression => SpaceKind::Function,
+            InterfaceDeclaration | AnnotationTypeDeclaration => SpaceKind::Interface,
+            EnumDeclaration => SpaceKind::Enum,
             Program => SpaceKind::Unit,
             _ => SpaceKind::Unknown,
         }
     }
 
+    fn get_class_bases<'a>(node: &Node, code: &'a [u8]) -> Option<Vec<&'a str>> {
+        use Java::*;
+
+        if !matches!(
+            node.kind_id().into(),
+            ClassDeclaration | InterfaceDeclaration | EnumDeclaration | RecordDeclaration
+        ) {
+            return None;
+        }
+        // `extends` of a class and `implements` of a class, of an enum or of a record
+        // contain a type, while `extends` of an interface contains a list of types
+        Some(
+            node.children()
+                .filter_map(|clause| match clause.kind_id().into() {
+                    Superclass => Some(clause),
+                    SuperInterfaces | ExtendsInterfaces => clause.first_child(|id| id == TypeList),
+                    _ => None,
+                })
+                .flat_map(|clause| clause.children())
+                .filter(|base| !matches!(base.kind_id().into(), Extends | COMMA))
+                .filter_map(|base| base.utf8_text(code))
+                .collect(),
+        )
+    }
+
+    fn get_imports(node: &Node, code: &[u8]) -> Vec<String> {
+        use Java::*;
+
+        if node.kind_id() != ImportDeclaration {
+            return Vec::new();
+        }
+        // `import a.b.C;`, `import static a.b.C.m;` and `import a.b.*;`
+        node.first_child(|id| id == ScopedIdentifier || id == Identifier)
+            .and_then(|name| name.utf8_text(code))
+            .map(|name| {
+                if node.is_child(Asterisk as u16) {
+                    vec![format!("{name}.*")]
+                } else {
+                    vec![name.to_string()]
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_abstractness(node: &Node, _code: &[u8]) -> Option<bool> {
+        use Java::*;
+
+        match node.kind_id().into() {
+            ClassDeclaration => Some(
+                node.first_child(|id| id == Modifiers)
+                    .is_some_and(|modifiers| modifiers.is_child(Abstract as u16)),
+            ),
+            InterfaceDeclaration | AnnotationTypeDeclaration => Some(true),
+            EnumDeclaration | RecordDeclaration => Some(false),
+            _ => None,
+        }
+    }
+
+    fn get_signature(node: &Node, code: &[u8]) -> Signature {
+        use Java::*;
+
+        if !matches!(
+            node.kind_id().into(),
+            MethodDeclaration | ConstructorDeclaration
+        ) {
+            return Signature::default();
+        }
+        // The dimensions of `int f()[]` and `int a[]` follow the name
+        let typ = |node: &Node| {
+            let typ = node.child_by_field_name("type")?.utf8_text(code)?;
+            let dimensions = node
+                .child_by_field_name("dimensions")
+                .and_then(|dimensions| dimensions.utf8_text(code))
+                .unwrap_or_default();
+            Some(collapse_whitespace(&format!("{typ}{dimensions}")))
+        };
+        let parameters = node
+            .child_by_field_name("parameters")
+            .map(|parameters| {
+                parameters
+                    .children()
+                    .filter_map(|parameter| match parameter.kind_id().into() {
+                        FormalParameter => Some(crate::Parameter {
+                            name: parameter
+                                .child_by_field_name("name")
+                                .and_then(|name| name.utf8_text(code))
+                                .map(str::to_string),
+                            typ: typ(&parameter),
+                            default: None,
+                        }),
+                        // `String... args`
+                        SpreadParameter => Some(crate::Parameter {
+                            name: parameter
+                                .first_child(|id| id == VariableDeclarator)
+                                .and_then(|declarator| declarator.child_by_field_name("name"))
+                                .and_then(|name| name.utf8_text(code))
+                                .map(str::to_string),
+                            typ: parameter
+                                .children()
+                                .find(|typ| {
+                                    typ.is_named()
+                                        && !matches!(
+                                            typ.kind_id().into(),
+                                            Modifiers | Annotation | MarkerAnnotation
+                                        )
+                                })
+                                .and_then(|typ| typ.utf8_text(code))
+                                .map(|typ| format!("{}...", collapse_whitespace(typ))),
+                            default: None,
+                        }),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut signature = Signature {
+            parameters,
+            return_type: typ(node),
+            generics: get_named_children_text(node.child_by_field_name("type_parameters"), code),
+            ..Signature::default()
+        };
+        if let Some(modifiers) = node.first_child(|id| id == Modifiers) {
+            for modifier in modifiers.children() {
+                let Some(text) = modifier.utf8_text(code) else {
+                    continue;
+                };
+                match modifier.kind_id().into() {
+                    Public | Private | Protected => signature.visibility = Some(text.to_string()),
+                    Annotation | MarkerAnnotation => signature
+                        .decorators
+                        .push(collapse_whitespace(text.trim_start_matches('@'))),
+                    _ => signature.modifiers.push(text.to_string()),
+                }
+            }
+        }
+        signature
+    }
+
+    fn get_package_name<'a>(root: &Node, code: &'a [u8]) -> Option<&'a str> {
+        use Java::*;
+
+        root.first_child(|id| id == PackageDeclaration)?
+            .first_child(|id| id == ScopedIdentifier || id == Identifier)
+            .and_then(|name| name.utf8_text(code))
+    }
+
+    fn get_func_signature(node: &Node, code: &[u8]) -> Option<Vec<String>> {
+        use Java::*;
+
+        if !matches!(
+            node.kind_id().into(),
+            MethodDeclaration | ConstructorDeclaration
+        ) {
+            return None;
+        }
+        let parameters = node.child_by_field_name("parameters")?;
+        Some(
+            parameters
+                .children()
+                .filter_map(|parameter| match parameter.kind_id().into() {
+                    FormalParameter => parameter
+                        .child_by_field_name("type")
+                        .and_then(|typ| typ.utf8_text(code))
+                        .map(collapse_whitespace),
+                    // `String... args`
+                    SpreadParameter => parameter
+                        .children()
+                        .find(|typ| typ.is_named() && typ.kind_id() != Modifiers)
+                        .and_then(|typ| typ.utf8_text(code))
+                        .map(|typ| format!("{}...", collapse_whitespace(typ))),
+                    _ => None,
+                })
+                .collect(),
+        )
+    }
+
     fn get_op_type(node: &Node) -> HalsteadType {
         use Java::*;
         // Some guides that informed grammar choice for Halstead
@@ -539,13 +2202,13 @@ impl Getter for JavaCode {
         // https://www.geeksforgeeks.org/software-engineering-halsteads-software-metrics/?msclkid=5e181114abef11ecbb03527e95a34828
         match node.kind_id().into() {
             // Operator: control flow
-            | If | Else | Switch | Case | Try | Catch | Throw | Throws | Throws2 | For | While | Continue | Break | Do | Finally
+            | If | Else | Switch | Case | When | Yield | Try | Catch | Throw | Throws | Throws2 | For | While | Continue | Break | Do | Finally
             // Operator: keywords
             | New | Return | Default | Abstract | Assert | Instanceof | Extends | Final | Implements | Transient | Synchronized | Super | This | VoidType
             // Operator: brackets and comma and terminators (separators)
             | SEMI | COMMA | COLONCOLON | LBRACE | LBRACK | LPAREN // | RBRACE | RBRACK | RPAREN | DOTDOTDOT | DOT
             // Operator: operators
-            | EQ | LT | GT | BANG | TILDE | QMARK | COLON // no grammar for lambda operator ->
+            | EQ | LT | GT | BANG | TILDE | QMARK | COLON | DASHGT
             | EQEQ | LTEQ | GTEQ | BANGEQ | AMPAMP | PIPEPIPE | PLUSPLUS | DASHDASH
             | PLUS | DASH | STAR | SLASH | AMP | PIPE | CARET | PERCENT| LTLT | GTGT | GTGTGT
             | PLUSEQ | DASHEQ | STAREQ | SLASHEQ | AMPEQ | PIPEEQ | CARETEQ | PERCENTEQ | LTLTEQ | GTGTEQ | GTGTGTEQ
@@ -554,7 +2217,7 @@ impl Getter for JavaCode {
             => {
                 HalsteadType::Operator
             },
-            // Operands: variables, constants, literals
+            // Operands: variables, constants, literals, a text block is a string literal
             Identifier | NullLiteral | ClassLiteral | StringLiteral | CharacterLiteral | HexIntegerLiteral | OctalIntegerLiteral | BinaryIntegerLiteral | DecimalIntegerLiteral | HexFloatingPointLiteral | DecimalFloatingPointLiteral  => {
                 HalsteadType::Operand
             },
@@ -576,4 +2239,147 @@ impl Getter for JavaCode {
     }
 }
 
-impl Getter for KotlinCode {}
+// Checks whether a `Kotlin` declaration is public, which is the default visibility,
+// while `internal` declarations are only visible in their module
+pub(crate) fn is_kotlin_public(node: &Node) -> bool {
+    use Kotlin::*;
+
+    node.first_child(|id| id == Modifiers)
+        .and_then(|modifiers| modifiers.first_child(|id| id == VisibilityModifier))
+        .is_none_or(|visibility| visibility.is_child(Public as u16))
+}
+
+impl Getter for KotlinCode {
+    fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+        use Kotlin::*;
+
+        match node.kind_id().into() {
+            // A secondary constructor is named after its class
+            SecondaryConstructor => node
+                .parent()
+                .and_then(|body| body.parent())
+                .and_then(|class| class.child_by_field_name("name"))
+                .and_then(|name| name.utf8_text(code)),
+            // An accessor is named after its property
+            Getter | Setter => node
+                .parent()
+                .and_then(|property| property.first_child(|id| id == VariableDeclaration))
+                .and_then(|variable| variable.first_child(|id| id == Identifier))
+                .and_then(|name| name.utf8_text(code)),
+            // An unnamed companion object is named `Companion` by the compiler
+            CompanionObject => node
+                .child_by_field_name("name")
+                .map_or(Some("Companion"), |name| name.utf8_text(code)),
+            _ => node
+                .child_by_field_name("name")
+                .map_or(Some("<anonymous>"), |name| name.utf8_text(code)),
+        }
+    }
+
+    fn get_space_kind(node: &Node, _code: &[u8]) -> SpaceKind {
+        use Kotlin::*;
+
+        match node.kind_id().into() {
+            ClassDeclaration if node.is_child(Interface as u16) => SpaceKind::Interface,
+            ClassDeclaration
+                if node
+                    .first_child(|id| id == Modifiers)
+                    .is_some_and(|modifiers| {
+                        modifiers.first_occurrence(|id| id == Enum).is_some()
+                    }) =>
+            {
+                SpaceKind::Enum
+            }
+            // `object` declarations, companion objects and object expressions
+            // are classes with a single instance
+            ClassDeclaration | ObjectDeclaration | CompanionObject | ObjectLiteral => {
+                SpaceKind::Class
+            }
+            FunctionDeclaration | SecondaryConstructor | Getter | Setter => SpaceKind::Function,
+            SourceFile => SpaceKind::Unit,
+            _ => SpaceKind::Unknown,
+        }
+    }
+
+    fn get_space_body<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+        use Kotlin::*;
+
+        node.first_child(|id| id == FunctionBody || id == ClassBody || id == EnumClassBody)
+    }
+
+    fn get_package_name<'a>(root: &Node, code: &'a [u8]) -> Option<&'a str> {
+        use Kotlin::*;
+
+        root.first_child(|id| id == PackageHeader)?
+            .first_child(|id| id == QualifiedIdentifier || id == Identifier)
+            .and_then(|name| name.utf8_text(code))
+    }
+
+    fn get_imports(node: &Node, code: &[u8]) -> Vec<String> {
+        use Kotlin::*;
+
+        if node.kind_id() != Import {
+            return Vec::new();
+        }
+        // `import a.b.C`, `import a.b.C as D` and `import a.b.*`
+        node.first_child(|id| id == QualifiedIdentifier)
+            .and_then(|name| name.utf8_text(code))
+            .map(|name| {
+                if node.is_child(STAR as u16) {
+                    vec![format!("{name}.*")]
+                } else {
+                    vec![name.to_string()]
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_abstractness(node: &Node, _code: &[u8]) -> Option<bool> {
+        use Kotlin::*;
+
+        match node.kind_id().into() {
+            ClassDeclaration => Some(
+                node.is_child(Interface as u16)
+                    || node
+                        .first_child(|id| id == Modifiers)
+                        .is_some_and(|modifiers| {
+                            modifiers
+                                .first_occurrence(|id| id == Abstract || id == Sealed)
+                                .is_some()
+                        }),
+            ),
+            ObjectDeclaration => Some(false),
+            _ => None,
+        }
+    }
+
+    fn get_class_bases<'a>(node: &Node, code: &'a [u8]) -> Option<Vec<&'a str>> {
+        use Kotlin::*;
+
+        if !matches!(node.kind_id().into(), ClassDeclaration | ObjectDeclaration) {
+            return None;
+        }
+        // A base is either a type, a constructor invocation `B()`
+        // or an explicit delegation `I by impl`
+        Some(
+            node.first_child(|id| id == DelegationSpecifiers)
+                .map(|specifiers| {
+                    specifiers
+                        .children()
+                        .filter(|specifier| specifier.kind_id() == DelegationSpecifier)
+                        .filter_map(|specifier| {
+                            specifier
+                                .children()
+                                .find(|base| base.kind_id() != Annotation)
+                        })
+                        .filter_map(|base| match base.kind_id().into() {
+                            ConstructorInvocation | ExplicitDelegation => base.child(0),
+                            _ => Some(base),
+                        })
+                        .filter_map(|base| base.utf8_text(code))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
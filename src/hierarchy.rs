@@ -0,0 +1,481 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use petgraph::{algo::kosaraju_scc, graph::NodeIndex, Direction, Graph};
+use serde::{Deserialize, Serialize};
+
+use crate::getter::Getter;
+use crate::inheritance;
+use crate::spaces::FuncSpace;
+use crate::traits::*;
+
+/// A class declaration with its direct bases.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClassDecl {
+    /// The name of the class
+    pub name: String,
+    /// The direct bases of the class, as they are written in the code
+    pub bases: Vec<String>,
+    /// The first line of the class
+    pub start_line: usize,
+    /// The last line of the class
+    pub end_line: usize,
+}
+
+/// Class declarations of a file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct HierarchyFile {
+    /// The name of the language of the file
+    pub language: String,
+    /// The class declarations of the file
+    pub classes: Vec<ClassDecl>,
+}
+
+/// Class declarations of a series of files.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct HierarchyResults {
+    /// The class declarations of each file
+    pub files: HashMap<PathBuf, HierarchyFile>,
+}
+
+/// Returns the class declarations contained in a code.
+pub fn get_classes<T: ParserTrait>(parser: &T) -> Vec<ClassDecl> {
+    let code = parser.get_code();
+    let node = parser.get_root();
+    let mut cursor = node.cursor();
+    let mut stack = Vec::new();
+    let mut classes = Vec::new();
+
+    stack.push(node);
+
+    while let Some(node) = stack.pop() {
+        if let Some(bases) = T::Getter::get_class_bases(&node, code) {
+            if let Some(name) = T::Getter::get_func_space_name(&node, code) {
+                classes.push(ClassDecl {
+                    name: name.split_whitespace().collect::<Vec<_>>().join(" "),
+                    bases: bases.iter().map(|base| base.to_string()).collect(),
+                    start_line: node.start_row() + 1,
+                    end_line: node.end_row() + 1,
+                });
+            }
+        }
+
+        cursor.reset(&node);
+        if cursor.goto_first_child() {
+            loop {
+                stack.push(cursor.node());
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+
+    classes.sort_by_key(|class| class.start_line);
+    classes
+}
+
+/// Configuration options for collecting the class declarations of a code.
+#[derive(Debug)]
+pub struct ClassDeclsCfg {
+    /// Path to the file containing the code
+    pub path: PathBuf,
+    /// The class declarations of the files analyzed so far
+    pub results: Arc<Mutex<HierarchyResults>>,
+}
+
+pub struct ClassDecls {
+    _guard: (),
+}
+
+impl Callback for ClassDecls {
+    type Res = std::io::Result<()>;
+    type Cfg = ClassDeclsCfg;
+
+    fn call<T: ParserTrait>(cfg: Self::Cfg, parser: &T) -> Self::Res {
+        let file = HierarchyFile {
+            language: parser.get_language().get_name().to_string(),
+            classes: get_classes(parser),
+        };
+        let mut results = cfg.results.lock().unwrap();
+        results.files.insert(cfg.path, file);
+        Ok(())
+    }
+}
+
+/// Inheritance data of a class.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ClassInheritance {
+    /// The depth of the class in the inheritance tree
+    pub dit: usize,
+    /// The number of classes directly derived from the class
+    pub noc: usize,
+    /// The direct bases of the class not declared in the analyzed files
+    pub unresolved_bases: Vec<String>,
+}
+
+/// The inheritance graph of the classes declared in a series of files.
+///
+/// A base is resolved through its unqualified name among the classes
+/// written in the same language, preferring a class declared
+/// in the same file, then in the same directory.
+/// An unresolved base, such as a class of a standard library,
+/// is considered as a root of the inheritance tree.
+#[derive(Debug, Default)]
+pub struct Hierarchy {
+    classes: HashMap<PathBuf, Vec<(String, usize, ClassInheritance)>>,
+    unresolved_bases: BTreeMap<String, usize>,
+}
+
+// Returns the unqualified name of a class without its type arguments
+fn simple_name(name: &str) -> &str {
+    let name = name.split(['<', '(']).next().unwrap_or(name).trim();
+    name.rsplit(['.', ':']).next().unwrap_or(name).trim()
+}
+
+impl Hierarchy {
+    /// Builds the inheritance graph of the classes declared in a series of files.
+    pub fn new(results: &HierarchyResults) -> Self {
+        let mut paths = results.files.keys().collect::<Vec<_>>();
+        paths.sort();
+        let classes = paths
+            .iter()
+            .flat_map(|path| {
+                results.files[*path]
+                    .classes
+                    .iter()
+                    .map(move |class| (*path, class))
+            })
+            .collect::<Vec<_>>();
+
+        let mut names: HashMap<(&str, &str), Vec<usize>> = HashMap::new();
+        for (i, (path, class)) in classes.iter().enumerate() {
+            let language = results.files[*path].language.as_str();
+            names
+                .entry((language, simple_name(&class.name)))
+                .or_default()
+                .push(i);
+        }
+
+        // An edge goes from a class to one of its bases
+        let mut g = Graph::new();
+        for i in 0..classes.len() {
+            g.add_node(i);
+        }
+        let mut unresolved = vec![Vec::new(); classes.len()];
+        let mut unresolved_bases = BTreeMap::new();
+        for (i, (path, class)) in classes.iter().enumerate() {
+            let language = results.files[*path].language.as_str();
+            for base in class.bases.iter() {
+                let candidates = names
+                    .get(&(language, simple_name(base)))
+                    .map(|candidates| {
+                        candidates
+                            .iter()
+                            .filter(|j| **j != i)
+                            .copied()
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                let resolved = candidates
+                    .iter()
+                    .find(|j| classes[**j].0 == *path)
+                    .or_else(|| {
+                        candidates
+                            .iter()
+                            .find(|j| classes[**j].0.parent() == path.parent())
+                    })
+                    .or_else(|| candidates.first());
+                if let Some(j) = resolved {
+                    g.update_edge(NodeIndex::new(i), NodeIndex::new(*j), ());
+                } else {
+                    unresolved[i].push(base.clone());
+                    *unresolved_bases.entry(base.clone()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        // The classes of an inheritance cycle share the same depth,
+        // and since the strongly connected components are in reverse topological
+        // order, the depth of the bases of a class is computed before its own one
+        let components = kosaraju_scc(&g);
+        let mut component_of = vec![0; classes.len()];
+        for (c, component) in components.iter().enumerate() {
+            for node in component {
+                component_of[node.index()] = c;
+            }
+        }
+        let mut dits = vec![0; classes.len()];
+        for (c, component) in components.iter().enumerate() {
+            if component.len() > 1 {
+                eprintln!("Warning: possible inheritance cycle:");
+                for node in component {
+                    let (path, class) = classes[g[*node]];
+                    eprintln!("  - {} ({:?})", class.name, path);
+                }
+                eprintln!();
+            }
+
+            let mut dit = 0;
+            for node in component {
+                if !unresolved[node.index()].is_empty() {
+                    dit = dit.max(1);
+                }
+                for base in g.neighbors_directed(*node, Direction::Outgoing) {
+                    if component_of[base.index()] != c {
+                        dit = dit.max(dits[base.index()] + 1);
+                    }
+                }
+            }
+            for node in component {
+                dits[node.index()] = dit;
+            }
+        }
+
+        let mut hierarchy = Self {
+            classes: HashMap::new(),
+            unresolved_bases,
+        };
+        for (i, (path, class)) in classes.iter().enumerate() {
+            let node = NodeIndex::new(i);
+            let inheritance = ClassInheritance {
+                dit: dits[i],
+                noc: g.neighbors_directed(node, Direction::Incoming).count(),
+                unresolved_bases: std::mem::take(&mut unresolved[i]),
+            };
+            hierarchy.classes.entry((*path).clone()).or_default().push((
+                class.name.clone(),
+                class.start_line,
+                inheritance,
+            ));
+        }
+        hierarchy
+    }
+
+    /// Returns the inheritance data of the class declared
+    /// in a file with the given name and starting line.
+    pub fn get(&self, path: &Path, name: &str, start_line: usize) -> Option<&ClassInheritance> {
+        self.classes.get(path).and_then(|classes| {
+            classes
+                .iter()
+                .find(|(class, line, _)| *line == start_line && class == name)
+                .map(|(_, _, inheritance)| inheritance)
+        })
+    }
+
+    /// Returns the bases not declared in the analyzed files
+    /// with the number of classes directly derived from each of them.
+    pub fn unresolved_bases(&self) -> &BTreeMap<String, usize> {
+        &self.unresolved_bases
+    }
+
+    /// Sets the `Inheritance` metrics of the class spaces
+    /// contained in the function space of a file.
+    pub fn annotate(&self, space: &mut FuncSpace, path: &Path) {
+        self.annotate_space(space, path);
+    }
+
+    fn annotate_space(&self, space: &mut FuncSpace, path: &Path) -> inheritance::Stats {
+        let mut stats = space
+            .name
+            .as_ref()
+            .and_then(|name| self.get(path, name, space.start_line))
+            .map(|class| inheritance::Stats::new_class(class.dit, class.noc))
+            .unwrap_or_default();
+        for subspace in space.spaces.iter_mut() {
+            stats.merge(&self.annotate_space(subspace, path));
+        }
+        space.metrics.inheritance = stats.clone();
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{metrics, JavaParser, KotlinParser, ParserTrait, PythonParser, TypescriptParser};
+
+    use super::*;
+
+    fn collect<T: ParserTrait>(results: &mut HierarchyResults, path: &str, source: &str) {
+        let path = Path::new(path);
+        let parser = T::new(source.as_bytes().to_vec(), path, None);
+        let file = HierarchyFile {
+            language: parser.get_language().get_name().to_string(),
+            classes: get_classes(&parser),
+        };
+        results.files.insert(path.to_path_buf(), file);
+    }
+
+    #[test]
+    fn java_across_files() {
+        let mut results = HierarchyResults::default();
+        collect::<JavaParser>(
+            &mut results,
+            "a/Base.java",
+            "abstract class Base implements Comparable<Base>, java.io.Serializable {}",
+        );
+        collect::<JavaParser>(
+            &mut results,
+            "a/Derived.java",
+            "class Left extends Base {}
+             class Right extends a.Base implements Runnable {}",
+        );
+        collect::<JavaParser>(
+            &mut results,
+            "b/Leaf.java",
+            "class Leaf extends Left {
+                 interface Inner extends Cloneable {}
+             }",
+        );
+        let hierarchy = Hierarchy::new(&results);
+
+        let base = hierarchy.get(Path::new("a/Base.java"), "Base", 1).unwrap();
+        assert_eq!((base.dit, base.noc), (1, 2));
+        assert_eq!(
+            base.unresolved_bases,
+            ["Comparable<Base>", "java.io.Serializable"]
+        );
+        let right = hierarchy
+            .get(Path::new("a/Derived.java"), "Right", 2)
+            .unwrap();
+        assert_eq!((right.dit, right.noc), (2, 0));
+        assert_eq!(right.unresolved_bases, ["Runnable"]);
+        let leaf = hierarchy.get(Path::new("b/Leaf.java"), "Leaf", 1).unwrap();
+        assert_eq!((leaf.dit, leaf.noc), (3, 0));
+
+        insta::assert_json_snapshot!(
+            hierarchy.unresolved_bases(),
+            @r###"
+            {
+              "Cloneable": 1,
+              "Comparable<Base>": 1,
+              "Runnable": 1,
+              "java.io.Serializable": 1
+            }"###
+        );
+    }
+
+    #[test]
+    fn java_annotate_spaces() {
+        let source = "class A {}
+                      class B extends A {
+                          class C extends B {}
+                      }";
+        let path = Path::new("foo.java");
+        let mut results = HierarchyResults::default();
+        collect::<JavaParser>(&mut results, "foo.java", source);
+        let hierarchy = Hierarchy::new(&results);
+
+        let parser = JavaParser::new(source.as_bytes().to_vec(), path, None);
+        let mut space = metrics(&parser, path).unwrap();
+        hierarchy.annotate(&mut space, path);
+
+        insta::assert_json_snapshot!(
+            space.spaces[1].metrics.inheritance,
+            @r###"
+            {
+              "classes": 2.0,
+              "dit_sum": 3.0,
+              "dit_average": 1.5,
+              "dit_max": 2.0,
+              "noc_sum": 1.0,
+              "noc_average": 0.5,
+              "noc_max": 1.0
+            }"###
+        );
+        insta::assert_json_snapshot!(
+            space.metrics.inheritance,
+            @r###"
+            {
+              "classes": 3.0,
+              "dit_sum": 3.0,
+              "dit_average": 1.0,
+              "dit_max": 2.0,
+              "noc_sum": 2.0,
+              "noc_average": 0.6666666666666666,
+              "noc_max": 1.0
+            }"###
+        );
+    }
+
+    #[test]
+    fn python_bases() {
+        let mut results = HierarchyResults::default();
+        collect::<PythonParser>(
+            &mut results,
+            "foo.py",
+            "class A(object): pass
+class B(A, metaclass=ABCMeta): pass
+class C(mod.B, A): pass",
+        );
+        let hierarchy = Hierarchy::new(&results);
+
+        let a = hierarchy.get(Path::new("foo.py"), "A", 1).unwrap();
+        assert_eq!((a.dit, a.noc), (0, 2));
+        let c = hierarchy.get(Path::new("foo.py"), "C", 3).unwrap();
+        assert_eq!((c.dit, c.noc), (2, 0));
+        assert!(hierarchy.unresolved_bases().is_empty());
+    }
+
+    #[test]
+    fn typescript_bases() {
+        let mut results = HierarchyResults::default();
+        collect::<TypescriptParser>(
+            &mut results,
+            "foo.ts",
+            "interface I {}
+             interface J extends I, ns.K {}
+             class A implements J {}
+             class B extends A<number> implements I {}",
+        );
+        let hierarchy = Hierarchy::new(&results);
+
+        let i = hierarchy.get(Path::new("foo.ts"), "I", 1).unwrap();
+        assert_eq!((i.dit, i.noc), (0, 2));
+        let b = hierarchy.get(Path::new("foo.ts"), "B", 4).unwrap();
+        assert_eq!((b.dit, b.noc), (3, 0));
+        assert_eq!(
+            hierarchy.unresolved_bases().keys().collect::<Vec<_>>(),
+            ["ns.K"]
+        );
+    }
+
+    #[test]
+    fn kotlin_bases() {
+        let mut results = HierarchyResults::default();
+        collect::<KotlinParser>(
+            &mut results,
+            "foo.kt",
+            "interface I
+             open class A : I
+             class B : A(), I by Impl()
+             object O : A()",
+        );
+        let hierarchy = Hierarchy::new(&results);
+
+        let a = hierarchy.get(Path::new("foo.kt"), "A", 2).unwrap();
+        assert_eq!((a.dit, a.noc), (1, 2));
+        let b = hierarchy.get(Path::new("foo.kt"), "B", 3).unwrap();
+        assert_eq!((b.dit, b.noc), (2, 0));
+    }
+
+    #[test]
+    fn inheritance_cycle() {
+        let mut results = HierarchyResults::default();
+        collect::<JavaParser>(
+            &mut results,
+            "foo.java",
+            "class A extends B {}
+             class B extends A {}",
+        );
+        let hierarchy = Hierarchy::new(&results);
+
+        let a = hierarchy.get(Path::new("foo.java"), "A", 1).unwrap();
+        let b = hierarchy.get(Path::new("foo.java"), "B", 2).unwrap();
+        assert_eq!((a.dit, a.noc), (0, 1));
+        assert_eq!((b.dit, b.noc), (0, 1));
+    }
+}
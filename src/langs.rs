@@ -67,6 +67,16 @@ mk_langs!(
         [rs],
         ["rust"]
     ),
+    (
+        C,
+        "The `C` language",
+        "c",
+        CCode,
+        CParser,
+        tree_sitter_c,
+        [c],
+        ["c"]
+    ),
     (
         Cpp,
         "The `C/C++` language",
@@ -74,8 +84,8 @@ mk_langs!(
         CppCode,
         CppParser,
         tree_sitter_cpp,
-        [cpp, cxx, cc, hxx, hpp, c, h, hh, inc, mm, m],
-        ["c++", "c", "objc", "objc++", "objective-c++", "objective-c"]
+        [cpp, cxx, cc, hxx, hpp, h, hh, inc, mm, m],
+        ["c++", "objc", "objc++", "objective-c++", "objective-c"]
     ),
     (
         Python,
@@ -221,428 +221,333 @@ pub enum Cpp {
     DQUOTEDQUOTE = 214,
     This = 215,
     LiteralSuffix = 216,
-    AloneMacro = 217,
-    AloneMacroCallToken1 = 218,
-    CACHETRYINSPECT = 219,
-    CACHETRYUNWRAP = 220,
-    FORWARD = 221,
-    FORWARDSETATTRIBUTE = 222,
-    IDBTRYINSPECT = 223,
-    IDBTRYUNWRAP = 224,
-    LSTRYINSPECT = 225,
-    LSTRYUNWRAP = 226,
-    SDBTRYINSPECT = 227,
-    SDBTRYUNWRAP = 228,
-    PSGET = 229,
-    PSGETANDSET = 230,
-    PSGETLOCKLESS = 231,
-    QMTRYINSPECT = 232,
-    QMNOTEONLYTRYUNWRAP = 233,
-    QMTRYUNWRAP = 234,
-    QMWARNONLYTRYUNWRAP = 235,
-    MacroStatement = 236,
-    MOZALLOCATOR = 237,
-    MOZALLOWTEMPORARY = 238,
-    MOZALWAYSINLINE = 239,
-    MOZALWAYSINLINEEVENDEBUG = 240,
-    MOZASANBLACKLIST = 241,
-    MOZCANRUNSCRIPT = 242,
-    MOZCANRUNSCRIPTBOUNDARY = 243,
-    MOZCANRUNSCRIPTFORDEFINITION = 244,
-    MOZCOLD = 245,
-    MOZFALLTHROUGH = 246,
-    MOZFORMATPRINTF = 247,
-    MOZHAVEANALYZERNORETURN = 248,
-    MOZHAVEASANBLACKLIST = 249,
-    MOZHAVENEVERINLINE = 250,
-    MOZHAVENORETURN = 251,
-    MOZHAVENORETURNPTR = 252,
-    MOZHAVENOSANITIZEATTR = 253,
-    MOZHAVESIGNEDOVERFLOWSANITIZEATTR = 254,
-    MOZHAVEUNSIGNEDOVERFLOWSANITIZEATTR = 255,
-    MOZHEAPALLOCATOR = 256,
-    MOZHEAPCLASS = 257,
-    MOZIMPLICIT = 258,
-    MOZINHERITTYPEANNOTATIONSFROMTEMPLATEARGS = 259,
-    MOZINITOUTSIDECTOR = 260,
-    MOZISCLASSINIT = 261,
-    MOZISREFPTR = 262,
-    MOZISSMARTPTRTOREFCOUNTED = 263,
-    MOZMAYBEUNUSED = 264,
-    MOZMAYCALLAFTERMUSTRETURN = 265,
-    MOZMUSTOVERRIDE = 266,
-    MOZMUSTRETURNFROMCALLERIFTHISISARG = 267,
-    MOZMUSTUSE = 268,
-    MOZMUSTUSETYPE = 269,
-    MOZNEEDSMEMMOVABLEMEMBERS = 270,
-    MOZNEEDSMEMMOVABLETYPE = 271,
-    MOZNEEDSNOVTABLETYPE = 272,
-    MOZNEVERINLINE = 273,
-    MOZNEVERINLINEDEBUG = 274,
-    MOZNONHEAPCLASS = 275,
-    MOZNONNULL = 276,
-    MOZNONNULLRETURN = 277,
-    MOZNONAUTOABLE = 278,
-    MOZNONMEMMOVABLE = 279,
-    MOZNONOWNINGREF = 280,
-    MOZNONPARAM = 281,
-    MOZNONTEMPORARYCLASS = 282,
-    MOZNORETURN = 283,
-    MOZNORETURNPTR = 284,
-    MOZNOADDREFRELEASEONRETURN = 285,
-    MOZNOARITHMETICEXPRINARGUMENT = 286,
-    MOZNODANGLINGONTEMPORARIES = 287,
-    MOZNOSANITIZESIGNEDOVERFLOW = 288,
-    MOZNOSANITIZEUNSIGNEDOVERFLOW = 289,
-    MOZONLYUSEDTOAVOIDSTATICCONSTRUCTORS = 290,
-    MOZOWNINGREF = 291,
-    MOZPOPDISABLENONTRIVIALUNIONWARNINGS = 292,
-    MOZPRETENDNORETURNFORSTATICANALYSIS = 293,
-    MOZPUSHDISABLENONTRIVIALUNIONWARNINGS = 294,
-    MOZRAII = 295,
-    MOZREQUIREDBASEMETHOD = 296,
-    MOZSTACKCLASS = 297,
-    MOZSTATICCLASS = 298,
-    MOZSTATICLOCALCLASS = 299,
-    MOZTEMPORARYCLASS = 300,
-    MOZTRIVIALCTORDTOR = 301,
-    MOZTSANBLACKLIST = 302,
-    MOZUNSAFEREF = 303,
-    MOZXPCOMABI = 304,
-    JSPUBLICAPI = 305,
-    RawStringDelimiter = 306,
-    RawStringContent = 307,
-    TranslationUnit = 308,
-    TopLevelItem = 309,
-    BlockItem = 310,
-    PreprocInclude = 311,
-    PreprocDef = 312,
-    PreprocFunctionDef = 313,
-    PreprocParams = 314,
-    PreprocCall = 315,
-    PreprocIf = 316,
-    PreprocIfdef = 317,
-    PreprocElse = 318,
-    PreprocElif = 319,
-    PreprocElifdef = 320,
-    PreprocIf2 = 321,
-    PreprocIfdef2 = 322,
-    PreprocElse2 = 323,
-    PreprocElif2 = 324,
-    PreprocElifdef2 = 325,
-    PreprocIf3 = 326,
-    PreprocIfdef3 = 327,
-    PreprocElse3 = 328,
-    PreprocElif3 = 329,
-    PreprocElifdef3 = 330,
-    PreprocIf4 = 331,
-    PreprocIfdef4 = 332,
-    PreprocElse4 = 333,
-    PreprocElif4 = 334,
-    PreprocElifdef4 = 335,
-    PreprocExpression = 336,
-    ParenthesizedExpression = 337,
-    PreprocDefined = 338,
-    UnaryExpression = 339,
-    CallExpression = 340,
-    ArgumentList = 341,
-    BinaryExpression = 342,
-    FunctionDefinition = 343,
-    Declaration = 344,
-    TypeDefinition = 345,
-    TypeDefinitionType = 346,
-    TypeDefinitionDeclarators = 347,
-    DeclarationModifiers = 348,
-    DeclarationSpecifiers = 349,
-    LinkageSpecification = 350,
-    AttributeSpecifier = 351,
-    Attribute = 352,
-    AttributeDeclaration = 353,
-    MsDeclspecModifier = 354,
-    MsBasedModifier = 355,
-    MsCallModifier = 356,
-    MsUnalignedPtrModifier = 357,
-    MsPointerModifier = 358,
-    DeclarationList = 359,
-    Declarator = 360,
-    FieldDeclarator = 361,
-    TypeDeclarator = 362,
-    AbstractDeclarator = 363,
-    ParenthesizedDeclarator = 364,
-    ParenthesizedDeclarator2 = 365,
-    ParenthesizedDeclarator3 = 366,
-    AbstractParenthesizedDeclarator = 367,
-    AttributedDeclarator = 368,
-    AttributedDeclarator2 = 369,
-    AttributedDeclarator3 = 370,
-    PointerDeclarator = 371,
-    PointerDeclarator2 = 372,
-    PointerTypeDeclarator = 373,
-    AbstractPointerDeclarator = 374,
-    FunctionDeclarator = 375,
-    FunctionDeclarator2 = 376,
-    FunctionDeclarator3 = 377,
-    AbstractFunctionDeclarator = 378,
-    ArrayDeclarator = 379,
-    ArrayDeclarator2 = 380,
-    ArrayDeclarator3 = 381,
-    AbstractArrayDeclarator = 382,
-    InitDeclarator = 383,
-    CompoundStatement = 384,
-    StorageClassSpecifier = 385,
-    TypeQualifier = 386,
-    AlignasQualifier = 387,
-    TypeSpecifier = 388,
-    SizedTypeSpecifier = 389,
-    EnumSpecifier = 390,
-    EnumeratorList = 391,
-    StructSpecifier = 392,
-    UnionSpecifier = 393,
-    FieldDeclarationList = 394,
-    FieldDeclarationListItem = 395,
-    FieldDeclaration = 396,
-    BitfieldClause = 397,
-    Enumerator = 398,
-    ParameterList = 399,
-    ParameterDeclaration = 400,
-    AttributedStatement = 401,
-    Statement = 402,
-    TopLevelStatement = 403,
-    LabeledStatement = 404,
-    ExpressionStatement = 405,
-    ExpressionStatement2 = 406,
-    IfStatement = 407,
-    ElseClause = 408,
-    SwitchStatement = 409,
-    CaseStatement = 410,
-    WhileStatement = 411,
-    DoStatement = 412,
-    ForStatement = 413,
-    ForStatementBody = 414,
-    ReturnStatement = 415,
-    BreakStatement = 416,
-    ContinueStatement = 417,
-    GotoStatement = 418,
-    SehTryStatement = 419,
-    SehExceptClause = 420,
-    SehFinallyClause = 421,
-    SehLeaveStatement = 422,
-    Expression = 423,
-    String = 424,
-    CommaExpression = 425,
-    ConditionalExpression = 426,
-    AssignmentExpression = 427,
-    PointerExpression = 428,
-    UnaryExpression2 = 429,
-    BinaryExpression2 = 430,
-    UpdateExpression = 431,
-    CastExpression = 432,
-    TypeDescriptor = 433,
-    SizeofExpression = 434,
-    AlignofExpression = 435,
-    OffsetofExpression = 436,
-    GenericExpression = 437,
-    SubscriptExpression = 438,
-    CallExpression2 = 439,
-    GnuAsmExpression = 440,
-    GnuAsmQualifier = 441,
-    GnuAsmOutputOperandList = 442,
-    GnuAsmOutputOperand = 443,
-    GnuAsmInputOperandList = 444,
-    GnuAsmInputOperand = 445,
-    GnuAsmClobberList = 446,
-    GnuAsmGotoList = 447,
-    ExtensionExpression = 448,
-    ArgumentList2 = 449,
-    FieldExpression = 450,
-    CompoundLiteralExpression = 451,
-    ParenthesizedExpression2 = 452,
-    InitializerList = 453,
-    InitializerPair = 454,
-    SubscriptDesignator = 455,
-    SubscriptRangeDesignator = 456,
-    FieldDesignator = 457,
-    CharLiteral = 458,
-    ConcatenatedString = 459,
-    StringLiteral = 460,
-    Null = 461,
-    EmptyDeclaration = 462,
-    PlaceholderTypeSpecifier = 463,
-    Decltype = 464,
-    Decltype2 = 465,
-    ClassDeclaration = 466,
-    ClassDeclarationItem = 467,
-    ClassSpecifier = 468,
-    ClassName = 469,
-    VirtualSpecifier = 470,
-    ExplicitFunctionSpecifier = 471,
-    BaseClassClause = 472,
-    EnumBaseClause = 473,
-    DependentType = 474,
-    TemplateDeclaration = 475,
-    TemplateInstantiation = 476,
-    TemplateParameterList = 477,
-    TypeParameterDeclaration = 478,
-    VariadicTypeParameterDeclaration = 479,
-    OptionalTypeParameterDeclaration = 480,
-    TemplateTemplateParameterDeclaration = 481,
-    OptionalParameterDeclaration = 482,
-    VariadicParameterDeclaration = 483,
-    VariadicDeclarator = 484,
-    ReferenceDeclarator = 485,
-    OperatorCast = 486,
-    FieldInitializerList = 487,
-    FieldInitializer = 488,
-    FunctionDefinition2 = 489,
-    ConstructorSpecifiers = 490,
-    FunctionDefinition3 = 491,
-    Declaration2 = 492,
-    TryStatement = 493,
-    FunctionDefinition4 = 494,
-    Declaration3 = 495,
-    DefaultMethodClause = 496,
-    DeleteMethodClause = 497,
-    PureVirtualClause = 498,
-    FriendDeclaration = 499,
-    AccessSpecifier = 500,
-    ReferenceDeclarator2 = 501,
-    ReferenceDeclarator3 = 502,
-    ReferenceDeclarator4 = 503,
-    AbstractReferenceDeclarator = 504,
-    StructuredBindingDeclarator = 505,
-    RefQualifier = 506,
-    FunctionDeclaratorSeq = 507,
-    FunctionAttributesStart = 508,
-    FunctionExceptionSpecification = 509,
-    FunctionAttributesEnd = 510,
-    FunctionPostfix = 511,
-    TrailingReturnType = 512,
-    Noexcept = 513,
-    ThrowSpecifier = 514,
-    TemplateType = 515,
-    TemplateMethod = 516,
-    TemplateFunction = 517,
-    TemplateArgumentList = 518,
-    NamespaceDefinition = 519,
-    NamespaceAliasDefinition = 520,
-    NamespaceSpecifier = 521,
-    NestedNamespaceSpecifier = 522,
-    UsingDeclaration = 523,
-    AliasDeclaration = 524,
-    StaticAssertDeclaration = 525,
-    ConceptDefinition = 526,
-    ForRangeLoop = 527,
-    ForRangeLoopBody = 528,
-    InitStatement = 529,
-    ConditionClause = 530,
-    Declaration4 = 531,
-    CoReturnStatement = 532,
-    CoYieldStatement = 533,
-    ThrowStatement = 534,
-    TryStatement2 = 535,
-    CatchClause = 536,
-    RawStringLiteral = 537,
-    SubscriptArgumentList = 538,
-    CoAwaitExpression = 539,
-    NewExpression = 540,
-    NewDeclarator = 541,
-    DeleteExpression = 542,
-    TypeRequirement = 543,
-    CompoundRequirement = 544,
-    Requirement = 545,
-    RequirementSeq = 546,
-    ConstraintConjunction = 547,
-    ConstraintDisjunction = 548,
-    RequirementClauseConstraint = 549,
-    RequiresClause = 550,
-    ParameterList2 = 551,
-    RequiresExpression = 552,
-    LambdaExpression = 553,
-    LambdaCaptureSpecifier = 554,
-    LambdaDefaultCapture = 555,
-    LambdaCaptureIdentifier = 556,
-    LambdaCaptureInitializer = 557,
-    LambdaCapture = 558,
-    FoldOperator = 559,
-    BinaryFoldOperator = 560,
-    UnaryLeftFold = 561,
-    UnaryRightFold = 562,
-    BinaryFold = 563,
-    FoldExpression = 564,
-    ParameterPackExpansion = 565,
-    ParameterPackExpansion2 = 566,
-    ParameterPackExpansion3 = 567,
-    DestructorName = 568,
-    DependentName = 569,
-    DependentName2 = 570,
-    DependentName3 = 571,
-    ScopeResolution = 572,
-    QualifiedIdentifier = 573,
-    QualifiedIdentifier2 = 574,
-    QualifiedIdentifier3 = 575,
-    QualifiedIdentifier4 = 576,
-    AssignmentExpression2 = 577,
-    OperatorName = 578,
-    UserDefinedLiteral = 579,
-    AloneMacroCall = 580,
-    CallMacroWithDeclFirstArg = 581,
-    MacroAnnotation = 582,
-    TranslationUnitRepeat1 = 583,
-    PreprocParamsRepeat1 = 584,
-    PreprocIfRepeat1 = 585,
-    PreprocIfInFieldDeclarationListRepeat1 = 586,
-    PreprocIfInEnumeratorListRepeat1 = 587,
-    PreprocIfInEnumeratorListNoCommaRepeat1 = 588,
-    PreprocArgumentListRepeat1 = 589,
-    DeclarationRepeat1 = 590,
-    TypeDefinitionRepeat1 = 591,
-    TypeDefinitionTypeRepeat1 = 592,
-    TypeDefinitionDeclaratorsRepeat1 = 593,
-    DeclarationSpecifiersRepeat1 = 594,
-    AttributeDeclarationRepeat1 = 595,
-    AttributedDeclaratorRepeat1 = 596,
-    PointerDeclaratorRepeat1 = 597,
-    ArrayDeclaratorRepeat1 = 598,
-    SizedTypeSpecifierRepeat1 = 599,
-    EnumeratorListRepeat1 = 600,
-    FieldDeclarationRepeat1 = 601,
-    ParameterListRepeat1 = 602,
-    CaseStatementRepeat1 = 603,
-    GenericExpressionRepeat1 = 604,
-    GnuAsmExpressionRepeat1 = 605,
-    GnuAsmOutputOperandListRepeat1 = 606,
-    GnuAsmInputOperandListRepeat1 = 607,
-    GnuAsmClobberListRepeat1 = 608,
-    GnuAsmGotoListRepeat1 = 609,
-    ArgumentListRepeat1 = 610,
-    InitializerListRepeat1 = 611,
-    InitializerPairRepeat1 = 612,
-    CharLiteralRepeat1 = 613,
-    ConcatenatedStringRepeat1 = 614,
-    StringLiteralRepeat1 = 615,
-    ClassDeclarationRepeat1 = 616,
-    ClassSpecifierRepeat1 = 617,
-    BaseClassClauseRepeat1 = 618,
-    TemplateParameterListRepeat1 = 619,
-    FieldInitializerListRepeat1 = 620,
-    OperatorCastDefinitionRepeat1 = 621,
-    ConstructorTryStatementRepeat1 = 622,
-    StructuredBindingDeclaratorRepeat1 = 623,
-    FunctionPostfixRepeat1 = 624,
-    ThrowSpecifierRepeat1 = 625,
-    TemplateArgumentListRepeat1 = 626,
-    SubscriptArgumentListRepeat1 = 627,
-    RequirementSeqRepeat1 = 628,
-    RequiresParameterListRepeat1 = 629,
-    LambdaCaptureSpecifierRepeat1 = 630,
-    AloneMacroCallRepeat1 = 631,
-    CallMacroWithDeclFirstArgRepeat1 = 632,
-    FieldIdentifier = 633,
-    NamespaceIdentifier = 634,
-    SimpleRequirement = 635,
-    StatementIdentifier = 636,
-    TypeIdentifier = 637,
-    Error = 638,
+    RawStringDelimiter = 217,
+    RawStringContent = 218,
+    TranslationUnit = 219,
+    TopLevelItem = 220,
+    BlockItem = 221,
+    PreprocInclude = 222,
+    PreprocDef = 223,
+    PreprocFunctionDef = 224,
+    PreprocParams = 225,
+    PreprocCall = 226,
+    PreprocIf = 227,
+    PreprocIfdef = 228,
+    PreprocElse = 229,
+    PreprocElif = 230,
+    PreprocElifdef = 231,
+    PreprocIf2 = 232,
+    PreprocIfdef2 = 233,
+    PreprocElse2 = 234,
+    PreprocElif2 = 235,
+    PreprocElifdef2 = 236,
+    PreprocIf3 = 237,
+    PreprocIfdef3 = 238,
+    PreprocElse3 = 239,
+    PreprocElif3 = 240,
+    PreprocElifdef3 = 241,
+    PreprocIf4 = 242,
+    PreprocIfdef4 = 243,
+    PreprocElse4 = 244,
+    PreprocElif4 = 245,
+    PreprocElifdef4 = 246,
+    PreprocExpression = 247,
+    ParenthesizedExpression = 248,
+    PreprocDefined = 249,
+    UnaryExpression = 250,
+    CallExpression = 251,
+    ArgumentList = 252,
+    BinaryExpression = 253,
+    FunctionDefinition = 254,
+    Declaration = 255,
+    TypeDefinition = 256,
+    TypeDefinitionType = 257,
+    TypeDefinitionDeclarators = 258,
+    DeclarationModifiers = 259,
+    DeclarationSpecifiers = 260,
+    LinkageSpecification = 261,
+    AttributeSpecifier = 262,
+    Attribute = 263,
+    AttributeDeclaration = 264,
+    MsDeclspecModifier = 265,
+    MsBasedModifier = 266,
+    MsCallModifier = 267,
+    MsUnalignedPtrModifier = 268,
+    MsPointerModifier = 269,
+    DeclarationList = 270,
+    Declarator = 271,
+    FieldDeclarator = 272,
+    TypeDeclarator = 273,
+    AbstractDeclarator = 274,
+    ParenthesizedDeclarator = 275,
+    ParenthesizedDeclarator2 = 276,
+    ParenthesizedDeclarator3 = 277,
+    AbstractParenthesizedDeclarator = 278,
+    AttributedDeclarator = 279,
+    AttributedDeclarator2 = 280,
+    AttributedDeclarator3 = 281,
+    PointerDeclarator = 282,
+    PointerDeclarator2 = 283,
+    PointerTypeDeclarator = 284,
+    AbstractPointerDeclarator = 285,
+    FunctionDeclarator = 286,
+    FunctionDeclarator2 = 287,
+    FunctionDeclarator3 = 288,
+    AbstractFunctionDeclarator = 289,
+    ArrayDeclarator = 290,
+    ArrayDeclarator2 = 291,
+    ArrayDeclarator3 = 292,
+    AbstractArrayDeclarator = 293,
+    InitDeclarator = 294,
+    CompoundStatement = 295,
+    StorageClassSpecifier = 296,
+    TypeQualifier = 297,
+    AlignasQualifier = 298,
+    TypeSpecifier = 299,
+    SizedTypeSpecifier = 300,
+    EnumSpecifier = 301,
+    EnumeratorList = 302,
+    StructSpecifier = 303,
+    UnionSpecifier = 304,
+    FieldDeclarationList = 305,
+    FieldDeclarationListItem = 306,
+    FieldDeclaration = 307,
+    BitfieldClause = 308,
+    Enumerator = 309,
+    ParameterList = 310,
+    ParameterDeclaration = 311,
+    AttributedStatement = 312,
+    Statement = 313,
+    TopLevelStatement = 314,
+    LabeledStatement = 315,
+    ExpressionStatement = 316,
+    ExpressionStatement2 = 317,
+    IfStatement = 318,
+    ElseClause = 319,
+    SwitchStatement = 320,
+    CaseStatement = 321,
+    WhileStatement = 322,
+    DoStatement = 323,
+    ForStatement = 324,
+    ForStatementBody = 325,
+    ReturnStatement = 326,
+    BreakStatement = 327,
+    ContinueStatement = 328,
+    GotoStatement = 329,
+    SehTryStatement = 330,
+    SehExceptClause = 331,
+    SehFinallyClause = 332,
+    SehLeaveStatement = 333,
+    Expression = 334,
+    String = 335,
+    CommaExpression = 336,
+    ConditionalExpression = 337,
+    AssignmentExpression = 338,
+    PointerExpression = 339,
+    UnaryExpression2 = 340,
+    BinaryExpression2 = 341,
+    UpdateExpression = 342,
+    CastExpression = 343,
+    TypeDescriptor = 344,
+    SizeofExpression = 345,
+    AlignofExpression = 346,
+    OffsetofExpression = 347,
+    GenericExpression = 348,
+    SubscriptExpression = 349,
+    CallExpression2 = 350,
+    GnuAsmExpression = 351,
+    GnuAsmQualifier = 352,
+    GnuAsmOutputOperandList = 353,
+    GnuAsmOutputOperand = 354,
+    GnuAsmInputOperandList = 355,
+    GnuAsmInputOperand = 356,
+    GnuAsmClobberList = 357,
+    GnuAsmGotoList = 358,
+    ExtensionExpression = 359,
+    ArgumentList2 = 360,
+    FieldExpression = 361,
+    CompoundLiteralExpression = 362,
+    ParenthesizedExpression2 = 363,
+    InitializerList = 364,
+    InitializerPair = 365,
+    SubscriptDesignator = 366,
+    SubscriptRangeDesignator = 367,
+    FieldDesignator = 368,
+    CharLiteral = 369,
+    ConcatenatedString = 370,
+    StringLiteral = 371,
+    Null = 372,
+    EmptyDeclaration = 373,
+    PlaceholderTypeSpecifier = 374,
+    Decltype = 375,
+    Decltype2 = 376,
+    ClassDeclaration = 377,
+    ClassDeclarationItem = 378,
+    ClassSpecifier = 379,
+    ClassName = 380,
+    VirtualSpecifier = 381,
+    ExplicitFunctionSpecifier = 382,
+    BaseClassClause = 383,
+    EnumBaseClause = 384,
+    DependentType = 385,
+    TemplateDeclaration = 386,
+    TemplateInstantiation = 387,
+    TemplateParameterList = 388,
+    TypeParameterDeclaration = 389,
+    VariadicTypeParameterDeclaration = 390,
+    OptionalTypeParameterDeclaration = 391,
+    TemplateTemplateParameterDeclaration = 392,
+    OptionalParameterDeclaration = 393,
+    VariadicParameterDeclaration = 394,
+    VariadicDeclarator = 395,
+    ReferenceDeclarator = 396,
+    OperatorCast = 397,
+    FieldInitializerList = 398,
+    FieldInitializer = 399,
+    FunctionDefinition2 = 400,
+    ConstructorSpecifiers = 401,
+    FunctionDefinition3 = 402,
+    Declaration2 = 403,
+    TryStatement = 404,
+    FunctionDefinition4 = 405,
+    Declaration3 = 406,
+    DefaultMethodClause = 407,
+    DeleteMethodClause = 408,
+    PureVirtualClause = 409,
+    FriendDeclaration = 410,
+    AccessSpecifier = 411,
+    ReferenceDeclarator2 = 412,
+    ReferenceDeclarator3 = 413,
+    ReferenceDeclarator4 = 414,
+    AbstractReferenceDeclarator = 415,
+    StructuredBindingDeclarator = 416,
+    RefQualifier = 417,
+    FunctionDeclaratorSeq = 418,
+    FunctionAttributesStart = 419,
+    FunctionExceptionSpecification = 420,
+    FunctionAttributesEnd = 421,
+    FunctionPostfix = 422,
+    TrailingReturnType = 423,
+    Noexcept = 424,
+    ThrowSpecifier = 425,
+    TemplateType = 426,
+    TemplateMethod = 427,
+    TemplateFunction = 428,
+    TemplateArgumentList = 429,
+    NamespaceDefinition = 430,
+    NamespaceAliasDefinition = 431,
+    NamespaceSpecifier = 432,
+    NestedNamespaceSpecifier = 433,
+    UsingDeclaration = 434,
+    AliasDeclaration = 435,
+    StaticAssertDeclaration = 436,
+    ConceptDefinition = 437,
+    ForRangeLoop = 438,
+    ForRangeLoopBody = 439,
+    InitStatement = 440,
+    ConditionClause = 441,
+    Declaration4 = 442,
+    CoReturnStatement = 443,
+    CoYieldStatement = 444,
+    ThrowStatement = 445,
+    TryStatement2 = 446,
+    CatchClause = 447,
+    RawStringLiteral = 448,
+    SubscriptArgumentList = 449,
+    CoAwaitExpression = 450,
+    NewExpression = 451,
+    NewDeclarator = 452,
+    DeleteExpression = 453,
+    TypeRequirement = 454,
+    CompoundRequirement = 455,
+    Requirement = 456,
+    RequirementSeq = 457,
+    ConstraintConjunction = 458,
+    ConstraintDisjunction = 459,
+    RequirementClauseConstraint = 460,
+    RequiresClause = 461,
+    ParameterList2 = 462,
+    RequiresExpression = 463,
+    LambdaExpression = 464,
+    LambdaCaptureSpecifier = 465,
+    LambdaDefaultCapture = 466,
+    LambdaCaptureIdentifier = 467,
+    LambdaCaptureInitializer = 468,
+    LambdaCapture = 469,
+    FoldOperator = 470,
+    BinaryFoldOperator = 471,
+    UnaryLeftFold = 472,
+    UnaryRightFold = 473,
+    BinaryFold = 474,
+    FoldExpression = 475,
+    ParameterPackExpansion = 476,
+    ParameterPackExpansion2 = 477,
+    ParameterPackExpansion3 = 478,
+    DestructorName = 479,
+    DependentName = 480,
+    DependentName2 = 481,
+    DependentName3 = 482,
+    ScopeResolution = 483,
+    QualifiedIdentifier = 484,
+    QualifiedIdentifier2 = 485,
+    QualifiedIdentifier3 = 486,
+    QualifiedIdentifier4 = 487,
+    AssignmentExpression2 = 488,
+    OperatorName = 489,
+    UserDefinedLiteral = 490,
+    TranslationUnitRepeat1 = 491,
+    PreprocParamsRepeat1 = 492,
+    PreprocIfRepeat1 = 493,
+    PreprocIfInFieldDeclarationListRepeat1 = 494,
+    PreprocIfInEnumeratorListRepeat1 = 495,
+    PreprocIfInEnumeratorListNoCommaRepeat1 = 496,
+    PreprocArgumentListRepeat1 = 497,
+    DeclarationRepeat1 = 498,
+    TypeDefinitionRepeat1 = 499,
+    TypeDefinitionTypeRepeat1 = 500,
+    TypeDefinitionDeclaratorsRepeat1 = 501,
+    DeclarationSpecifiersRepeat1 = 502,
+    AttributeDeclarationRepeat1 = 503,
+    AttributedDeclaratorRepeat1 = 504,
+    PointerDeclaratorRepeat1 = 505,
+    ArrayDeclaratorRepeat1 = 506,
+    SizedTypeSpecifierRepeat1 = 507,
+    EnumeratorListRepeat1 = 508,
+    FieldDeclarationRepeat1 = 509,
+    ParameterListRepeat1 = 510,
+    CaseStatementRepeat1 = 511,
+    GenericExpressionRepeat1 = 512,
+    GnuAsmExpressionRepeat1 = 513,
+    GnuAsmOutputOperandListRepeat1 = 514,
+    GnuAsmInputOperandListRepeat1 = 515,
+    GnuAsmClobberListRepeat1 = 516,
+    GnuAsmGotoListRepeat1 = 517,
+    ArgumentListRepeat1 = 518,
+    InitializerListRepeat1 = 519,
+    InitializerPairRepeat1 = 520,
+    CharLiteralRepeat1 = 521,
+    ConcatenatedStringRepeat1 = 522,
+    StringLiteralRepeat1 = 523,
+    ClassDeclarationRepeat1 = 524,
+    BaseClassClauseRepeat1 = 525,
+    TemplateParameterListRepeat1 = 526,
+    FieldInitializerListRepeat1 = 527,
+    OperatorCastDefinitionRepeat1 = 528,
+    ConstructorTryStatementRepeat1 = 529,
+    StructuredBindingDeclaratorRepeat1 = 530,
+    FunctionPostfixRepeat1 = 531,
+    ThrowSpecifierRepeat1 = 532,
+    TemplateArgumentListRepeat1 = 533,
+    SubscriptArgumentListRepeat1 = 534,
+    RequirementSeqRepeat1 = 535,
+    RequiresParameterListRepeat1 = 536,
+    LambdaCaptureSpecifierRepeat1 = 537,
+    FieldIdentifier = 538,
+    NamespaceIdentifier = 539,
+    SimpleRequirement = 540,
+    StatementIdentifier = 541,
+    TypeIdentifier = 542,
+    Error = 543,
 }
 
 impl From<Cpp> for &'static str {
@@ -866,103 +771,6 @@ impl From<Cpp> for &'static str {
             Cpp::DQUOTEDQUOTE => "\"\"",
             Cpp::This => "this",
             Cpp::LiteralSuffix => "literal_suffix",
-            Cpp::AloneMacro => "alone_macro",
-            Cpp::AloneMacroCallToken1 => "alone_macro_call_token1",
-            Cpp::CACHETRYINSPECT => "CACHE_TRY_INSPECT",
-            Cpp::CACHETRYUNWRAP => "CACHE_TRY_UNWRAP",
-            Cpp::FORWARD => "FORWARD",
-            Cpp::FORWARDSETATTRIBUTE => "FORWARD_SET_ATTRIBUTE",
-            Cpp::IDBTRYINSPECT => "IDB_TRY_INSPECT",
-            Cpp::IDBTRYUNWRAP => "IDB_TRY_UNWRAP",
-            Cpp::LSTRYINSPECT => "LS_TRY_INSPECT",
-            Cpp::LSTRYUNWRAP => "LS_TRY_UNWRAP",
-            Cpp::SDBTRYINSPECT => "SDB_TRY_INSPECT",
-            Cpp::SDBTRYUNWRAP => "SDB_TRY_UNWRAP",
-            Cpp::PSGET => "PS_GET",
-            Cpp::PSGETANDSET => "PS_GET_AND_SET",
-            Cpp::PSGETLOCKLESS => "PS_GET_LOCKLESS",
-            Cpp::QMTRYINSPECT => "QM_TRY_INSPECT",
-            Cpp::QMNOTEONLYTRYUNWRAP => "QM_NOTEONLY_TRY_UNWRAP",
-            Cpp::QMTRYUNWRAP => "QM_TRY_UNWRAP",
-            Cpp::QMWARNONLYTRYUNWRAP => "QM_WARNONLY_TRY_UNWRAP",
-            Cpp::MacroStatement => "macro_statement",
-            Cpp::MOZALLOCATOR => "MOZ_ALLOCATOR",
-            Cpp::MOZALLOWTEMPORARY => "MOZ_ALLOW_TEMPORARY",
-            Cpp::MOZALWAYSINLINE => "MOZ_ALWAYS_INLINE",
-            Cpp::MOZALWAYSINLINEEVENDEBUG => "MOZ_ALWAYS_INLINE_EVEN_DEBUG",
-            Cpp::MOZASANBLACKLIST => "MOZ_ASAN_BLACKLIST",
-            Cpp::MOZCANRUNSCRIPT => "MOZ_CAN_RUN_SCRIPT",
-            Cpp::MOZCANRUNSCRIPTBOUNDARY => "MOZ_CAN_RUN_SCRIPT_BOUNDARY",
-            Cpp::MOZCANRUNSCRIPTFORDEFINITION => "MOZ_CAN_RUN_SCRIPT_FOR_DEFINITION",
-            Cpp::MOZCOLD => "MOZ_COLD",
-            Cpp::MOZFALLTHROUGH => "MOZ_FALLTHROUGH",
-            Cpp::MOZFORMATPRINTF => "MOZ_FORMAT_PRINTF",
-            Cpp::MOZHAVEANALYZERNORETURN => "MOZ_HAVE_ANALYZER_NORETURN",
-            Cpp::MOZHAVEASANBLACKLIST => "MOZ_HAVE_ASAN_BLACKLIST",
-            Cpp::MOZHAVENEVERINLINE => "MOZ_HAVE_NEVER_INLINE",
-            Cpp::MOZHAVENORETURN => "MOZ_HAVE_NORETURN",
-            Cpp::MOZHAVENORETURNPTR => "MOZ_HAVE_NORETURN_PTR",
-            Cpp::MOZHAVENOSANITIZEATTR => "MOZ_HAVE_NO_SANITIZE_ATTR",
-            Cpp::MOZHAVESIGNEDOVERFLOWSANITIZEATTR => "MOZ_HAVE_SIGNED_OVERFLOW_SANITIZE_ATTR",
-            Cpp::MOZHAVEUNSIGNEDOVERFLOWSANITIZEATTR => "MOZ_HAVE_UNSIGNED_OVERFLOW_SANITIZE_ATTR",
-            Cpp::MOZHEAPALLOCATOR => "MOZ_HEAP_ALLOCATOR",
-            Cpp::MOZHEAPCLASS => "MOZ_HEAP_CLASS",
-            Cpp::MOZIMPLICIT => "MOZ_IMPLICIT",
-            Cpp::MOZINHERITTYPEANNOTATIONSFROMTEMPLATEARGS => {
-                "MOZ_INHERIT_TYPE_ANNOTATIONS_FROM_TEMPLATE_ARGS"
-            }
-            Cpp::MOZINITOUTSIDECTOR => "MOZ_INIT_OUTSIDE_CTOR",
-            Cpp::MOZISCLASSINIT => "MOZ_IS_CLASS_INIT",
-            Cpp::MOZISREFPTR => "MOZ_IS_REFPTR",
-            Cpp::MOZISSMARTPTRTOREFCOUNTED => "MOZ_IS_SMARTPTR_TO_REFCOUNTED",
-            Cpp::MOZMAYBEUNUSED => "MOZ_MAYBE_UNUSED",
-            Cpp::MOZMAYCALLAFTERMUSTRETURN => "MOZ_MAY_CALL_AFTER_MUST_RETURN",
-            Cpp::MOZMUSTOVERRIDE => "MOZ_MUST_OVERRIDE",
-            Cpp::MOZMUSTRETURNFROMCALLERIFTHISISARG => "MOZ_MUST_RETURN_FROM_CALLER_IF_THIS_IS_ARG",
-            Cpp::MOZMUSTUSE => "MOZ_MUST_USE",
-            Cpp::MOZMUSTUSETYPE => "MOZ_MUST_USE_TYPE",
-            Cpp::MOZNEEDSMEMMOVABLEMEMBERS => "MOZ_NEEDS_MEMMOVABLE_MEMBERS",
-            Cpp::MOZNEEDSMEMMOVABLETYPE => "MOZ_NEEDS_MEMMOVABLE_TYPE",
-            Cpp::MOZNEEDSNOVTABLETYPE => "MOZ_NEEDS_NO_VTABLE_TYPE",
-            Cpp::MOZNEVERINLINE => "MOZ_NEVER_INLINE",
-            Cpp::MOZNEVERINLINEDEBUG => "MOZ_NEVER_INLINE_DEBUG",
-            Cpp::MOZNONHEAPCLASS => "MOZ_NONHEAP_CLASS",
-            Cpp::MOZNONNULL => "MOZ_NONNULL",
-            Cpp::MOZNONNULLRETURN => "MOZ_NONNULL_RETURN",
-            Cpp::MOZNONAUTOABLE => "MOZ_NON_AUTOABLE",
-            Cpp::MOZNONMEMMOVABLE => "MOZ_NON_MEMMOVABLE",
-            Cpp::MOZNONOWNINGREF => "MOZ_NON_OWNING_REF",
-            Cpp::MOZNONPARAM => "MOZ_NON_PARAM",
-            Cpp::MOZNONTEMPORARYCLASS => "MOZ_NON_TEMPORARY_CLASS",
-            Cpp::MOZNORETURN => "MOZ_NORETURN",
-            Cpp::MOZNORETURNPTR => "MOZ_NORETURN_PTR",
-            Cpp::MOZNOADDREFRELEASEONRETURN => "MOZ_NO_ADDREF_RELEASE_ON_RETURN",
-            Cpp::MOZNOARITHMETICEXPRINARGUMENT => "MOZ_NO_ARITHMETIC_EXPR_IN_ARGUMENT",
-            Cpp::MOZNODANGLINGONTEMPORARIES => "MOZ_NO_DANGLING_ON_TEMPORARIES",
-            Cpp::MOZNOSANITIZESIGNEDOVERFLOW => "MOZ_NO_SANITIZE_SIGNED_OVERFLOW",
-            Cpp::MOZNOSANITIZEUNSIGNEDOVERFLOW => "MOZ_NO_SANITIZE_UNSIGNED_OVERFLOW",
-            Cpp::MOZONLYUSEDTOAVOIDSTATICCONSTRUCTORS => {
-                "MOZ_ONLY_USED_TO_AVOID_STATIC_CONSTRUCTORS"
-            }
-            Cpp::MOZOWNINGREF => "MOZ_OWNING_REF",
-            Cpp::MOZPOPDISABLENONTRIVIALUNIONWARNINGS => {
-                "MOZ_POP_DISABLE_NONTRIVIAL_UNION_WARNINGS"
-            }
-            Cpp::MOZPRETENDNORETURNFORSTATICANALYSIS => "MOZ_PRETEND_NORETURN_FOR_STATIC_ANALYSIS",
-            Cpp::MOZPUSHDISABLENONTRIVIALUNIONWARNINGS => {
-                "MOZ_PUSH_DISABLE_NONTRIVIAL_UNION_WARNINGS"
-            }
-            Cpp::MOZRAII => "MOZ_RAII",
-            Cpp::MOZREQUIREDBASEMETHOD => "MOZ_REQUIRED_BASE_METHOD",
-            Cpp::MOZSTACKCLASS => "MOZ_STACK_CLASS",
-            Cpp::MOZSTATICCLASS => "MOZ_STATIC_CLASS",
-            Cpp::MOZSTATICLOCALCLASS => "MOZ_STATIC_LOCAL_CLASS",
-            Cpp::MOZTEMPORARYCLASS => "MOZ_TEMPORARY_CLASS",
-            Cpp::MOZTRIVIALCTORDTOR => "MOZ_TRIVIAL_CTOR_DTOR",
-            Cpp::MOZTSANBLACKLIST => "MOZ_TSAN_BLACKLIST",
-            Cpp::MOZUNSAFEREF => "MOZ_UNSAFE_REF",
-            Cpp::MOZXPCOMABI => "MOZ_XPCOM_ABI",
-            Cpp::JSPUBLICAPI => "JS_PUBLIC_API",
             Cpp::RawStringDelimiter => "raw_string_delimiter",
             Cpp::RawStringContent => "raw_string_content",
             Cpp::TranslationUnit => "translation_unit",
@@ -1237,9 +1045,6 @@ impl From<Cpp> for &'static str {
             Cpp::AssignmentExpression2 => "assignment_expression",
             Cpp::OperatorName => "operator_name",
             Cpp::UserDefinedLiteral => "user_defined_literal",
-            Cpp::AloneMacroCall => "alone_macro_call",
-            Cpp::CallMacroWithDeclFirstArg => "_call_macro_with_decl_first_arg",
-            Cpp::MacroAnnotation => "macro_annotation",
             Cpp::TranslationUnitRepeat1 => "translation_unit_repeat1",
             Cpp::PreprocParamsRepeat1 => "preproc_params_repeat1",
             Cpp::PreprocIfRepeat1 => "preproc_if_repeat1",
@@ -1278,7 +1083,6 @@ impl From<Cpp> for &'static str {
             Cpp::ConcatenatedStringRepeat1 => "concatenated_string_repeat1",
             Cpp::StringLiteralRepeat1 => "string_literal_repeat1",
             Cpp::ClassDeclarationRepeat1 => "_class_declaration_repeat1",
-            Cpp::ClassSpecifierRepeat1 => "class_specifier_repeat1",
             Cpp::BaseClassClauseRepeat1 => "base_class_clause_repeat1",
             Cpp::TemplateParameterListRepeat1 => "template_parameter_list_repeat1",
             Cpp::FieldInitializerListRepeat1 => "field_initializer_list_repeat1",
@@ -1292,8 +1096,6 @@ impl From<Cpp> for &'static str {
             Cpp::RequirementSeqRepeat1 => "requirement_seq_repeat1",
             Cpp::RequiresParameterListRepeat1 => "requires_parameter_list_repeat1",
             Cpp::LambdaCaptureSpecifierRepeat1 => "lambda_capture_specifier_repeat1",
-            Cpp::AloneMacroCallRepeat1 => "alone_macro_call_repeat1",
-            Cpp::CallMacroWithDeclFirstArgRepeat1 => "_call_macro_with_decl_first_arg_repeat1",
             Cpp::FieldIdentifier => "field_identifier",
             Cpp::NamespaceIdentifier => "namespace_identifier",
             Cpp::SimpleRequirement => "simple_requirement",
@@ -87,9 +87,30 @@ pub use crate::ast::*;
 mod count;
 pub use crate::count::*;
 
+mod annotations;
+pub use crate::annotations::*;
+
 mod preproc;
 pub use crate::preproc::*;
 
+mod hierarchy;
+pub use crate::hierarchy::*;
+
+mod dependencies;
+pub use crate::dependencies::*;
+
+mod clones;
+pub use crate::clones::*;
+
+mod coverage;
+pub use crate::coverage::*;
+
+mod rollup;
+pub use crate::rollup::*;
+
+mod commented_code;
+pub use crate::commented_code::*;
+
 mod langs;
 pub use crate::langs::*;
 
@@ -21,6 +21,13 @@ macro_rules! implement_metric_trait {
            }
         )+
     );
+    (Cbo, $($code:ident),+) => (
+        $(
+           impl Cbo for $code {
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
     (Cognitive, $($code:ident),+) => (
         $(
            impl Cognitive for $code {
@@ -28,6 +35,13 @@ macro_rules! implement_metric_trait {
            }
         )+
     );
+    (Docs, $($code:ident),+) => (
+        $(
+           impl Docs for $code {
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
     (Halstead, $($code:ident),+) => (
         $(
            impl Halstead for $code {
@@ -35,6 +49,20 @@ macro_rules! implement_metric_trait {
            }
         )+
     );
+    (Jsx, $($code:ident),+) => (
+        $(
+           impl Jsx for $code {
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
+    (Lcom, $($code:ident),+) => (
+        $(
+           impl Lcom for $code {
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
     (Loc, $($code:ident),+) => (
         $(
            impl Loc for $code {
@@ -42,6 +70,13 @@ macro_rules! implement_metric_trait {
            }
         )+
     );
+    (Typing, $($code:ident),+) => (
+        $(
+           impl Typing for $code {
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
     (Wmc, $($code:ident),+) => (
         $(
            impl Wmc for $code {
@@ -182,6 +217,63 @@ macro_rules! mk_action {
             }
         }
 
+        /// Returns all function spaces data of a code, where the comments
+        /// containing code are not counted by the `cloc` metric.
+        #[inline(always)]
+        pub fn get_function_spaces_without_commented_code(lang: &LANG, source: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>) -> Option<FuncSpace> {
+            match lang {
+                $(
+                    LANG::$camel => {
+                        let parser = $parser::new(source, &path, pr);
+                        metrics_without_commented_code(&parser, &path)
+                    },
+                )*
+            }
+        }
+
+        /// Returns all function spaces data of a code computed with some options.
+        #[inline(always)]
+        pub fn get_function_spaces_with_options(lang: &LANG, source: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>, options: &MetricsOptions) -> Option<FuncSpace> {
+            match lang {
+                $(
+                    LANG::$camel => {
+                        let parser = $parser::new(source, &path, pr);
+                        metrics_with_options(&parser, &path, options)
+                    },
+                )*
+            }
+        }
+
+        /// Returns the span and the signature of each function in a code.
+        #[inline(always)]
+        pub fn get_functions(lang: &LANG, source: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>) -> FunctionSpans {
+            let spans = match lang {
+                $(
+                    LANG::$camel => {
+                        let parser = $parser::new(source, &path, pr);
+                        function(&parser)
+                    },
+                )*
+            };
+            FunctionSpans {
+                name: path.to_str().map(|name| name.to_string()),
+                spans,
+            }
+        }
+
+        /// Returns the comments of a code containing code.
+        #[inline(always)]
+        pub fn get_commented_code(lang: &LANG, source: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>) -> CommentedCode {
+            match lang {
+                $(
+                    LANG::$camel => {
+                        let parser = $parser::new(source, &path, pr);
+                        commented_code(&parser, &path)
+                    },
+                )*
+            }
+        }
+
         /// Returns all operators and operands of each space in a code.
         ///
         /// # Examples
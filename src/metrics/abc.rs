@@ -350,6 +350,7 @@ implement_metric_trait!(
     TypescriptCode,
     TsxCode,
     RustCode,
+    CCode,
     CppCode,
     PreprocCode,
     CcommentCode,
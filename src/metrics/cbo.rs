@@ -0,0 +1,563 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Cbo` metric.
+///
+/// This metric counts the number of distinct classes a class is coupled to,
+/// that is the types referenced by its fields, method parameters,
+/// local variables and calls.
+/// The `Cbo` (Coupling Between Objects) is an object-oriented metric for classes.
+///
+/// Original paper and definition:
+/// <https://www.researchgate.net/publication/3187649_Kemerer_CF_A_metric_suite_for_object_oriented_design_IEEE_Trans_Softw_Eng_206_476-493>
+#[derive(Clone, Debug)]
+pub struct Stats {
+    cbo: usize,
+    cbo_sum: usize,
+    cbo_min: usize,
+    cbo_max: usize,
+    classes: usize,
+    classes_sum: usize,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            cbo: 0,
+            cbo_sum: 0,
+            cbo_min: usize::MAX,
+            cbo_max: 0,
+            classes: 0,
+            classes_sum: 0,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("cbo", 5)?;
+        st.serialize_field("classes", &self.classes_sum())?;
+        st.serialize_field("sum", &self.cbo_sum())?;
+        st.serialize_field("average", &self.cbo_average())?;
+        st.serialize_field("min", &self.cbo_min())?;
+        st.serialize_field("max", &self.cbo_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "classes: {}, sum: {}, average: {}, min: {}, max: {}",
+            self.classes_sum(),
+            self.cbo_sum(),
+            self.cbo_average(),
+            self.cbo_min(),
+            self.cbo_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Cbo` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.cbo_min = self.cbo_min.min(other.cbo_min);
+        self.cbo_max = self.cbo_max.max(other.cbo_max);
+        self.cbo_sum += other.cbo_sum;
+        self.classes_sum += other.classes_sum;
+    }
+
+    /// Returns the `Cbo` metric value of the class of a space.
+    #[inline(always)]
+    pub fn cbo(&self) -> f64 {
+        self.cbo as f64
+    }
+
+    /// Returns the sum of the `Cbo` metric values of the classes in a space.
+    #[inline(always)]
+    pub fn cbo_sum(&self) -> f64 {
+        self.cbo_sum as f64
+    }
+
+    /// Returns the number of classes in a space.
+    #[inline(always)]
+    pub fn classes_sum(&self) -> f64 {
+        self.classes_sum as f64
+    }
+
+    /// Returns the `Cbo` metric average value
+    ///
+    /// This value is computed dividing the `Cbo` sum
+    /// for the number of classes in a space.
+    #[inline(always)]
+    pub fn cbo_average(&self) -> f64 {
+        self.cbo_sum() / self.classes_sum()
+    }
+
+    /// Returns the `Cbo` minimum value of the classes in a space.
+    #[inline(always)]
+    pub fn cbo_min(&self) -> f64 {
+        self.cbo_min as f64
+    }
+
+    /// Returns the `Cbo` maximum value of the classes in a space.
+    #[inline(always)]
+    pub fn cbo_max(&self) -> f64 {
+        self.cbo_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.cbo_sum += self.cbo;
+        self.classes_sum += self.classes;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        if self.classes > 0 {
+            self.cbo_min = self.cbo_min.min(self.cbo);
+            self.cbo_max = self.cbo_max.max(self.cbo);
+        }
+        self.compute_sum();
+    }
+
+    // Checks if the `Cbo` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.classes_sum == 0
+    }
+}
+
+pub trait Cbo
+where
+    Self: Checker,
+{
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+#[inline(always)]
+fn is_capitalized(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_uppercase())
+}
+
+// Sets the `Cbo` value of a class counting the distinct types referenced
+// in its body, except for the class itself and its type parameters.
+//
+// `type_param` returns the name declared by a type parameter node,
+// `is_nested_class` detects the classes whose bodies must not be visited,
+// and `type_ref` returns the name of the type referenced by a node,
+// if any, in which case its children are not visited.
+fn compute_cbo<'a>(
+    class: &Node<'a>,
+    header: &Node<'a>,
+    code: &'a [u8],
+    stats: &mut Stats,
+    type_param: fn(&Node, &'a [u8]) -> Option<&'a str>,
+    is_nested_class: fn(&Node) -> bool,
+    type_ref: fn(&Node, &'a [u8]) -> Option<&'a str>,
+) {
+    let mut excluded = HashSet::new();
+    if let Some(name) = class.child_by_field_name("name") {
+        excluded.insert(name.utf8_text(code).unwrap_or_default());
+    }
+    header.act_on_node_pruned(&mut |node| {
+        if let Some(name) = type_param(node, code) {
+            excluded.insert(name);
+            return false;
+        }
+        node.id() == class.id() || !is_nested_class(node)
+    });
+
+    let mut types = HashSet::new();
+    if let Some(body) = class.child_by_field_name("body") {
+        body.act_on_node_pruned(&mut |node| {
+            if is_nested_class(node) || type_param(node, code).is_some() {
+                return false;
+            }
+            if let Some(name) = type_ref(node, code) {
+                if !excluded.contains(name) {
+                    types.insert(name);
+                }
+                return false;
+            }
+            true
+        });
+    }
+
+    stats.cbo = types.len();
+    stats.classes = 1;
+}
+
+impl Cbo for JavaCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Java::*;
+
+        fn type_param<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+            if node.kind_id() == TypeParameter {
+                node.first_child(|id| id == TypeIdentifier)
+                    .and_then(|name| name.utf8_text(code))
+            } else {
+                None
+            }
+        }
+
+        fn type_ref<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+            match node.kind_id().into() {
+                // `java.util.List` is a single type
+                ScopedTypeIdentifier | TypeIdentifier => node.utf8_text(code),
+                // Static accesses to the members of a class: `Math.max(a, b)`
+                Identifier => node
+                    .parent()
+                    .filter(|parent| {
+                        matches!(parent.kind_id().into(), MethodInvocation | FieldAccess)
+                    })
+                    .and_then(|parent| parent.child_by_field_name("object"))
+                    .filter(|object| object.id() == node.id())
+                    .map(|_| node.utf8_text(code).unwrap_or_default())
+                    .filter(|name| is_capitalized(name)),
+                _ => None,
+            }
+        }
+
+        if let ClassDeclaration | InterfaceDeclaration = node.kind_id().into() {
+            compute_cbo(
+                node,
+                node,
+                code,
+                stats,
+                type_param,
+                |node| {
+                    matches!(
+                        node.kind_id().into(),
+                        ClassDeclaration | InterfaceDeclaration
+                    )
+                },
+                type_ref,
+            );
+        }
+    }
+}
+
+macro_rules! ts_cbo {
+    ($lang:ident) => {
+        fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+            use $lang::*;
+
+            fn type_param<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+                if node.kind_id() == TypeParameter {
+                    node.child_by_field_name("name")
+                        .and_then(|name| name.utf8_text(code))
+                } else {
+                    None
+                }
+            }
+
+            fn type_ref<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+                match node.kind_id().into() {
+                    // `ns.Type` is a single type
+                    NestedTypeIdentifier | TypeIdentifier => node.utf8_text(code),
+                    // Instantiations and static accesses to the members of a class:
+                    // `new Foo()` and `Foo.bar()`
+                    Identifier => node
+                        .parent()
+                        .and_then(|parent| match parent.kind_id().into() {
+                            NewExpression => parent.child_by_field_name("constructor"),
+                            MemberExpression => parent.child_by_field_name("object"),
+                            _ => None,
+                        })
+                        .filter(|object| object.id() == node.id())
+                        .map(|_| node.utf8_text(code).unwrap_or_default())
+                        .filter(|name| is_capitalized(name)),
+                    _ => None,
+                }
+            }
+
+            if let Class | ClassDeclaration | AbstractClassDeclaration | InterfaceDeclaration =
+                node.kind_id().into()
+            {
+                compute_cbo(
+                    node,
+                    node,
+                    code,
+                    stats,
+                    type_param,
+                    |node| {
+                        matches!(
+                            node.kind_id().into(),
+                            Class
+                                | ClassDeclaration
+                                | AbstractClassDeclaration
+                                | InterfaceDeclaration
+                        )
+                    },
+                    type_ref,
+                );
+            }
+        }
+    };
+}
+
+impl Cbo for TypescriptCode {
+    ts_cbo!(Typescript);
+}
+
+impl Cbo for TsxCode {
+    ts_cbo!(Tsx);
+}
+
+impl Cbo for PythonCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Python::{Attribute, Block, Block2, Call, ClassDefinition, Identifier, Type};
+
+        // Python has no type declarations, so only capitalized names
+        // (PEP 8 class names) are considered in type hints, instantiations
+        // and static accesses to the members of a class
+        fn type_ref<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+            if node.kind_id() != Identifier {
+                return None;
+            }
+            let parent = node.parent()?;
+            let is_ref = match parent.kind_id().into() {
+                Call => parent
+                    .child_by_field_name("function")
+                    .is_some_and(|function| function.id() == node.id()),
+                Attribute => parent
+                    .child_by_field_name("object")
+                    .is_some_and(|object| object.id() == node.id()),
+                // A name inside a type hint, such as `Optional[Foo]`
+                _ => {
+                    let mut ancestor = Some(parent);
+                    while let Some(node) = ancestor
+                        .filter(|node| !matches!(node.kind_id().into(), Type | Block | Block2))
+                    {
+                        ancestor = node.parent();
+                    }
+                    ancestor.is_some_and(|node| node.kind_id() == Type)
+                }
+            };
+            node.utf8_text(code)
+                .filter(|name| is_ref && is_capitalized(name))
+        }
+
+        if node.kind_id() == ClassDefinition {
+            compute_cbo(
+                node,
+                node,
+                code,
+                stats,
+                |_, _| None,
+                |node| node.kind_id() == ClassDefinition,
+                type_ref,
+            );
+        }
+    }
+}
+
+impl Cbo for CppCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Cpp::*;
+
+        fn type_param<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+            match node.kind_id().into() {
+                TypeParameterDeclaration
+                | OptionalTypeParameterDeclaration
+                | VariadicTypeParameterDeclaration => node
+                    .first_child(|id| id == TypeIdentifier)
+                    .and_then(|name| name.utf8_text(code)),
+                _ => None,
+            }
+        }
+
+        fn is_nested_class(node: &Node) -> bool {
+            matches!(
+                node.kind_id().into(),
+                ClassSpecifier | StructSpecifier | UnionSpecifier
+            ) && node.child_by_field_name("body").is_some()
+        }
+
+        if is_nested_class(node) {
+            // The template parameters of a class are declared
+            // in the enclosing template declaration
+            let header = node
+                .parent()
+                .filter(|parent| parent.kind_id() == TemplateDeclaration)
+                .unwrap_or(*node);
+            compute_cbo(
+                node,
+                &header,
+                code,
+                stats,
+                type_param,
+                is_nested_class,
+                |node, code| {
+                    if node.kind_id() == TypeIdentifier {
+                        node.utf8_text(code)
+                    } else {
+                        None
+                    }
+                },
+            );
+        }
+    }
+}
+
+implement_metric_trait!(
+    Cbo,
+    MozjsCode,
+    JavascriptCode,
+    RustCode,
+    CCode,
+    PreprocCode,
+    CcommentCode,
+    KotlinCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics;
+
+    use super::*;
+
+    #[test]
+    fn java_single_class() {
+        check_metrics::<JavaParser>(
+            "import java.util.List;
+
+            public class Example<T> {
+                private Foo foo = new Foo();     // +1 Foo
+                private List<Bar> bars;          // +1 List, +1 Bar
+                private T item;
+                private int n;
+
+                public Example(Baz baz) {        // +1 Baz
+                    n = Math.max(0, baz.size()); // +1 Math
+                }
+
+                public <U> Example<T> with(U u) {
+                    Qux qux = new Qux(u);        // +1 Qux
+                    return this;
+                }
+            }",
+            "foo.java",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cbo,
+                    @r###"
+                    {
+                      "classes": 1.0,
+                      "sum": 6.0,
+                      "average": 6.0,
+                      "min": 6.0,
+                      "max": 6.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_nested_classes() {
+        check_metrics::<JavaParser>(
+            "class Outer {            // cbo = 2
+                private Inner inner;  // +1 Inner
+                private Foo foo;      // +1 Foo
+
+                class Inner {         // cbo = 3
+                    private Bar bar;  // +1 Bar
+                    private Foo foo;  // +1 Foo
+                    private Outer o;  // +1 Outer
+                }
+            }
+
+            interface Shape {         // cbo = 1
+                double area(Unit u);  // +1 Unit
+            }",
+            "foo.java",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cbo,
+                    @r###"
+                    {
+                      "classes": 3.0,
+                      "sum": 6.0,
+                      "average": 2.0,
+                      "min": 1.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_no_classes() {
+        check_metrics::<JavaParser>("import java.util.List;", "foo.java", |metric| {
+            assert!(metric.cbo.is_disabled());
+        });
+    }
+
+    #[test]
+    fn typescript_class() {
+        check_metrics::<TypescriptParser>(
+            "class A<T> extends B {
+                private x: number = 1;
+                y: Foo;                                          // +1 Foo
+                constructor(b: Bar) { this.x = 2; }              // +1 Bar
+                get(t: T): ns.Baz {                              // +1 ns.Baz
+                    return Util.f(this.y, new Qux(), new A());   // +1 Util, +1 Qux
+                }
+            }",
+            "foo.ts",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cbo,
+                    @r###"
+                    {
+                      "classes": 1.0,
+                      "sum": 5.0,
+                      "average": 5.0,
+                      "min": 5.0,
+                      "max": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_class() {
+        check_metrics::<PythonParser>(
+            "class A(B):
+                def __init__(self, b: Bar):           # +1 Bar
+                    self.x = Foo()                    # +1 Foo
+                    self.items = list()
+
+                def get(self) -> Optional[Baz]:       # +1 Optional, +1 Baz
+                    return Util.f(self.x, A())        # +1 Util",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cbo,
+                    @r###"
+                    {
+                      "classes": 1.0,
+                      "sum": 5.0,
+                      "average": 5.0,
+                      "min": 5.0,
+                      "max": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+}
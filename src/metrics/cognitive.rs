@@ -5,6 +5,7 @@ use serde::Serialize;
 use std::fmt;
 
 use crate::checker::Checker;
+use crate::distribution::Distribution;
 use crate::macros::implement_metric_trait;
 use crate::*;
 
@@ -27,6 +28,7 @@ pub struct Stats {
     nesting: usize,
     total_space_functions: usize,
     boolean_seq: BoolSequence,
+    distribution: Distribution,
 }
 
 impl Default for Stats {
@@ -39,6 +41,7 @@ impl Default for Stats {
             nesting: 0,
             total_space_functions: 1,
             boolean_seq: BoolSequence::default(),
+            distribution: Distribution::default(),
         }
     }
 }
@@ -48,11 +51,16 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut st = serializer.serialize_struct("cognitive", 4)?;
+        let mut st = serializer.serialize_struct("cognitive", 9)?;
         st.serialize_field("sum", &self.cognitive_sum())?;
         st.serialize_field("average", &self.cognitive_average())?;
         st.serialize_field("min", &self.cognitive_min())?;
         st.serialize_field("max", &self.cognitive_max())?;
+        st.serialize_field("median", &self.cognitive_distribution().median())?;
+        st.serialize_field("p90", &self.cognitive_distribution().p90())?;
+        st.serialize_field("p95", &self.cognitive_distribution().p95())?;
+        st.serialize_field("stddev", &self.cognitive_distribution().stddev())?;
+        st.serialize_field("histogram", &self.cognitive_distribution().histogram())?;
         st.end()
     }
 }
@@ -76,6 +84,7 @@ impl Stats {
         self.structural_min = self.structural_min.min(other.structural_min);
         self.structural_max = self.structural_max.max(other.structural_max);
         self.structural_sum += other.structural_sum;
+        self.distribution.merge(&other.distribution);
     }
 
     /// Returns the `Cognitive Complexity` metric value
@@ -109,6 +118,14 @@ impl Stats {
     pub(crate) fn compute_sum(&mut self) {
         self.structural_sum += self.structural;
     }
+
+    /// Returns the distribution of the `Cognitive Complexity` metric values
+    /// of the functions in a space
+    #[inline(always)]
+    pub fn cognitive_distribution(&self) -> &Distribution {
+        &self.distribution
+    }
+
     #[inline(always)]
     pub(crate) fn compute_minmax(&mut self) {
         self.structural_min = self.structural_min.min(self.structural);
@@ -116,6 +133,12 @@ impl Stats {
         self.compute_sum();
     }
 
+    // Adds the `Cognitive Complexity` value of a function to the distribution
+    #[inline(always)]
+    pub(crate) fn compute_distribution(&mut self) {
+        self.distribution.add(self.structural as f64);
+    }
+
     pub(crate) fn finalize(&mut self, total_space_functions: usize) {
         self.total_space_functions = total_space_functions;
     }
@@ -242,9 +265,19 @@ impl Cognitive for PythonCode {
         let (mut nesting, mut depth, mut lambda) = get_nesting_from_map(node, nesting_map);
 
         match node.kind_id().into() {
-            IfStatement | ForStatement | WhileStatement | ConditionalExpression => {
+            IfStatement
+            | ForStatement
+            | WhileStatement
+            | MatchStatement
+            | ConditionalExpression => {
                 increase_nesting(stats, &mut nesting, depth, lambda);
             }
+            // Each `for` and `if` of a comprehension, as for the cyclomatic
+            // complexity, and the guard of a `case`: a comprehension is
+            // an expression, so its clauses do not increase the nesting
+            IfClause | ForInClause => {
+                increment_by_one(stats);
+            }
             ElifClause => {
                 // No nesting increment for them because their cost has already
                 // been paid by the if construct
@@ -257,7 +290,7 @@ impl Cognitive for PythonCode {
                 // been paid by the if construct
                 increment_by_one(stats);
             }
-            ExceptClause => {
+            ExceptClause | ExceptGroupClause => {
                 nesting += 1;
                 increment(stats);
             }
@@ -354,6 +387,38 @@ impl Cognitive for RustCode {
     }
 }
 
+impl Cognitive for CCode {
+    fn compute(
+        node: &Node,
+        stats: &mut Stats,
+        nesting_map: &mut HashMap<usize, (usize, usize, usize)>,
+    ) {
+        use C::*;
+
+        let (mut nesting, depth, lambda) = get_nesting_from_map(node, nesting_map);
+
+        match node.kind_id().into() {
+            IfStatement if !Self::is_else_if(node) => {
+                increase_nesting(stats, &mut nesting, depth, lambda);
+            }
+            ForStatement | WhileStatement | DoStatement | SwitchStatement => {
+                increase_nesting(stats, &mut nesting, depth, lambda);
+            }
+            GotoStatement | Else /* else-if also */ => {
+                increment_by_one(stats);
+            }
+            UnaryExpression | UnaryExpression2 => {
+                stats.boolean_seq.not_operator(node.kind_id());
+            }
+            BinaryExpression | BinaryExpression2 => {
+                compute_booleans::<language_c::C>(node, stats, AMPAMP, PIPEPIPE);
+            }
+            _ => {}
+        }
+        nesting_map.insert(node.id(), (nesting, depth, lambda));
+    }
+}
+
 impl Cognitive for CppCode {
     fn compute(
         node: &Node,
@@ -469,10 +534,12 @@ impl Cognitive for JavaCode {
                     increase_nesting(stats,&mut nesting, depth, lambda);
                 }
             }
+            // The block of a switch expression or statement
             ForStatement | WhileStatement | DoStatement | SwitchBlock | CatchClause => {
                 increase_nesting(stats,&mut nesting, depth, lambda);
             }
-            Else /* else-if also */ => {
+            // `case Circle c when c.r() > 1`
+            Else /* else-if also */ | Guard => {
                 increment_by_one(stats);
             }
             UnaryExpression => {
@@ -508,7 +575,12 @@ mod tests {
                       "sum": 0.0,
                       "average": null,
                       "min": 0.0,
-                      "max": 0.0
+                      "max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -524,7 +596,12 @@ mod tests {
                       "sum": 0.0,
                       "average": null,
                       "min": 0.0,
-                      "max": 0.0
+                      "max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -540,7 +617,12 @@ mod tests {
                       "sum": 0.0,
                       "average": null,
                       "min": 0.0,
-                      "max": 0.0
+                      "max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -556,7 +638,12 @@ mod tests {
                       "sum": 0.0,
                       "average": null,
                       "min": 0.0,
-                      "max": 0.0
+                      "max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -579,7 +666,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -602,7 +696,14 @@ mod tests {
                       "sum": 1.0,
                       "average": 1.0,
                       "min": 0.0,
-                      "max": 1.0
+                      "max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
                     }"###
                 );
             },
@@ -625,7 +726,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -651,7 +759,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -679,7 +794,14 @@ mod tests {
                       "sum": 6.0,
                       "average": 6.0,
                       "min": 0.0,
-                      "max": 6.0
+                      "max": 6.0,
+                      "median": 6.0,
+                      "p90": 6.0,
+                      "p95": 6.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -706,7 +828,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -733,7 +862,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -760,7 +896,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -782,7 +925,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -806,7 +956,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -827,7 +984,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -851,7 +1015,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -872,7 +1043,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -896,7 +1074,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -917,7 +1102,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -941,7 +1133,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -962,7 +1161,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -983,7 +1189,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1007,7 +1220,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1028,7 +1248,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1052,7 +1279,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1073,7 +1307,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1095,7 +1336,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1119,7 +1367,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1143,7 +1398,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1167,7 +1429,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1192,7 +1461,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1215,7 +1491,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1249,7 +1532,14 @@ mod tests {
                       "sum": 11.0,
                       "average": 11.0,
                       "min": 0.0,
-                      "max": 11.0
+                      "max": 11.0,
+                      "median": 11.0,
+                      "p90": 11.0,
+                      "p95": 11.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1273,7 +1563,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1307,7 +1604,14 @@ mod tests {
                       "sum": 11.0,
                       "average": 11.0,
                       "min": 0.0,
-                      "max": 11.0
+                      "max": 11.0,
+                      "median": 11.0,
+                      "p90": 11.0,
+                      "p95": 11.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1341,7 +1645,14 @@ mod tests {
                       "sum": 11.0,
                       "average": 11.0,
                       "min": 0.0,
-                      "max": 11.0
+                      "max": 11.0,
+                      "median": 11.0,
+                      "p90": 11.0,
+                      "p95": 11.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1365,7 +1676,14 @@ mod tests {
                       "sum": 6.0,
                       "average": 6.0,
                       "min": 0.0,
-                      "max": 6.0
+                      "max": 6.0,
+                      "median": 6.0,
+                      "p90": 6.0,
+                      "p95": 6.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1394,7 +1712,14 @@ mod tests {
                       "sum": 6.0,
                       "average": 6.0,
                       "min": 0.0,
-                      "max": 6.0
+                      "max": 6.0,
+                      "median": 6.0,
+                      "p90": 6.0,
+                      "p95": 6.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1420,7 +1745,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1452,7 +1784,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1487,7 +1826,14 @@ mod tests {
                       "sum": 11.0,
                       "average": 11.0,
                       "min": 0.0,
-                      "max": 11.0
+                      "max": 11.0,
+                      "median": 11.0,
+                      "p90": 11.0,
+                      "p95": 11.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1515,7 +1861,14 @@ mod tests {
                       "sum": 7.0,
                       "average": 7.0,
                       "min": 0.0,
-                      "max": 7.0
+                      "max": 7.0,
+                      "median": 7.0,
+                      "p90": 7.0,
+                      "p95": 7.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1550,7 +1903,52 @@ mod tests {
                       "sum": 1.0,
                       "average": 1.0,
                       "min": 0.0,
-                      "max": 1.0
+                      "max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn c_grammar() {
+        // `new` and `class` are identifiers in C
+        check_metrics::<CParser>(
+            "int f(int new, int class) {
+                 if (new && class) { // +2 (if, &&)
+                     while (new-- || class) { // +3 (while with nesting = 1, ||)
+                         goto end; // +1
+                     }
+                 } else if (class) { // +1
+                     return 1;
+                 }
+             end:
+                 return 0;
+             }",
+            "foo.c",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cognitive,
+                    @r###"
+                    {
+                      "sum": 7.0,
+                      "average": 7.0,
+                      "min": 0.0,
+                      "max": 7.0,
+                      "median": 7.0,
+                      "p90": 7.0,
+                      "p95": 7.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1585,7 +1983,14 @@ mod tests {
                       "sum": 1.0,
                       "average": 1.0,
                       "min": 0.0,
-                      "max": 1.0
+                      "max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
                     }"###
                 );
             },
@@ -1608,7 +2013,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1635,7 +2047,15 @@ mod tests {
                       "sum": 5.0,
                       "average": 1.25,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 1.0,
+                      "p90": 2.7,
+                      "p95": 2.8499999999999996,
+                      "stddev": 1.299038105676658,
+                      "histogram": {
+                        "0-1": 2,
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -1668,7 +2088,14 @@ mod tests {
                       "sum": 9.0,
                       "average": 9.0,
                       "min": 0.0,
-                      "max": 9.0
+                      "max": 9.0,
+                      "median": 9.0,
+                      "p90": 9.0,
+                      "p95": 9.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1697,7 +2124,14 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -1728,7 +2162,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1745,9 +2186,13 @@ mod tests {
               "sum": 0.0,
               "average": null,
               "min": 0.0,
-              "max": 0.0
-            }
-            "###
+              "max": 0.0,
+              "median": null,
+              "p90": null,
+              "p95": null,
+              "stddev": null,
+              "histogram": {}
+            }"###
             );
         });
     }
@@ -1771,9 +2216,15 @@ mod tests {
                   "sum": 1.0,
                   "average": 1.0,
                   "min": 0.0,
-                  "max": 1.0
-                }
-                "###
+                  "max": 1.0,
+                  "median": 1.0,
+                  "p90": 1.0,
+                  "p95": 1.0,
+                  "stddev": 0.0,
+                  "histogram": {
+                    "1-2": 1
+                  }
+                }"###
                 );
             },
         );
@@ -1804,9 +2255,15 @@ mod tests {
                   "sum": 3.0,
                   "average": 3.0,
                   "min": 0.0,
-                  "max": 3.0
-                }
-                "###
+                  "max": 3.0,
+                  "median": 3.0,
+                  "p90": 3.0,
+                  "p95": 3.0,
+                  "stddev": 0.0,
+                  "histogram": {
+                    "2-4": 1
+                  }
+                }"###
                 );
             },
         );
@@ -1834,7 +2291,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 4.0,
                       "min": 0.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -1867,7 +2331,14 @@ mod tests {
                       "sum": 1.0,
                       "average": 1.0,
                       "min": 0.0,
-                      "max": 1.0
+                      "max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
                     }"###
                 );
             },
@@ -1895,7 +2366,14 @@ mod tests {
                       "sum": 1.0,
                       "average": 1.0,
                       "min": 0.0,
-                      "max": 1.0
+                      "max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
                     }"###
                 );
             },
@@ -1921,7 +2399,98 @@ mod tests {
                       "sum": 3.0,
                       "average": 3.0,
                       "min": 0.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_switch_patterns() {
+        check_metrics::<JavaParser>(
+            "class X {
+              String f(Object o) {
+                if (o instanceof String s && !s.isEmpty()) { // +2 (+1 &&)
+                  return s;
+                }
+                return switch (o) { // +1
+                  case Circle c when c.r() > 1 -> \"big\"; // +1
+                  case Circle c -> { // +0
+                    if (c.r() == 0) { // +2 (nesting = 1)
+                      yield \"point\";
+                    }
+                    yield \"circle\";
+                  }
+                  default -> \"other\";
+                };
+              }
+            }",
+            "foo.java",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cognitive,
+                    @r###"
+                    {
+                      "sum": 6.0,
+                      "average": 6.0,
+                      "min": 0.0,
+                      "max": 6.0,
+                      "median": 6.0,
+                      "p90": 6.0,
+                      "p95": 6.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_match_and_comprehensions() {
+        check_metrics::<PythonParser>(
+            "async def f(x):
+                 match x:  # +1
+                     case [a] if a > 0:  # +1
+                         if a > 1:  # +2 (nesting = 1)
+                             pass
+                     case _:
+                         pass
+                 # Each `for` and `if` of a comprehension
+                 y = [i for i in x if i for j in i if j]  # +4
+                 async for i in x:  # +1
+                     pass
+                 try:
+                     pass
+                 except* ValueError:  # +1
+                     pass",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cognitive,
+                    @r###"
+                    {
+                      "sum": 10.0,
+                      "average": 10.0,
+                      "min": 0.0,
+                      "max": 10.0,
+                      "median": 10.0,
+                      "p90": 10.0,
+                      "p95": 10.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -0,0 +1,175 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::fmt;
+
+/// The `Crap` metric.
+///
+/// This metric combines the `Cyclomatic` complexity of a function
+/// with the ratio of its lines covered by tests:
+/// `cyclomatic² × (1 − coverage)³ + cyclomatic`.
+///
+/// The coverage data are read from a coverage report,
+/// so this metric is computed by a [`Coverage`] rather than while
+/// traversing a file.
+///
+/// Original paper and definition:
+/// <https://www.artima.com/weblogs/viewpost.jsp?thread=210575>
+///
+/// [`Coverage`]: crate::Coverage
+#[derive(Clone, Debug, Default)]
+pub struct Stats {
+    lines: usize,
+    covered_lines: usize,
+    branches: usize,
+    covered_branches: usize,
+    crap: f64,
+    crap_sum: f64,
+    crap_max: f64,
+    functions: usize,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("crap", 10)?;
+        st.serialize_field("lines", &self.lines())?;
+        st.serialize_field("covered_lines", &self.covered_lines())?;
+        st.serialize_field("line_coverage", &self.line_coverage())?;
+        st.serialize_field("branches", &self.branches())?;
+        st.serialize_field("covered_branches", &self.covered_branches())?;
+        st.serialize_field("branch_coverage", &self.branch_coverage())?;
+        st.serialize_field("crap", &self.crap())?;
+        st.serialize_field("sum", &self.crap_sum())?;
+        st.serialize_field("average", &self.crap_average())?;
+        st.serialize_field("max", &self.crap_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "lines: {}, covered_lines: {}, line_coverage: {}, branches: {}, covered_branches: {}, branch_coverage: {}, crap: {}, sum: {}, average: {}, max: {}",
+            self.lines(),
+            self.covered_lines(),
+            self.line_coverage(),
+            self.branches(),
+            self.covered_branches(),
+            self.branch_coverage(),
+            self.crap(),
+            self.crap_sum(),
+            self.crap_average(),
+            self.crap_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Crap` metric into the first one
+    ///
+    /// The coverage of the lines and of the branches is not merged,
+    /// since the lines of a space already contain the ones of its subspaces.
+    pub fn merge(&mut self, other: &Stats) {
+        self.crap_sum += other.crap_sum;
+        self.crap_max = self.crap_max.max(other.crap_max);
+        self.functions += other.functions;
+    }
+
+    /// Returns the number of lines of a space found in the coverage report
+    #[inline(always)]
+    pub fn lines(&self) -> f64 {
+        self.lines as f64
+    }
+
+    /// Returns the number of lines of a space executed at least once
+    #[inline(always)]
+    pub fn covered_lines(&self) -> f64 {
+        self.covered_lines as f64
+    }
+
+    /// Returns the ratio of covered lines of a space
+    ///
+    /// If there are no lines in the coverage report, its value is `NAN`.
+    #[inline(always)]
+    pub fn line_coverage(&self) -> f64 {
+        self.covered_lines() / self.lines()
+    }
+
+    /// Returns the number of branches of a space
+    #[inline(always)]
+    pub fn branches(&self) -> f64 {
+        self.branches as f64
+    }
+
+    /// Returns the number of branches of a space taken at least once
+    #[inline(always)]
+    pub fn covered_branches(&self) -> f64 {
+        self.covered_branches as f64
+    }
+
+    /// Returns the ratio of covered branches of a space
+    ///
+    /// If there are no branches in a space, its value is `NAN`.
+    #[inline(always)]
+    pub fn branch_coverage(&self) -> f64 {
+        self.covered_branches() / self.branches()
+    }
+
+    /// Returns the `Crap` metric value of a function
+    #[inline(always)]
+    pub fn crap(&self) -> f64 {
+        self.crap
+    }
+
+    /// Returns the sum of the `Crap` metric values of the functions in a space
+    #[inline(always)]
+    pub fn crap_sum(&self) -> f64 {
+        self.crap_sum
+    }
+
+    /// Returns the `Crap` metric average value of the functions in a space
+    #[inline(always)]
+    pub fn crap_average(&self) -> f64 {
+        self.crap_sum / self.functions as f64
+    }
+
+    /// Returns the `Crap` metric maximum value of the functions in a space
+    #[inline(always)]
+    pub fn crap_max(&self) -> f64 {
+        self.crap_max
+    }
+
+    // Checks if the `Crap` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.lines == 0 && self.functions == 0
+    }
+
+    // Sets the coverage of the lines and of the branches of a space
+    pub(crate) fn set_coverage(
+        &mut self,
+        lines: usize,
+        covered_lines: usize,
+        branches: usize,
+        covered_branches: usize,
+    ) {
+        self.lines = lines;
+        self.covered_lines = covered_lines;
+        self.branches = branches;
+        self.covered_branches = covered_branches;
+    }
+
+    // Computes the `Crap` metric of a function from its `Cyclomatic` complexity
+    pub(crate) fn compute_function(&mut self, cyclomatic: f64) {
+        if self.lines == 0 {
+            return;
+        }
+        self.crap = cyclomatic.powi(2) * (1. - self.line_coverage()).powi(3) + cyclomatic;
+        self.crap_sum += self.crap;
+        self.crap_max = self.crap_max.max(self.crap);
+        self.functions += 1;
+    }
+}
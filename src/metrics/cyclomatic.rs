@@ -3,6 +3,7 @@ use serde::Serialize;
 use std::fmt;
 
 use crate::checker::Checker;
+use crate::distribution::Distribution;
 use crate::macros::implement_metric_trait;
 use crate::*;
 
@@ -14,6 +15,7 @@ pub struct Stats {
     n: usize,
     cyclomatic_max: f64,
     cyclomatic_min: f64,
+    distribution: Distribution,
 }
 
 impl Default for Stats {
@@ -24,6 +26,7 @@ impl Default for Stats {
             n: 1,
             cyclomatic_max: 0.,
             cyclomatic_min: f64::MAX,
+            distribution: Distribution::default(),
         }
     }
 }
@@ -33,11 +36,16 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut st = serializer.serialize_struct("cyclomatic", 4)?;
+        let mut st = serializer.serialize_struct("cyclomatic", 9)?;
         st.serialize_field("sum", &self.cyclomatic_sum())?;
         st.serialize_field("average", &self.cyclomatic_average())?;
         st.serialize_field("min", &self.cyclomatic_min())?;
         st.serialize_field("max", &self.cyclomatic_max())?;
+        st.serialize_field("median", &self.cyclomatic_distribution().median())?;
+        st.serialize_field("p90", &self.cyclomatic_distribution().p90())?;
+        st.serialize_field("p95", &self.cyclomatic_distribution().p95())?;
+        st.serialize_field("stddev", &self.cyclomatic_distribution().stddev())?;
+        st.serialize_field("histogram", &self.cyclomatic_distribution().histogram())?;
         st.end()
     }
 }
@@ -64,6 +72,7 @@ impl Stats {
 
         self.cyclomatic_sum += other.cyclomatic_sum;
         self.n += other.n;
+        self.distribution.merge(&other.distribution);
     }
 
     /// Returns the `Cyclomatic` metric value
@@ -94,12 +103,26 @@ impl Stats {
     pub(crate) fn compute_sum(&mut self) {
         self.cyclomatic_sum += self.cyclomatic;
     }
+
+    /// Returns the distribution of the `Cyclomatic` metric values
+    /// of the functions in a space
+    #[inline(always)]
+    pub fn cyclomatic_distribution(&self) -> &Distribution {
+        &self.distribution
+    }
+
     #[inline(always)]
     pub(crate) fn compute_minmax(&mut self) {
         self.cyclomatic_max = self.cyclomatic_max.max(self.cyclomatic);
         self.cyclomatic_min = self.cyclomatic_min.min(self.cyclomatic);
         self.compute_sum();
     }
+
+    // Adds the `Cyclomatic` value of a function to the distribution
+    #[inline(always)]
+    pub(crate) fn compute_distribution(&mut self) {
+        self.distribution.add(self.cyclomatic);
+    }
 }
 
 pub trait Cyclomatic
@@ -114,17 +137,31 @@ impl Cyclomatic for PythonCode {
         use Python::*;
 
         match node.kind_id().into() {
-            If | Elif | For | While | Except | With | Assert | And | Or => {
+            If | Elif | For | While | Except | ExceptSTAR | With | Assert | And | Or => {
                 stats.cyclomatic += 1.;
             }
-            Else => {
-                if node.has_ancestors(
-                    |node| matches!(node.kind_id().into(), ForStatement | WhileStatement),
-                    |node| node.kind_id() == ElseClause,
-                ) {
+            // `case _:` is the default case of a `match`
+            Case => {
+                let is_wildcard = node
+                    .parent()
+                    .and_then(|clause| clause.first_child(|id| id == CasePattern))
+                    .is_some_and(|pattern| {
+                        pattern.child_count() == 1
+                            && pattern
+                                .child(0)
+                                .is_some_and(|child| child.kind_id() == UNDERSCORE)
+                    });
+                if !is_wildcard {
                     stats.cyclomatic += 1.;
                 }
             }
+            Else if node.has_ancestors(
+                |node| matches!(node.kind_id().into(), ForStatement | WhileStatement),
+                |node| node.kind_id() == ElseClause,
+            ) =>
+            {
+                stats.cyclomatic += 1.;
+            }
             _ => {}
         }
     }
@@ -195,6 +232,19 @@ impl Cyclomatic for RustCode {
     }
 }
 
+impl Cyclomatic for CCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use C::*;
+
+        if matches!(
+            node.kind_id().into(),
+            If | For | While | Case | ConditionalExpression | AMPAMP | PIPEPIPE
+        ) {
+            stats.cyclomatic += 1.;
+        }
+    }
+}
+
 impl Cyclomatic for CppCode {
     fn compute(node: &Node, stats: &mut Stats) {
         use Cpp::*;
@@ -203,6 +253,11 @@ impl Cyclomatic for CppCode {
             If | For | While | Case | Catch | ConditionalExpression | AMPAMP | PIPEPIPE => {
                 stats.cyclomatic += 1.;
             }
+            // A coroutine suspended by `co_await` or `co_yield`
+            // is either resumed or destroyed
+            CoAwaitExpression | CoYieldStatement => {
+                stats.cyclomatic += 1.;
+            }
             _ => {}
         }
     }
@@ -213,7 +268,8 @@ impl Cyclomatic for JavaCode {
         use Java::*;
 
         match node.kind_id().into() {
-            If | For | While | Case | Catch | TernaryExpression | AMPAMP | PIPEPIPE => {
+            // A `case` of a switch expression or statement, and the `when` guard of a pattern
+            If | For | While | Case | Guard | Catch | TernaryExpression | AMPAMP | PIPEPIPE => {
                 stats.cyclomatic += 1.;
             }
             _ => {}
@@ -221,7 +277,25 @@ impl Cyclomatic for JavaCode {
     }
 }
 
-implement_metric_trait!(Cyclomatic, KotlinCode, PreprocCode, CcommentCode);
+impl Cyclomatic for KotlinCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Kotlin::*;
+
+        match node.kind_id().into() {
+            // The elvis operator `a ?: b` is a conditional expression
+            If | For | While | Catch | AMPAMP | PIPEPIPE | QMARKCOLON => {
+                stats.cyclomatic += 1.;
+            }
+            // A branch of a `when`, except `else`
+            WhenEntry if !node.is_child(Else as u16) => {
+                stats.cyclomatic += 1.;
+            }
+            _ => {}
+        }
+    }
+}
+
+implement_metric_trait!(Cyclomatic, PreprocCode, CcommentCode);
 
 #[cfg(test)]
 mod tests {
@@ -247,7 +321,14 @@ mod tests {
                       "sum": 6.0,
                       "average": 3.0,
                       "min": 1.0,
-                      "max": 5.0
+                      "max": 5.0,
+                      "median": 5.0,
+                      "p90": 5.0,
+                      "p95": 5.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -271,7 +352,14 @@ mod tests {
                       "sum": 4.0,
                       "average": 2.0,
                       "min": 1.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -299,7 +387,14 @@ mod tests {
                       "sum": 5.0,
                       "average": 2.5,
                       "min": 1.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -335,7 +430,53 @@ mod tests {
                       "sum": 5.0,
                       "average": 2.5,
                       "min": 1.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn c_grammar() {
+        // `new` and `class` are identifiers in C
+        check_metrics::<CParser>(
+            "int f(int new, int class) { // +2 (+1 unit space)
+                 if (new && class) { // +2 (if, &&)
+                     while (new-- || class) { // +2 (while, ||)
+                         goto end;
+                     }
+                 } else if (class) { // +1
+                     return 1;
+                 }
+             end:
+                 return 0;
+             }",
+            "foo.c",
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 7.0,
+                      "average": 3.5,
+                      "min": 1.0,
+                      "max": 6.0,
+                      "median": 6.0,
+                      "p90": 6.0,
+                      "p95": 6.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -367,7 +508,14 @@ mod tests {
                       "sum": 5.0,
                       "average": 2.5,
                       "min": 1.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -409,7 +557,14 @@ mod tests {
                       "sum": 7.0,
                       "average": 3.5,
                       "min": 3.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -455,7 +610,14 @@ mod tests {
                       "sum": 7.0,
                       "average": 3.5,
                       "min": 3.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -493,7 +655,14 @@ mod tests {
                       "sum": 9.0,
                       "average": 2.25,
                       "min": 1.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 2.5,
+                      "p90": 2.9,
+                      "p95": 2.95,
+                      "stddev": 0.5,
+                      "histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -546,7 +715,14 @@ mod tests {
                       "sum": 11.0,
                       "average": 2.2,
                       "min": 1.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": 3.0,
+                      "p90": 3.0,
+                      "p95": 3.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 3
+                      }
                     }"###
                 );
             },
@@ -568,7 +744,7 @@ mod tests {
             public class B { // +1
 
                 public void test() { // +1
-                    A a = new A() {
+                    A a = new A() { // +1
                         public boolean m1(int n) { // +1
                             if (n % 2 == 0) { // +1
                                 return true;
@@ -586,15 +762,142 @@ mod tests {
             }",
             "foo.java",
             |metric| {
-                // nspace = 8 (unit, 2 classes and 5 methods)
+                // nspace = 9 (unit, 3 classes and 5 methods)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 11.0,
+                      "average": 1.2222222222222223,
+                      "min": 1.0,
+                      "max": 2.0,
+                      "median": 1.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.4898979485566356,
+                      "histogram": {
+                        "1-2": 3,
+                        "2-4": 2
+                      }
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_switch_patterns() {
+        check_metrics::<JavaParser>(
+            "class A { // +1
+                String f(Object o) { // +1
+                    return switch (o) {
+                        case Circle c when c.r() > 1 -> \"big\"; // +2
+                        case Circle(double r) -> \"circle\"; // +1
+                        case String s -> { // +1
+                            yield s.isEmpty() ? \"empty\" : s; // +1
+                        }
+                        default -> \"other\";
+                    };
+                }
+            }",
+            "foo.java",
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                // `default` is not counted
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 8.0,
+                      "average": 2.6666666666666665,
+                      "min": 1.0,
+                      "max": 6.0,
+                      "median": 6.0,
+                      "p90": 6.0,
+                      "p95": 6.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_coroutine() {
+        check_metrics::<CppParser>(
+            "task<int> f(int x) { // +1
+                if (x) { // +1
+                    co_return 0;
+                }
+                int y = co_await g(x); // +1
+                co_yield y; // +1
+                co_return y;
+            }",
+            "foo.cpp",
+            |metric| {
+                // nspace = 2 (unit and function)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 2.5,
+                      "min": 1.0,
+                      "max": 4.0,
+                      "median": 4.0,
+                      "p90": 4.0,
+                      "p95": 4.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "4-8": 1
+                      }
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_match_and_comprehensions() {
+        check_metrics::<PythonParser>(
+            "async def f(x):  # +1
+                 match x:
+                     case [a] if a > 0:  # +2
+                         pass
+                     case {'k': b}:  # +1
+                         pass
+                     case _:
+                         pass
+                 # Each `for` and `if` of a comprehension, as for cognitive
+                 y = [i for i in x if i for j in i if j]  # +4
+                 async for i in x:  # +1
+                     async with i:  # +1
+                         pass
+                 try:
+                     pass
+                 except* ValueError:  # +1
+                     pass",
+            "foo.py",
+            |metric| {
+                // nspace = 2 (func and unit)
                 insta::assert_json_snapshot!(
                     metric.cyclomatic,
                     @r###"
                     {
-                      "sum": 10.0,
-                      "average": 1.25,
+                      "sum": 12.0,
+                      "average": 6.0,
                       "min": 1.0,
-                      "max": 2.0
+                      "max": 11.0,
+                      "median": 11.0,
+                      "p90": 11.0,
+                      "p95": 11.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -0,0 +1,284 @@
+use serde::ser::{SerializeMap, SerializeStruct, Serializer};
+use serde::Serialize;
+use std::fmt;
+
+/// A bucket of the histogram of a [`Distribution`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bucket {
+    /// The first value of a bucket
+    pub start: f64,
+    /// The value following the last value of a bucket
+    pub end: f64,
+    /// The number of values contained in a bucket
+    pub count: usize,
+}
+
+/// The histogram of a [`Distribution`].
+///
+/// The buckets start from `[0, 1)` and then double their width,
+/// so that a few very large values do not hide the small ones.
+/// Only the buckets containing some values are kept, and they are
+/// serialized as a map from the `start-end` range of a bucket to its count.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Histogram {
+    /// The non-empty buckets of a histogram, sorted by their range
+    pub buckets: Vec<Bucket>,
+}
+
+impl Serialize for Histogram {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.buckets.len()))?;
+        for bucket in &self.buckets {
+            map.serialize_entry(&format!("{}-{}", bucket.start, bucket.end), &bucket.count)?;
+        }
+        map.end()
+    }
+}
+
+/// The distribution of the values of a metric computed on each function
+/// contained in a space.
+///
+/// It shows the skew of the values hidden by their average, such as
+/// a very long function among many short ones.
+///
+/// The metrics of the functions take few distinct values, so each distinct
+/// value is stored once with the number of functions having it: merging
+/// the distributions of the spaces into their parents does not copy the
+/// value of each function, and the values are always sorted.
+#[derive(Clone, Debug, Default)]
+pub struct Distribution {
+    // The distinct values, sorted, and their number of occurrences
+    values: Vec<(f64, usize)>,
+    count: usize,
+}
+
+impl Serialize for Distribution {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("distribution", 5)?;
+        st.serialize_field("median", &self.median())?;
+        st.serialize_field("p90", &self.p90())?;
+        st.serialize_field("p95", &self.p95())?;
+        st.serialize_field("stddev", &self.stddev())?;
+        st.serialize_field("histogram", &self.histogram())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "median: {}, p90: {}, p95: {}, stddev: {}",
+            self.median(),
+            self.p90(),
+            self.p95(),
+            self.stddev()
+        )
+    }
+}
+
+impl Distribution {
+    /// Merges a second distribution into the first one
+    pub fn merge(&mut self, other: &Distribution) {
+        for (value, count) in &other.values {
+            self.insert(*value, *count);
+        }
+    }
+
+    /// Returns the number of values of a distribution
+    #[inline(always)]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    // Returns the value at a position of the sorted values
+    fn nth(&self, index: usize) -> f64 {
+        let mut seen = 0;
+        for (value, count) in &self.values {
+            seen += count;
+            if index < seen {
+                return *value;
+            }
+        }
+        f64::NAN
+    }
+
+    /// Returns the value below which a percentage of the values fall
+    ///
+    /// The value is linearly interpolated between the two closest values.
+    /// If there are no values, it is `NAN`.
+    pub fn percentile(&self, percentage: f64) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        let rank = percentage.clamp(0., 100.) / 100. * (self.count - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        let (lower_value, upper_value) = (self.nth(lower), self.nth(upper));
+        lower_value + (upper_value - lower_value) * (rank - lower as f64)
+    }
+
+    /// Returns the median of the values
+    #[inline(always)]
+    pub fn median(&self) -> f64 {
+        self.percentile(50.)
+    }
+
+    /// Returns the 90th percentile of the values
+    #[inline(always)]
+    pub fn p90(&self) -> f64 {
+        self.percentile(90.)
+    }
+
+    /// Returns the 95th percentile of the values
+    #[inline(always)]
+    pub fn p95(&self) -> f64 {
+        self.percentile(95.)
+    }
+
+    /// Returns the standard deviation of the values
+    ///
+    /// If there are no values, it is `NAN`.
+    pub fn stddev(&self) -> f64 {
+        let count = self.count as f64;
+        let mean = self
+            .values
+            .iter()
+            .map(|(value, n)| value * *n as f64)
+            .sum::<f64>()
+            / count;
+        let variance = self
+            .values
+            .iter()
+            .map(|(value, n)| (value - mean).powi(2) * *n as f64)
+            .sum::<f64>()
+            / count;
+        variance.sqrt()
+    }
+
+    /// Returns the histogram of the values
+    pub fn histogram(&self) -> Histogram {
+        let mut counts: Vec<usize> = Vec::new();
+        for (value, count) in &self.values {
+            // [0, 1) is the first bucket, [2^(i-1), 2^i) the i-th one
+            let index = if *value < 1. {
+                0
+            } else {
+                value.log2().floor() as usize + 1
+            };
+            if counts.len() <= index {
+                counts.resize(index + 1, 0);
+            }
+            counts[index] += count;
+        }
+        let buckets = counts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > 0)
+            .map(|(index, count)| Bucket {
+                start: match index {
+                    0 => 0.,
+                    _ => 2f64.powi(index as i32 - 1),
+                },
+                end: 2f64.powi(index as i32),
+                count,
+            })
+            .collect();
+        Histogram { buckets }
+    }
+
+    // Adds the value of a function to a distribution
+    #[inline(always)]
+    pub(crate) fn add(&mut self, value: f64) {
+        self.insert(value, 1);
+    }
+
+    // Adds some occurrences of a value, keeping the values sorted
+    fn insert(&mut self, value: f64, count: usize) {
+        match self
+            .values
+            .binary_search_by(|(other, _)| other.total_cmp(&value))
+        {
+            Ok(index) => self.values[index].1 += count,
+            Err(index) => self.values.insert(index, (value, count)),
+        }
+        self.count += count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distribution(values: &[f64]) -> Distribution {
+        let mut distribution = Distribution::default();
+        for value in values {
+            distribution.add(*value);
+        }
+        distribution
+    }
+
+    #[test]
+    fn percentiles() {
+        let distribution = distribution(&[3., 1., 4., 2., 10., 6., 5., 8., 7., 9.]);
+        assert_eq!(distribution.median(), 5.5);
+        assert!((distribution.p90() - 9.1).abs() < 1e-9);
+        assert!((distribution.p95() - 9.55).abs() < 1e-9);
+        assert!((distribution.stddev() - 8.25f64.sqrt()).abs() < 1e-9);
+
+        let single = self::distribution(&[300.]);
+        assert_eq!(single.median(), 300.);
+        assert_eq!(single.p95(), 300.);
+        assert_eq!(single.stddev(), 0.);
+    }
+
+    #[test]
+    fn repeated_values() {
+        let mut distribution = distribution(&[2., 1., 2.]);
+        distribution.merge(&self::distribution(&[1., 2., 8.]));
+        assert_eq!(distribution.count(), 6);
+        assert_eq!(distribution.values, [(1., 2), (2., 3), (8., 1)]);
+        assert_eq!(distribution.median(), 2.);
+        assert!((distribution.p90() - 5.).abs() < 1e-9);
+        assert!((distribution.stddev() - (53f64 / 9.).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty() {
+        let distribution = Distribution::default();
+        assert!(distribution.median().is_nan());
+        assert!(distribution.stddev().is_nan());
+        assert!(distribution.histogram().buckets.is_empty());
+    }
+
+    #[test]
+    fn histogram() {
+        let mut distribution = distribution(&[0., 1., 3., 3.]);
+        distribution.merge(&self::distribution(&[300.]));
+        let histogram = distribution.histogram();
+        let buckets: Vec<_> = histogram
+            .buckets
+            .iter()
+            .map(|bucket| (bucket.start, bucket.end, bucket.count))
+            .collect();
+        assert_eq!(
+            buckets,
+            [(0., 1., 1), (1., 2., 1), (2., 4., 2), (256., 512., 1)]
+        );
+        insta::assert_json_snapshot!(
+            histogram,
+            @r###"
+        {
+          "0-1": 1,
+          "1-2": 1,
+          "2-4": 2,
+          "256-512": 1
+        }"###
+        );
+    }
+}
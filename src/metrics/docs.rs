@@ -0,0 +1,720 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::getter::Getter;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// A public item without a documentation comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Item {
+    /// The name of an item
+    pub name: String,
+    /// The first line of an item
+    pub start_line: usize,
+}
+
+/// The `Docs` metric.
+///
+/// This metric counts the public functions, methods and types of a space
+/// and how many of them are documented by a documentation comment,
+/// such as a Rust `///` comment, a Python docstring or a Javadoc comment.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    public: usize,
+    documented: usize,
+    undocumented_items: Vec<Item>,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("docs", 5)?;
+        st.serialize_field("public", &self.public())?;
+        st.serialize_field("documented", &self.documented())?;
+        st.serialize_field("undocumented", &self.undocumented())?;
+        st.serialize_field("coverage", &self.coverage())?;
+        st.serialize_field("undocumented_items", &self.undocumented_items)?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "public: {}, documented: {}, undocumented: {}, coverage: {}",
+            self.public(),
+            self.documented(),
+            self.undocumented(),
+            self.coverage()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Docs` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.public += other.public;
+        self.documented += other.documented;
+        self.undocumented_items
+            .extend(other.undocumented_items.iter().cloned());
+    }
+
+    /// Returns the number of public items
+    pub fn public(&self) -> f64 {
+        self.public as f64
+    }
+
+    /// Returns the number of documented public items
+    pub fn documented(&self) -> f64 {
+        self.documented as f64
+    }
+
+    /// Returns the number of undocumented public items
+    pub fn undocumented(&self) -> f64 {
+        (self.public - self.documented) as f64
+    }
+
+    /// Returns the ratio of documented public items
+    pub fn coverage(&self) -> f64 {
+        self.documented() / self.public()
+    }
+
+    /// Returns the undocumented public items
+    pub fn undocumented_items(&self) -> &[Item] {
+        &self.undocumented_items
+    }
+
+    // Checks if the `Docs` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.public == 0
+    }
+
+    fn add_item<T: Getter>(&mut self, node: &Node, code: &[u8], documented: bool) {
+        self.public += 1;
+        if documented {
+            self.documented += 1;
+        } else {
+            self.undocumented_items.push(Item {
+                name: T::get_func_space_name(node, code)
+                    .unwrap_or("<anonymous>")
+                    .to_string(),
+                start_line: node.start_row() + 1,
+            });
+        }
+    }
+}
+
+pub trait Docs
+where
+    Self: Checker,
+{
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+// Checks whether the comments preceding a node contain a documentation comment.
+// The nodes which can be written between a documentation comment and its item,
+// such as the Rust attributes, are skipped.
+fn has_doc_comment(
+    node: &Node,
+    code: &[u8],
+    is_comment: fn(&Node) -> bool,
+    is_skipped: fn(&Node) -> bool,
+    is_doc: fn(&str) -> bool,
+) -> bool {
+    let mut prev = node.previous_sibling();
+    while let Some(node) = prev {
+        if is_comment(&node) {
+            if node.utf8_text(code).is_some_and(is_doc) {
+                return true;
+            }
+        } else if !is_skipped(&node) {
+            return false;
+        }
+        prev = node.previous_sibling();
+    }
+    false
+}
+
+#[inline(always)]
+fn is_javadoc(text: &str) -> bool {
+    text.starts_with("/**") && !text.starts_with("/***") && text != "/**/"
+}
+
+impl Docs for PythonCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Python::{ClassDefinition, ExpressionStatement, FunctionDefinition};
+
+        if !matches!(node.kind_id().into(), FunctionDefinition | ClassDefinition) {
+            return;
+        }
+
+        // Names starting with an underscore are private, as well as the functions
+        // nested in other functions
+        let is_public = |node: &Node| {
+            Self::get_func_space_name(node, code).is_some_and(|name| !name.starts_with('_'))
+        };
+        if !is_public(node) {
+            return;
+        }
+        let mut parent = node.parent();
+        while let Some(ancestor) = parent {
+            match ancestor.kind_id().into() {
+                FunctionDefinition => return,
+                ClassDefinition if !is_public(&ancestor) => return,
+                _ => {}
+            }
+            parent = ancestor.parent();
+        }
+
+        // A docstring is a string being the first statement of the body
+        let documented = node
+            .child_by_field_name("body")
+            .and_then(|body| body.child(0))
+            .filter(|statement| statement.kind_id() == ExpressionStatement)
+            .and_then(|statement| statement.child(0))
+            .is_some_and(|expr| expr.kind_id() == Python::String);
+        stats.add_item::<Self>(node, code, documented);
+    }
+}
+
+macro_rules! js_docs {
+    ($code:ident, $language:ident, $($item:ident)|+, $($private:ident)|+) => {
+        impl Docs for $code {
+            fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+                use $language::*;
+
+                if !matches!(node.kind_id().into(), $($item)|+) {
+                    return;
+                }
+
+                // A declaration is public when it is exported, a method when
+                // its class is exported and it is not private
+                let is_exported = |node: &Node| {
+                    node.parent()
+                        .is_some_and(|parent| parent.kind_id() == ExportStatement)
+                };
+                let is_public = if node.kind_id() == MethodDefinition {
+                    let is_private = node.children().any(|child| {
+                        matches!(child.kind_id().into(), $($private)|+)
+                            && child.utf8_text(code) != Some("public")
+                    });
+                    !is_private
+                        && node
+                            .parent()
+                            .and_then(|body| body.parent())
+                            .is_some_and(|class| is_exported(&class))
+                } else {
+                    is_exported(node)
+                };
+                if !is_public {
+                    return;
+                }
+
+                let outer = if node.kind_id() == MethodDefinition {
+                    *node
+                } else {
+                    node.parent().unwrap_or(*node)
+                };
+                let documented = has_doc_comment(
+                    &outer,
+                    code,
+                    |node| node.kind_id() == Comment,
+                    |_| false,
+                    is_javadoc,
+                );
+                stats.add_item::<Self>(node, code, documented);
+            }
+        }
+    };
+}
+
+js_docs!(
+    MozjsCode,
+    Mozjs,
+    FunctionDeclaration | GeneratorFunctionDeclaration | ClassDeclaration | MethodDefinition,
+    PrivatePropertyIdentifier
+);
+js_docs!(
+    JavascriptCode,
+    Javascript,
+    FunctionDeclaration | GeneratorFunctionDeclaration | ClassDeclaration | MethodDefinition,
+    PrivatePropertyIdentifier
+);
+js_docs!(
+    TypescriptCode,
+    Typescript,
+    FunctionDeclaration
+        | GeneratorFunctionDeclaration
+        | ClassDeclaration
+        | AbstractClassDeclaration
+        | InterfaceDeclaration
+        | MethodDefinition,
+    PrivatePropertyIdentifier | AccessibilityModifier
+);
+js_docs!(
+    TsxCode,
+    Tsx,
+    FunctionDeclaration
+        | GeneratorFunctionDeclaration
+        | ClassDeclaration
+        | AbstractClassDeclaration
+        | InterfaceDeclaration
+        | MethodDefinition,
+    PrivatePropertyIdentifier | AccessibilityModifier
+);
+
+impl Docs for RustCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Rust::*;
+
+        if !matches!(
+            node.kind_id().into(),
+            FunctionItem
+                | FunctionSignatureItem
+                | StructItem
+                | EnumItem
+                | UnionItem
+                | TraitItem
+                | ModItem
+        ) {
+            return;
+        }
+
+        // Only the items declared as `pub` are public, while the methods
+        // of a trait are as public as their trait
+        let is_pub = |node: &Node| {
+            node.first_child(|id| id == VisibilityModifier)
+                .and_then(|visibility| visibility.utf8_text(code))
+                == Some("pub")
+        };
+        let in_trait = node
+            .parent()
+            .filter(|list| list.kind_id() == DeclarationList)
+            .and_then(|list| list.parent())
+            .filter(|item| item.kind_id() == TraitItem);
+        let is_public = match in_trait {
+            Some(trait_item) => is_pub(&trait_item),
+            None => is_pub(node),
+        };
+        // `mod foo;` is documented in its own file
+        if !is_public || (node.kind_id() == ModItem && node.child_by_field_name("body").is_none()) {
+            return;
+        }
+
+        let is_comment = |node: &Node| matches!(node.kind_id().into(), LineComment | BlockComment);
+        let mut documented = has_doc_comment(
+            node,
+            code,
+            is_comment,
+            |node| node.kind_id() == AttributeItem,
+            |text| {
+                (text.starts_with("///") && !text.starts_with("////"))
+                    || (text.starts_with("/**") && !text.starts_with("/***") && text != "/**/")
+            },
+        ) || {
+            // `#[doc = "..."]` is a documentation comment as well
+            let mut prev = node.previous_sibling();
+            let mut doc_attribute = false;
+            while let Some(attr) = prev.filter(|node| node.kind_id() == AttributeItem) {
+                doc_attribute |= attr
+                    .utf8_text(code)
+                    .is_some_and(|text| text.trim_start_matches("#[").starts_with("doc"));
+                prev = attr.previous_sibling();
+            }
+            doc_attribute
+        };
+        if !documented && node.kind_id() == ModItem {
+            // A module can be documented by the `//!` comments in its body
+            documented = node.child_by_field_name("body").is_some_and(|body| {
+                body.children().any(|child| {
+                    is_comment(&child)
+                        && child
+                            .utf8_text(code)
+                            .is_some_and(|text| text.starts_with("//!") || text.starts_with("/*!"))
+                })
+            });
+        }
+        stats.add_item::<Self>(node, code, documented);
+    }
+}
+
+impl Docs for CCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use C::*;
+
+        let is_type = node.kind_id() == StructSpecifier;
+        if !(Self::is_func(node) || is_type && node.child_by_field_name("body").is_some()) {
+            return;
+        }
+
+        // A static function is private to its translation unit
+        if node
+            .first_child(|id| id == StorageClassSpecifier)
+            .is_some_and(|storage| storage.utf8_text(code) == Some("static"))
+        {
+            return;
+        }
+
+        // A struct is the child of a declaration
+        let outer = node
+            .parent()
+            .filter(|parent| is_type && parent.kind_id() == Declaration)
+            .unwrap_or(*node);
+        let documented = has_doc_comment(
+            &outer,
+            code,
+            |node| node.kind_id() == Comment,
+            |_| false,
+            |text| {
+                is_javadoc(text)
+                    || text.starts_with("/*!")
+                    || (text.starts_with("///") && !text.starts_with("////"))
+                    || text.starts_with("//!")
+            },
+        );
+        stats.add_item::<Self>(node, code, documented);
+    }
+}
+
+impl Docs for CppCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Cpp::*;
+
+        let is_type = matches!(node.kind_id().into(), ClassSpecifier | StructSpecifier);
+        if !(Self::is_func(node) || is_type && node.child_by_field_name("body").is_some()) {
+            return;
+        }
+
+        if node
+            .first_child(|id| id == StorageClassSpecifier)
+            .is_some_and(|storage| storage.utf8_text(code) == Some("static"))
+            && node
+                .parent()
+                .is_none_or(|parent| parent.kind_id() != FieldDeclarationList)
+        {
+            return;
+        }
+
+        // The members of a class are private until a `public:` specifier,
+        // while the members of a struct are public
+        let is_member_public = |member: &Node| {
+            let Some(list) = member
+                .parent()
+                .filter(|p| p.kind_id() == FieldDeclarationList)
+            else {
+                return true;
+            };
+            let mut prev = member.previous_sibling();
+            while let Some(node) = prev {
+                if node.kind_id() == AccessSpecifier {
+                    return node.utf8_text(code) == Some("public");
+                }
+                prev = node.previous_sibling();
+            }
+            list.parent()
+                .is_some_and(|class| class.kind_id() == StructSpecifier)
+        };
+        // A type is the child of a declaration, a template is the parent of its item
+        let mut outer = *node;
+        if is_type {
+            if let Some(parent) = node.parent().filter(|parent| {
+                matches!(
+                    parent.kind_id().into(),
+                    Declaration | FieldDeclaration | Declaration2 | Declaration3 | Declaration4
+                )
+            }) {
+                outer = parent;
+            }
+        }
+        if let Some(parent) = outer
+            .parent()
+            .filter(|parent| parent.kind_id() == TemplateDeclaration)
+        {
+            outer = parent;
+        }
+        if !is_member_public(&outer) {
+            return;
+        }
+
+        let documented = has_doc_comment(
+            &outer,
+            code,
+            |node| node.kind_id() == Comment,
+            |_| false,
+            |text| {
+                is_javadoc(text)
+                    || text.starts_with("/*!")
+                    || (text.starts_with("///") && !text.starts_with("////"))
+                    || text.starts_with("//!")
+            },
+        );
+        stats.add_item::<Self>(node, code, documented);
+    }
+}
+
+impl Docs for JavaCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Java::*;
+
+        if !matches!(
+            node.kind_id().into(),
+            ClassDeclaration
+                | InterfaceDeclaration
+                | EnumDeclaration
+                | RecordDeclaration
+                | AnnotationTypeDeclaration
+                | MethodDeclaration
+                | ConstructorDeclaration
+        ) {
+            return;
+        }
+
+        // The members of an interface are public unless they are private
+        let modifiers = node.first_child(|id| id == Modifiers);
+        let has_modifier = |modifier: Java| {
+            modifiers.is_some_and(|m| m.children().any(|child| child.kind_id() == modifier))
+        };
+        let in_interface = node
+            .parent()
+            .is_some_and(|parent| parent.kind_id() == InterfaceBody);
+        let is_public = has_modifier(Public) || (in_interface && !has_modifier(Private));
+        if !is_public {
+            return;
+        }
+
+        let documented = has_doc_comment(
+            node,
+            code,
+            |node| matches!(node.kind_id().into(), BlockComment | LineComment),
+            |_| false,
+            is_javadoc,
+        );
+        stats.add_item::<Self>(node, code, documented);
+    }
+}
+
+impl Docs for KotlinCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Kotlin::*;
+
+        let is_item = |node: &Node| {
+            matches!(
+                node.kind_id().into(),
+                ClassDeclaration | ObjectDeclaration | FunctionDeclaration
+            )
+        };
+        if !is_item(node) {
+            return;
+        }
+
+        // Declarations are public by default, local declarations are never public
+        let is_public = |node: &Node| {
+            node.first_child(|id| id == Modifiers)
+                .is_none_or(|modifiers| {
+                    modifiers
+                        .first_child(|id| id == VisibilityModifier)
+                        .and_then(|visibility| visibility.utf8_text(code))
+                        .is_none_or(|visibility| visibility == "public")
+                })
+        };
+        if !is_public(node) {
+            return;
+        }
+        let mut parent = node.parent();
+        while let Some(ancestor) = parent {
+            if is_item(&ancestor)
+                && (ancestor.kind_id() == FunctionDeclaration || !is_public(&ancestor))
+            {
+                return;
+            }
+            parent = ancestor.parent();
+        }
+
+        let documented = has_doc_comment(
+            node,
+            code,
+            |node| matches!(node.kind_id().into(), BlockComment | LineComment),
+            |_| false,
+            is_javadoc,
+        );
+        stats.add_item::<Self>(node, code, documented);
+    }
+}
+
+implement_metric_trait!(Docs, PreprocCode, CcommentCode);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics;
+
+    use super::*;
+
+    #[test]
+    fn python_docstrings() {
+        check_metrics::<PythonParser>(
+            "class A:
+                 \"\"\"Documented class.\"\"\"
+                 def f(self):  # undocumented
+                     def g():  # nested, not public
+                         pass
+                 def _h(self):  # private
+                     pass
+             def k():
+                 '''Documented function.'''
+                 return 1",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.docs,
+                    @r###"
+                    {
+                      "public": 3.0,
+                      "documented": 2.0,
+                      "undocumented": 1.0,
+                      "coverage": 0.6666666666666666,
+                      "undocumented_items": [
+                        {
+                          "name": "f",
+                          "start_line": 3
+                        }
+                      ]
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_doc_comments() {
+        check_metrics::<RustParser>(
+            "/// Documented.
+             #[derive(Debug)]
+             pub struct A;
+             pub(crate) struct B;
+             #[doc = \"Documented.\"]
+             pub enum C {}
+             pub mod m {
+                 //! Documented.
+             }
+             // Not a doc comment.
+             pub fn f() {}
+             fn g() {}
+             pub trait T {
+                 fn t(&self);
+             }",
+            "foo.rs",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.docs,
+                    @r###"
+                    {
+                      "public": 6.0,
+                      "documented": 3.0,
+                      "undocumented": 3.0,
+                      "coverage": 0.5,
+                      "undocumented_items": [
+                        {
+                          "name": "f",
+                          "start_line": 11
+                        },
+                        {
+                          "name": "T",
+                          "start_line": 13
+                        },
+                        {
+                          "name": "t",
+                          "start_line": 14
+                        }
+                      ]
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_jsdoc() {
+        check_metrics::<TypescriptParser>(
+            "/** Documented. */
+             export function f() {}
+             function g() {}
+             export class A {
+                 /** Documented. */
+                 m() {}
+                 private n() {}
+                 /* Not a doc comment. */
+                 o() {}
+             }",
+            "foo.ts",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.docs,
+                    @r###"
+                    {
+                      "public": 4.0,
+                      "documented": 2.0,
+                      "undocumented": 2.0,
+                      "coverage": 0.5,
+                      "undocumented_items": [
+                        {
+                          "name": "A",
+                          "start_line": 4
+                        },
+                        {
+                          "name": "o",
+                          "start_line": 9
+                        }
+                      ]
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_javadoc() {
+        check_metrics::<JavaParser>(
+            "/** Documented. */
+             @Deprecated
+             public class A {
+                 /** Documented. */
+                 public void f() {}
+                 public void g() {}
+                 private void h() {}
+             }
+             interface I {
+                 void i();
+             }",
+            "foo.java",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.docs,
+                    @r###"
+                    {
+                      "public": 4.0,
+                      "documented": 2.0,
+                      "undocumented": 2.0,
+                      "coverage": 0.5,
+                      "undocumented_items": [
+                        {
+                          "name": "g",
+                          "start_line": 6
+                        },
+                        {
+                          "name": "i",
+                          "start_line": 10
+                        }
+                      ]
+                    }"###
+                );
+            },
+        );
+    }
+}
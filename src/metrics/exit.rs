@@ -3,6 +3,7 @@ use serde::Serialize;
 use std::fmt;
 
 use crate::checker::Checker;
+use crate::distribution::Distribution;
 use crate::macros::implement_metric_trait;
 use crate::*;
 
@@ -17,6 +18,7 @@ pub struct Stats {
     total_space_functions: usize,
     exit_min: usize,
     exit_max: usize,
+    distribution: Distribution,
 }
 
 impl Default for Stats {
@@ -27,6 +29,7 @@ impl Default for Stats {
             total_space_functions: 1,
             exit_min: usize::MAX,
             exit_max: 0,
+            distribution: Distribution::default(),
         }
     }
 }
@@ -36,11 +39,16 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut st = serializer.serialize_struct("nexits", 4)?;
+        let mut st = serializer.serialize_struct("nexits", 9)?;
         st.serialize_field("sum", &self.exit_sum())?;
         st.serialize_field("average", &self.exit_average())?;
         st.serialize_field("min", &self.exit_min())?;
         st.serialize_field("max", &self.exit_max())?;
+        st.serialize_field("median", &self.exit_distribution().median())?;
+        st.serialize_field("p90", &self.exit_distribution().p90())?;
+        st.serialize_field("p95", &self.exit_distribution().p95())?;
+        st.serialize_field("stddev", &self.exit_distribution().stddev())?;
+        st.serialize_field("histogram", &self.exit_distribution().histogram())?;
         st.end()
     }
 }
@@ -64,6 +72,7 @@ impl Stats {
         self.exit_max = self.exit_max.max(other.exit_max);
         self.exit_min = self.exit_min.min(other.exit_min);
         self.exit_sum += other.exit_sum;
+        self.distribution.merge(&other.distribution);
     }
 
     /// Returns the `NExit` metric value
@@ -96,12 +105,27 @@ impl Stats {
     pub(crate) fn compute_sum(&mut self) {
         self.exit_sum += self.exit;
     }
+
+    /// Returns the distribution of the `NExit` metric values
+    /// of the functions in a space
+    #[inline(always)]
+    pub fn exit_distribution(&self) -> &Distribution {
+        &self.distribution
+    }
+
     #[inline(always)]
     pub(crate) fn compute_minmax(&mut self) {
         self.exit_max = self.exit_max.max(self.exit);
         self.exit_min = self.exit_min.min(self.exit);
         self.compute_sum();
     }
+
+    // Adds the `NExit` value of a function to the distribution
+    #[inline(always)]
+    pub(crate) fn compute_distribution(&mut self) {
+        self.distribution.add(self.exit as f64);
+    }
+
     pub(crate) fn finalize(&mut self, total_space_functions: usize) {
         self.total_space_functions = total_space_functions;
     }
@@ -166,9 +190,20 @@ impl Exit for RustCode {
     }
 }
 
+impl Exit for CCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if node.kind_id() == C::ReturnStatement {
+            stats.exit += 1;
+        }
+    }
+}
+
 impl Exit for CppCode {
     fn compute(node: &Node, stats: &mut Stats) {
-        if matches!(node.kind_id().into(), Cpp::ReturnStatement) {
+        if matches!(
+            node.kind_id().into(),
+            Cpp::ReturnStatement | Cpp::CoReturnStatement
+        ) {
             stats.exit += 1;
         }
     }
@@ -176,6 +211,7 @@ impl Exit for CppCode {
 
 impl Exit for JavaCode {
     fn compute(node: &Node, stats: &mut Stats) {
+        // `yield` exits a switch expression, not a method
         if matches!(node.kind_id().into(), Java::ReturnStatement) {
             stats.exit += 1;
         }
@@ -201,7 +237,12 @@ mod tests {
                       "sum": 0.0,
                       "average": null,
                       "min": 0.0,
-                      "max": 0.0
+                      "max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -218,7 +259,12 @@ mod tests {
                       "sum": 0.0,
                       "average": null,
                       "min": 0.0,
-                      "max": 0.0
+                      "max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -235,7 +281,12 @@ mod tests {
                       "sum": 3.0,
                       "average": null,
                       "min": 3.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -252,7 +303,12 @@ mod tests {
                       "sum": 0.0,
                       "average": null,
                       "min": 0.0,
-                      "max": 0.0
+                      "max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -269,7 +325,12 @@ mod tests {
                       "sum": 0.0,
                       "average": null,
                       "min": 0.0,
-                      "max": 0.0
+                      "max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -292,7 +353,14 @@ mod tests {
                       "sum": 1.0,
                       "average": 1.0,
                       "min": 0.0,
-                      "max": 1.0
+                      "max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
                     }"###
                 );
             },
@@ -318,7 +386,14 @@ mod tests {
                       "sum": 2.0,
                       "average": 1.0,
                       "min": 0.0,
-                      "max": 1.0
+                      "max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 2
+                      }
                     }"###
                 );
             },
@@ -344,7 +419,15 @@ mod tests {
                       "sum": 2.0,
                       "average": 0.5,
                       "min": 0.0,
-                      "max": 1.0
+                      "max": 1.0,
+                      "median": 0.5,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.5,
+                      "histogram": {
+                        "0-1": 2,
+                        "1-2": 2
+                      }
                     }"###
                 );
             },
@@ -362,7 +445,12 @@ mod tests {
                       "sum": 0.0,
                       "average": null,
                       "min": 0.0,
-                      "max": 0.0
+                      "max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -386,7 +474,14 @@ mod tests {
                       "sum": 1.0,
                       "average": 1.0,
                       "min": 0.0,
-                      "max": 1.0
+                      "max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
                     }"###
                 );
             },
@@ -414,7 +509,88 @@ mod tests {
                       "sum": 2.0,
                       "average": 2.0,
                       "min": 0.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_yield() {
+        check_metrics::<JavaParser>(
+            "class A {
+              public int f(int x) {
+                int y = switch (x) {
+                    case 0 -> {
+                        yield 1;
+                    }
+                    default -> {
+                        yield x;
+                    }
+                };
+                return y;
+              }
+            }",
+            "foo.java",
+            |metric| {
+                // `yield` exits a switch expression, not a method
+                insta::assert_json_snapshot!(
+                    metric.nexits,
+                    @r###"
+                    {
+                      "sum": 1.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_coroutine() {
+        check_metrics::<CppParser>(
+            "task<int> f(int x) {
+                if (x) {
+                    co_return 0; // +1
+                }
+                co_yield x;
+                co_return x; // +1
+            }",
+            "foo.cpp",
+            |metric| {
+                // `co_yield` suspends a coroutine without exiting it
+                insta::assert_json_snapshot!(
+                    metric.nexits,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -316,6 +316,12 @@ impl Halstead for RustCode {
     }
 }
 
+impl Halstead for CCode {
+    fn compute<'a>(node: &Node<'a>, code: &'a [u8], halstead_maps: &mut HalsteadMaps<'a>) {
+        compute_halstead::<Self>(node, code, halstead_maps);
+    }
+}
+
 impl Halstead for CppCode {
     fn compute<'a>(node: &Node<'a>, code: &'a [u8], halstead_maps: &mut HalsteadMaps<'a>) {
         compute_halstead::<Self>(node, code, halstead_maps);
@@ -419,6 +425,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cpp_coroutine_operators() {
+        check_metrics::<CppParser>(
+            "task<int> f() {
+                int a = co_await g();
+                co_yield a;
+                co_return a;
+            }",
+            "foo.cpp",
+            |metric| {
+                // unique operators: <, >, (), {}, int, =, co_await, co_yield, co_return, ;
+                // unique operands: task, f, a, g
+                insta::assert_json_snapshot!(
+                    metric.halstead,
+                    @r###"
+                    {
+                      "n1": 10.0,
+                      "N1": 14.0,
+                      "n2": 4.0,
+                      "N2": 6.0,
+                      "length": 20.0,
+                      "estimated_program_length": 41.219280948873624,
+                      "purity_ratio": 2.0609640474436812,
+                      "vocabulary": 14.0,
+                      "volume": 76.14709844115208,
+                      "difficulty": 7.5,
+                      "level": 0.13333333333333333,
+                      "effort": 571.1032383086406,
+                      "time": 31.727957683813365,
+                      "bugs": 0.02294502281013948
+                    }"###
+                );
+            },
+        );
+    }
+
     #[test]
     fn rust_operators_and_operands() {
         check_metrics::<RustParser>(
@@ -698,4 +740,47 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn java_switch_expression_and_text_block() {
+        check_metrics::<JavaParser>(
+            "class A {
+                String f(Object o) {
+                    String t = \"\"\"
+                        a text block
+                        \"\"\";
+                    return switch (o) {
+                        case Integer i when i > 0 -> t;
+                        default -> {
+                            yield \"\";
+                        }
+                    };
+                }
+            }",
+            "foo.java",
+            |metric| {
+                // `->`, `when` and `yield` are operators, a text block is an operand
+                insta::assert_json_snapshot!(
+                    metric.halstead,
+                    @r###"
+                    {
+                      "n1": 12.0,
+                      "N1": 20.0,
+                      "n2": 8.0,
+                      "N2": 11.0,
+                      "length": 31.0,
+                      "estimated_program_length": 67.01955000865388,
+                      "purity_ratio": 2.161920968021093,
+                      "vocabulary": 20.0,
+                      "volume": 133.97977094150824,
+                      "difficulty": 8.25,
+                      "level": 0.12121212121212122,
+                      "effort": 1105.3331102674429,
+                      "time": 61.40739501485794,
+                      "bugs": 0.03563478954851777
+                    }"###
+                );
+            },
+        );
+    }
 }
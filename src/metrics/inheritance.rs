@@ -0,0 +1,134 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::fmt;
+
+/// The `Inheritance` metrics.
+///
+/// This metric suite contains two object-oriented metrics for classes:
+/// - `Dit` (Depth of Inheritance Tree): the length of the longest path
+///   from a class to the root of its inheritance hierarchy
+/// - `Noc` (Number of Children): the number of classes directly derived
+///   from a class
+///
+/// These metrics need the class declarations of a whole project,
+/// so they are computed by a [`Hierarchy`] rather than while
+/// traversing a single file.
+///
+/// Original paper and definition:
+/// <https://www.researchgate.net/publication/3187649_Kemerer_CF_A_metric_suite_for_object_oriented_design_IEEE_Trans_Softw_Eng_206_476-493>
+///
+/// [`Hierarchy`]: crate::Hierarchy
+#[derive(Clone, Debug, Default)]
+pub struct Stats {
+    dit_sum: usize,
+    dit_max: usize,
+    noc_sum: usize,
+    noc_max: usize,
+    classes_sum: usize,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("inheritance", 7)?;
+        st.serialize_field("classes", &self.classes_sum())?;
+        st.serialize_field("dit_sum", &self.dit_sum())?;
+        st.serialize_field("dit_average", &self.dit_average())?;
+        st.serialize_field("dit_max", &self.dit_max())?;
+        st.serialize_field("noc_sum", &self.noc_sum())?;
+        st.serialize_field("noc_average", &self.noc_average())?;
+        st.serialize_field("noc_max", &self.noc_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "classes: {}, dit_sum: {}, dit_average: {}, dit_max: {}, noc_sum: {}, noc_average: {}, noc_max: {}",
+            self.classes_sum(),
+            self.dit_sum(),
+            self.dit_average(),
+            self.dit_max(),
+            self.noc_sum(),
+            self.noc_average(),
+            self.noc_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Inheritance` metric suite into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.dit_sum += other.dit_sum;
+        self.dit_max = self.dit_max.max(other.dit_max);
+        self.noc_sum += other.noc_sum;
+        self.noc_max = self.noc_max.max(other.noc_max);
+        self.classes_sum += other.classes_sum;
+    }
+
+    /// Returns the number of classes in a space.
+    #[inline(always)]
+    pub fn classes_sum(&self) -> f64 {
+        self.classes_sum as f64
+    }
+
+    /// Returns the sum of the `Dit` values of the classes in a space.
+    ///
+    /// For a class space, this is the `Dit` value of the class
+    /// plus the ones of its nested classes.
+    #[inline(always)]
+    pub fn dit_sum(&self) -> f64 {
+        self.dit_sum as f64
+    }
+
+    /// Returns the `Dit` average value of the classes in a space.
+    #[inline(always)]
+    pub fn dit_average(&self) -> f64 {
+        self.dit_sum() / self.classes_sum()
+    }
+
+    /// Returns the `Dit` maximum value of the classes in a space.
+    #[inline(always)]
+    pub fn dit_max(&self) -> f64 {
+        self.dit_max as f64
+    }
+
+    /// Returns the sum of the `Noc` values of the classes in a space.
+    #[inline(always)]
+    pub fn noc_sum(&self) -> f64 {
+        self.noc_sum as f64
+    }
+
+    /// Returns the `Noc` average value of the classes in a space.
+    #[inline(always)]
+    pub fn noc_average(&self) -> f64 {
+        self.noc_sum() / self.classes_sum()
+    }
+
+    /// Returns the `Noc` maximum value of the classes in a space.
+    #[inline(always)]
+    pub fn noc_max(&self) -> f64 {
+        self.noc_max as f64
+    }
+
+    // Checks if the `Inheritance` metrics are disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.classes_sum == 0
+    }
+
+    // Creates the `Inheritance` metrics of a single class
+    pub(crate) fn new_class(dit: usize, noc: usize) -> Self {
+        Self {
+            dit_sum: dit,
+            dit_max: dit,
+            noc_sum: noc,
+            noc_max: noc,
+            classes_sum: 1,
+        }
+    }
+}
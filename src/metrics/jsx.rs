@@ -0,0 +1,362 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::getter::{has_descendant, Getter};
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Jsx` metric.
+///
+/// This metric measures the complexity of the `JSX` code of a space,
+/// where the rendering logic of a `React` component lives.
+///
+/// It counts the `JSX` elements of a space and measures their maximum depth,
+/// it counts the conditional renderings, the inline event handlers and,
+/// for a function component, the number of its props.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    elements: usize,
+    depth: usize,
+    branches: usize,
+    handlers: usize,
+    props: usize,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("jsx", 5)?;
+        st.serialize_field("elements", &self.elements())?;
+        st.serialize_field("depth", &self.depth())?;
+        st.serialize_field("branches", &self.branches())?;
+        st.serialize_field("handlers", &self.handlers())?;
+        st.serialize_field("props", &self.props())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "elements: {}, depth: {}, branches: {}, handlers: {}, props: {}",
+            self.elements(),
+            self.depth(),
+            self.branches(),
+            self.handlers(),
+            self.props()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Jsx` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.elements += other.elements;
+        self.depth = self.depth.max(other.depth);
+        self.branches += other.branches;
+        self.handlers += other.handlers;
+        self.props += other.props;
+    }
+
+    /// Returns the number of `JSX` elements
+    pub fn elements(&self) -> f64 {
+        self.elements as f64
+    }
+
+    /// Returns the maximum depth of the nested `JSX` elements
+    pub fn depth(&self) -> f64 {
+        self.depth as f64
+    }
+
+    /// Returns the number of conditional renderings
+    ///
+    /// A conditional rendering is an element rendered by `&&`, `||`, `??`
+    /// or by a ternary expression, such as `cond && <A />`, or a list of
+    /// elements rendered by the callback of `map`.
+    pub fn branches(&self) -> f64 {
+        self.branches as f64
+    }
+
+    /// Returns the number of inline event handlers,
+    /// such as `<button onClick={() => f()} />`
+    pub fn handlers(&self) -> f64 {
+        self.handlers as f64
+    }
+
+    /// Returns the number of props of the function components
+    pub fn props(&self) -> f64 {
+        self.props as f64
+    }
+
+    // Checks if the `Jsx` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.elements == 0
+    }
+}
+
+pub trait Jsx
+where
+    Self: Checker + Getter,
+{
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+// Computes the depth of a `JSX` element counting the elements containing it,
+// until the beginning of the function containing it.
+// The elements returned by a closure are nested in the elements containing it.
+fn compute_depth<T: Checker>(node: &Node, stats: &mut Stats, is_element: fn(u16) -> bool) {
+    let mut depth = 1;
+    let mut parent = node.parent();
+    while let Some(node) = parent {
+        if (T::is_func(&node) || T::is_func_space(&node)) && !T::is_closure(&node) {
+            break;
+        }
+        if is_element(node.kind_id()) {
+            depth += 1;
+        }
+        parent = node.parent();
+    }
+    stats.depth = stats.depth.max(depth);
+}
+
+// Counts the props of a function component: the properties of its first
+// parameter when it is destructured, `{ a, b }`, or the distinct properties
+// read from it otherwise, `props.a` and `props.b`.
+fn count_props(
+    node: &Node,
+    code: &[u8],
+    props: Option<Node>,
+    is_object_pattern: fn(u16) -> bool,
+    is_identifier: fn(u16) -> bool,
+    is_member_expression: fn(u16) -> bool,
+) -> usize {
+    let Some(props) = props else {
+        return 0;
+    };
+    if is_object_pattern(props.kind_id()) {
+        return props.children().filter(|child| child.is_named()).count();
+    }
+    if !is_identifier(props.kind_id()) {
+        return 0;
+    }
+    let text = |node: &Node| std::str::from_utf8(&code[node.start_byte()..node.end_byte()]).ok();
+    let name = text(&props);
+    let mut properties = HashSet::new();
+    let mut stack = vec![*node];
+    while let Some(node) = stack.pop() {
+        if is_member_expression(node.kind_id())
+            && node
+                .child_by_field_name("object")
+                .is_some_and(|object| is_identifier(object.kind_id()) && text(&object) == name)
+        {
+            if let Some(property) = node.child_by_field_name("property") {
+                properties.insert(text(&property));
+            }
+        }
+        stack.extend(node.children());
+    }
+    properties.len()
+}
+
+macro_rules! js_jsx {
+    ($code:ident, $language:ident, $member:ident $(, $required:ident, $optional:ident)?) => {
+        impl Jsx for $code {
+            fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+                use $language::*;
+
+                fn is_element(id: u16) -> bool {
+                    matches!(id.into(), JsxElement | JsxSelfClosingElement)
+                }
+
+                fn is_function(id: u16) -> bool {
+                    matches!(id.into(), ArrowFunction | FunctionExpression)
+                }
+
+                // Checks whether an expression is an element, `<A />` or `(<A />)`
+                fn is_rendered(node: Option<Node>) -> bool {
+                    let mut node = node;
+                    while let Some(expr) = node.filter(|expr| expr.kind_id() == ParenthesizedExpression) {
+                        node = expr.children().find(|child| child.is_named());
+                    }
+                    node.is_some_and(|node| is_element(node.kind_id()))
+                }
+
+                match node.kind_id().into() {
+                    JsxElement | JsxSelfClosingElement => {
+                        stats.elements += 1;
+                        compute_depth::<Self>(node, stats, is_element);
+                    }
+                    // `cond && <A />`
+                    BinaryExpression
+                        if node.child_by_field_name("operator").is_some_and(|operator| {
+                            matches!(operator.kind_id().into(), AMPAMP | PIPEPIPE | QMARKQMARK)
+                        }) && is_rendered(node.child_by_field_name("right")) =>
+                    {
+                        stats.branches += 1;
+                    }
+                    // `cond ? <A /> : <B />`
+                    TernaryExpression
+                        if is_rendered(node.child_by_field_name("consequence"))
+                            || is_rendered(node.child_by_field_name("alternative")) =>
+                    {
+                        stats.branches += 1;
+                    }
+                    // `items.map(item => <A />)`
+                    CallExpression
+                        if node
+                            .child_by_field_name("function")
+                            .filter(|function| function.kind_id() == $member)
+                            .and_then(|function| function.child_by_field_name("property"))
+                            .is_some_and(|property| &code[property.start_byte()..property.end_byte()] == b"map")
+                            && node
+                                .child_by_field_name("arguments")
+                                .is_some_and(|arguments| {
+                                    arguments.children().any(|argument| {
+                                        is_function(argument.kind_id())
+                                            && has_descendant(&argument, is_element, is_function)
+                                    })
+                                }) =>
+                    {
+                        stats.branches += 1;
+                    }
+                    // `<button onClick={() => f()} />`
+                    ArrowFunction | FunctionExpression
+                        if node
+                            .parent()
+                            .filter(|parent| parent.kind_id() == JsxExpression)
+                            .and_then(|parent| parent.parent())
+                            .is_some_and(|parent| parent.kind_id() == JsxAttribute) =>
+                    {
+                        stats.handlers += 1;
+                    }
+                    _ => {}
+                }
+
+                if Self::get_space_kind(node, code) == SpaceKind::Component {
+                    let props = node.child_by_field_name("parameter").or_else(|| {
+                        node.child_by_field_name("parameters")
+                            .and_then(|parameters| parameters.children().find(|child| child.is_named()))
+                    });
+                    $(
+                        // `props: Props`
+                        let props = props.and_then(|props| match props.kind_id().into() {
+                            $required | $optional => props.child_by_field_name("pattern"),
+                            _ => Some(props),
+                        });
+                    )?
+                    // `{ a, b } = {}`
+                    let props = props.map(|props| match props.kind_id().into() {
+                        AssignmentPattern => props.child_by_field_name("left").unwrap_or(props),
+                        _ => props,
+                    });
+                    stats.props += count_props(
+                        node,
+                        code,
+                        props,
+                        |id| id == ObjectPattern,
+                        |id| id == Identifier,
+                        |id| id == $member,
+                    );
+                }
+            }
+        }
+    };
+}
+
+js_jsx!(MozjsCode, Mozjs, MemberExpression2);
+js_jsx!(JavascriptCode, Javascript, MemberExpression2);
+js_jsx!(
+    TsxCode,
+    Tsx,
+    MemberExpression,
+    RequiredParameter,
+    OptionalParameter
+);
+
+implement_metric_trait!(
+    Jsx,
+    PythonCode,
+    TypescriptCode,
+    RustCode,
+    CCode,
+    CppCode,
+    JavaCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics;
+
+    use super::*;
+
+    #[test]
+    fn tsx_component() {
+        check_metrics::<TsxParser>(
+            "function List({ items, selected, onSelect }: Props) { // 3 props
+                return (
+                    <ul> {/* depth = 1 */}
+                        {items.length == 0 && <li>empty</li>} {/* +1 branch */}
+                        {items.map(item => ( /* +1 branch */
+                            <li key={item} onClick={() => onSelect(item)}> {/* +1 handler */}
+                                {item == selected ? <b>{item}</b> : item} {/* +1 branch, depth = 3 */}
+                            </li>
+                        ))}
+                    </ul>
+                );
+            }",
+            "foo.tsx",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.jsx,
+                    @r###"
+                    {
+                      "elements": 4.0,
+                      "depth": 3.0,
+                      "branches": 3.0,
+                      "handlers": 1.0,
+                      "props": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_component_props() {
+        check_metrics::<JavascriptParser>(
+            "const Greeting = (props) => { // 2 props
+                if (!props.name) {
+                    return null;
+                }
+                return <p title={props.title}>Hello {props.name}</p>;
+            };
+            function greet(name) {
+                return <Greeting name={name} />;
+            }",
+            "foo.js",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.jsx,
+                    @r###"
+                    {
+                      "elements": 2.0,
+                      "depth": 1.0,
+                      "branches": 0.0,
+                      "handlers": 0.0,
+                      "props": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,703 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Lcom` metric.
+///
+/// This metric measures the lack of cohesion of the methods of a class.
+/// Given the sets of fields used by each method, it is computed as the
+/// number of method pairs sharing no field minus the number of
+/// method pairs sharing at least one field, or zero when this difference
+/// is negative.
+/// The `Lcom` (Lack of Cohesion in Methods) is an object-oriented metric for classes.
+///
+/// Original paper and definition:
+/// <https://www.researchgate.net/publication/3187649_Kemerer_CF_A_metric_suite_for_object_oriented_design_IEEE_Trans_Softw_Eng_206_476-493>
+#[derive(Clone, Debug)]
+pub struct Stats {
+    lcom: usize,
+    lcom_sum: usize,
+    lcom_min: usize,
+    lcom_max: usize,
+    classes: usize,
+    classes_sum: usize,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            lcom: 0,
+            lcom_sum: 0,
+            lcom_min: usize::MAX,
+            lcom_max: 0,
+            classes: 0,
+            classes_sum: 0,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("lcom", 5)?;
+        st.serialize_field("classes", &self.classes_sum())?;
+        st.serialize_field("sum", &self.lcom_sum())?;
+        st.serialize_field("average", &self.lcom_average())?;
+        st.serialize_field("min", &self.lcom_min())?;
+        st.serialize_field("max", &self.lcom_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "classes: {}, sum: {}, average: {}, min: {}, max: {}",
+            self.classes_sum(),
+            self.lcom_sum(),
+            self.lcom_average(),
+            self.lcom_min(),
+            self.lcom_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Lcom` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.lcom_min = self.lcom_min.min(other.lcom_min);
+        self.lcom_max = self.lcom_max.max(other.lcom_max);
+        self.lcom_sum += other.lcom_sum;
+        self.classes_sum += other.classes_sum;
+    }
+
+    /// Returns the `Lcom` metric value of the class of a space.
+    #[inline(always)]
+    pub fn lcom(&self) -> f64 {
+        self.lcom as f64
+    }
+
+    /// Returns the sum of the `Lcom` metric values of the classes in a space.
+    #[inline(always)]
+    pub fn lcom_sum(&self) -> f64 {
+        self.lcom_sum as f64
+    }
+
+    /// Returns the number of classes in a space.
+    #[inline(always)]
+    pub fn classes_sum(&self) -> f64 {
+        self.classes_sum as f64
+    }
+
+    /// Returns the `Lcom` metric average value
+    ///
+    /// This value is computed dividing the `Lcom` sum
+    /// for the number of classes in a space.
+    #[inline(always)]
+    pub fn lcom_average(&self) -> f64 {
+        self.lcom_sum() / self.classes_sum()
+    }
+
+    /// Returns the `Lcom` minimum value of the classes in a space.
+    #[inline(always)]
+    pub fn lcom_min(&self) -> f64 {
+        self.lcom_min as f64
+    }
+
+    /// Returns the `Lcom` maximum value of the classes in a space.
+    #[inline(always)]
+    pub fn lcom_max(&self) -> f64 {
+        self.lcom_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.lcom_sum += self.lcom;
+        self.classes_sum += self.classes;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        if self.classes > 0 {
+            self.lcom_min = self.lcom_min.min(self.lcom);
+            self.lcom_max = self.lcom_max.max(self.lcom);
+        }
+        self.compute_sum();
+    }
+
+    // Checks if the `Lcom` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.classes_sum == 0
+    }
+
+    // Sets the `Lcom` value of a class given
+    // the set of fields used by each of its methods
+    fn set_methods_fields(&mut self, methods: &[HashSet<&str>]) {
+        let mut disjoint: usize = 0;
+        let mut shared = 0;
+        for (i, fields) in methods.iter().enumerate() {
+            for other in &methods[i + 1..] {
+                if fields.is_disjoint(other) {
+                    disjoint += 1;
+                } else {
+                    shared += 1;
+                }
+            }
+        }
+        self.lcom = disjoint.saturating_sub(shared);
+        self.classes = 1;
+    }
+}
+
+pub trait Lcom
+where
+    Self: Checker,
+{
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+impl Lcom for JavaCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Java::*;
+
+        fn used_fields<'a>(
+            method: &Node,
+            code: &'a [u8],
+            fields: &HashSet<&'a str>,
+        ) -> HashSet<&'a str> {
+            // A local variable or a parameter shadows the field with the same name
+            let mut locals = HashSet::new();
+            method.act_on_node_pruned(&mut |node| match node.kind_id().into() {
+                ClassDeclaration | InterfaceDeclaration => false,
+                VariableDeclarator | FormalParameter | CatchFormalParameter
+                | EnhancedForStatement => {
+                    if let Some(name) = node.child_by_field_name("name") {
+                        locals.insert(name.utf8_text(code).unwrap_or_default());
+                    }
+                    true
+                }
+                Identifier => {
+                    if node.parent().is_some_and(|parent| {
+                        matches!(
+                            parent.kind_id().into(),
+                            LambdaExpression | InferredParameters
+                        )
+                    }) {
+                        locals.insert(node.utf8_text(code).unwrap_or_default());
+                    }
+                    true
+                }
+                _ => true,
+            });
+
+            let mut used = HashSet::new();
+            method.act_on_node_pruned(&mut |node| match node.kind_id().into() {
+                ClassDeclaration | InterfaceDeclaration => false,
+                Identifier => {
+                    let name = node.utf8_text(code).unwrap_or_default();
+                    let is_field = match node.parent() {
+                        Some(parent) if parent.kind_id() == FieldAccess => {
+                            if parent
+                                .child_by_field_name("field")
+                                .is_some_and(|field| field.id() == node.id())
+                            {
+                                // `this.field`
+                                parent
+                                    .child_by_field_name("object")
+                                    .is_some_and(|object| object.kind_id() == This)
+                            } else {
+                                !locals.contains(name)
+                            }
+                        }
+                        Some(parent)
+                            if matches!(
+                                parent.kind_id().into(),
+                                MethodInvocation | MethodDeclaration | ConstructorDeclaration
+                            ) && parent
+                                .child_by_field_name("name")
+                                .is_some_and(|method| method.id() == node.id()) =>
+                        {
+                            false
+                        }
+                        _ => !locals.contains(name),
+                    };
+                    if is_field && fields.contains(name) {
+                        used.insert(name);
+                    }
+                    false
+                }
+                _ => true,
+            });
+            used
+        }
+
+        if node.kind_id() != ClassDeclaration {
+            return;
+        }
+
+        if let Some(body) = node.child_by_field_name("body") {
+            let fields = body
+                .children()
+                .filter(|node| node.kind_id() == FieldDeclaration)
+                .flat_map(|node| node.children())
+                .filter(|node| node.kind_id() == VariableDeclarator)
+                .filter_map(|node| node.child_by_field_name("name"))
+                .filter_map(|name| name.utf8_text(code))
+                .collect::<HashSet<_>>();
+            let methods = body
+                .children()
+                .filter(|node| Self::is_func(node))
+                .map(|method| used_fields(&method, code, &fields))
+                .collect::<Vec<_>>();
+            stats.set_methods_fields(&methods);
+        }
+    }
+}
+
+macro_rules! ts_lcom {
+    ($lang:ident) => {
+        fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+            use $lang::*;
+
+            if !matches!(
+                node.kind_id().into(),
+                Class | ClassDeclaration | AbstractClassDeclaration
+            ) {
+                return;
+            }
+
+            if let Some(body) = node.child_by_field_name("body") {
+                let mut fields = body
+                    .children()
+                    .filter(|node| node.kind_id() == PublicFieldDefinition)
+                    .filter_map(|node| node.child_by_field_name("name"))
+                    .filter_map(|name| name.utf8_text(code))
+                    .collect::<HashSet<_>>();
+                // Parameter properties: `constructor(private x: number) {}`
+                body.children()
+                    .filter(|node| node.kind_id() == MethodDefinition)
+                    .filter_map(|node| node.child_by_field_name("parameters"))
+                    .flat_map(|node| node.children())
+                    .filter(|node| node.is_child(AccessibilityModifier as u16))
+                    .filter_map(|node| node.child_by_field_name("pattern"))
+                    .for_each(|name| {
+                        fields.insert(name.utf8_text(code).unwrap_or_default());
+                    });
+
+                let methods = body
+                    .children()
+                    .filter(|node| node.kind_id() == MethodDefinition)
+                    .map(|method| {
+                        let mut used = HashSet::new();
+                        method.act_on_node_pruned(&mut |node| match node.kind_id().into() {
+                            Class | ClassDeclaration | AbstractClassDeclaration => false,
+                            // `this.field`
+                            MemberExpression => {
+                                if node
+                                    .child_by_field_name("object")
+                                    .is_some_and(|object| object.kind_id() == This)
+                                {
+                                    if let Some(property) = node.child_by_field_name("property") {
+                                        let name = property.utf8_text(code).unwrap_or_default();
+                                        if fields.contains(name) {
+                                            used.insert(name);
+                                        }
+                                    }
+                                }
+                                true
+                            }
+                            _ => true,
+                        });
+                        used
+                    })
+                    .collect::<Vec<_>>();
+                stats.set_methods_fields(&methods);
+            }
+        }
+    };
+}
+
+impl Lcom for TypescriptCode {
+    ts_lcom!(Typescript);
+}
+
+impl Lcom for TsxCode {
+    ts_lcom!(Tsx);
+}
+
+impl Lcom for PythonCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Python::{
+            Assignment, Attribute, AugmentedAssignment, ClassDefinition, DecoratedDefinition,
+            Decorator, ExpressionStatement, FunctionDefinition, Identifier,
+        };
+
+        // Returns the name of the first parameter of a method, `self` or `cls`,
+        // which is missing for static methods
+        fn receiver<'a>(method: &Node, code: &'a [u8]) -> Option<&'a str> {
+            let is_static = method.parent().is_some_and(|parent| {
+                parent.kind_id() == DecoratedDefinition
+                    && parent.children().any(|decorator| {
+                        decorator.kind_id() == Decorator
+                            && decorator
+                                .utf8_text(code)
+                                .is_some_and(|text| text.trim_end() == "@staticmethod")
+                    })
+            });
+            if is_static {
+                return None;
+            }
+            method
+                .child_by_field_name("parameters")?
+                .first_child(|id| id == Identifier)
+                .and_then(|name| name.utf8_text(code))
+        }
+
+        // Returns the attribute name in `self.attribute`
+        fn receiver_attribute<'a>(node: &Node, code: &'a [u8], receiver: &str) -> Option<&'a str> {
+            if node.kind_id() != Attribute {
+                return None;
+            }
+            node.child_by_field_name("object")
+                .filter(|object| {
+                    object.kind_id() == Identifier && object.utf8_text(code) == Some(receiver)
+                })
+                .and(node.child_by_field_name("attribute"))
+                .and_then(|attribute| attribute.utf8_text(code))
+        }
+
+        if node.kind_id() != ClassDefinition {
+            return;
+        }
+
+        if let Some(body) = node.child_by_field_name("body") {
+            let methods = body
+                .children()
+                .filter_map(|node| match node.kind_id().into() {
+                    FunctionDefinition => Some(node),
+                    DecoratedDefinition => node.child_by_field_name("definition"),
+                    _ => None,
+                })
+                .filter(|node| node.kind_id() == FunctionDefinition)
+                .map(|method| (method, receiver(&method, code)))
+                .collect::<Vec<_>>();
+            let method_names = methods
+                .iter()
+                .filter_map(|(method, _)| method.child_by_field_name("name"))
+                .filter_map(|name| name.utf8_text(code))
+                .collect::<HashSet<_>>();
+
+            // Class attributes and instance attributes assigned in any method
+            let mut fields = body
+                .children()
+                .filter(|node| node.kind_id() == ExpressionStatement)
+                .flat_map(|node| node.children())
+                .filter(|node| node.kind_id() == Assignment)
+                .filter_map(|node| node.child_by_field_name("left"))
+                .filter(|node| node.kind_id() == Identifier)
+                .filter_map(|name| name.utf8_text(code))
+                .collect::<HashSet<_>>();
+            for (method, receiver) in methods.iter() {
+                if let Some(receiver) = receiver {
+                    method.act_on_node_pruned(&mut |node| match node.kind_id().into() {
+                        ClassDefinition => false,
+                        Assignment | AugmentedAssignment => {
+                            if let Some(name) = node
+                                .child_by_field_name("left")
+                                .and_then(|left| receiver_attribute(&left, code, receiver))
+                            {
+                                fields.insert(name);
+                            }
+                            true
+                        }
+                        _ => true,
+                    });
+                }
+            }
+            fields.retain(|name| !method_names.contains(name));
+
+            let methods = methods
+                .iter()
+                .map(|(method, receiver)| {
+                    let mut used = HashSet::new();
+                    if let Some(receiver) = receiver {
+                        method.act_on_node_pruned(&mut |node| {
+                            if node.kind_id() == ClassDefinition {
+                                return false;
+                            }
+                            if let Some(name) = receiver_attribute(node, code, receiver) {
+                                if fields.contains(name) {
+                                    used.insert(name);
+                                }
+                            }
+                            true
+                        });
+                    }
+                    used
+                })
+                .collect::<Vec<_>>();
+            stats.set_methods_fields(&methods);
+        }
+    }
+}
+
+impl Lcom for CppCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Cpp::*;
+
+        if !matches!(
+            node.kind_id().into(),
+            ClassSpecifier | StructSpecifier | UnionSpecifier
+        ) {
+            return;
+        }
+
+        if let Some(body) = node.child_by_field_name("body") {
+            // Data members are field declarations which do not declare a method
+            let mut fields = HashSet::new();
+            for declaration in body
+                .children()
+                .filter(|node| node.kind_id() == FieldDeclaration)
+            {
+                for declarator in declaration.children() {
+                    match declarator.kind_id().into() {
+                        FieldIdentifier => {
+                            fields.insert(declarator.utf8_text(code).unwrap_or_default());
+                        }
+                        PointerDeclarator | ReferenceDeclarator | ArrayDeclarator
+                            if declarator
+                                .first_occurrence(|id| {
+                                    matches!(
+                                        id.into(),
+                                        FunctionDeclarator
+                                            | FunctionDeclarator2
+                                            | FunctionDeclarator3
+                                    )
+                                })
+                                .is_none() =>
+                        {
+                            if let Some(name) =
+                                declarator.first_occurrence(|id| id == FieldIdentifier)
+                            {
+                                fields.insert(name.utf8_text(code).unwrap_or_default());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            let methods = body
+                .children()
+                .filter(|node| Self::is_func(node))
+                .map(|method| {
+                    // A local variable or a parameter shadows the field with the same name
+                    let mut locals = HashSet::new();
+                    let mut used = HashSet::new();
+                    method.act_on_node_pruned(&mut |node| match node.kind_id().into() {
+                        ClassSpecifier | StructSpecifier | UnionSpecifier => false,
+                        Identifier => {
+                            let name = node.utf8_text(code).unwrap_or_default();
+                            if node.parent().is_some_and(|parent| {
+                                matches!(
+                                    parent.kind_id().into(),
+                                    ParameterDeclaration
+                                        | OptionalParameterDeclaration
+                                        | InitDeclarator
+                                        | Declaration
+                                        | PointerDeclarator
+                                        | ReferenceDeclarator
+                                        | ArrayDeclarator
+                                )
+                            }) {
+                                locals.insert(name);
+                            } else if fields.contains(name) && !locals.contains(name) {
+                                used.insert(name);
+                            }
+                            false
+                        }
+                        // `this->field`
+                        FieldExpression => {
+                            if node
+                                .child_by_field_name("argument")
+                                .is_some_and(|argument| argument.kind_id() == This)
+                            {
+                                if let Some(field) = node.child_by_field_name("field") {
+                                    let name = field.utf8_text(code).unwrap_or_default();
+                                    if fields.contains(name) {
+                                        used.insert(name);
+                                    }
+                                }
+                            }
+                            true
+                        }
+                        _ => true,
+                    });
+                    used
+                })
+                .collect::<Vec<_>>();
+            stats.set_methods_fields(&methods);
+        }
+    }
+}
+
+implement_metric_trait!(
+    Lcom,
+    MozjsCode,
+    JavascriptCode,
+    RustCode,
+    CCode,
+    PreprocCode,
+    CcommentCode,
+    KotlinCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics;
+
+    use super::*;
+
+    #[test]
+    fn java_cohesive_class() {
+        check_metrics::<JavaParser>(
+            "class Counter {
+                private int count;
+                private int step;
+
+                public Counter(int step) { // count, step
+                    this.count = 0;
+                    this.step = step;
+                }
+                public void inc() {        // count, step
+                    count += step;
+                }
+                public int get() {         // count
+                    return count;
+                }
+            }",
+            "foo.java",
+            |metric| {
+                // P = 0, Q = 3
+                insta::assert_json_snapshot!(
+                    metric.lcom,
+                    @r###"
+                    {
+                      "classes": 1.0,
+                      "sum": 0.0,
+                      "average": 0.0,
+                      "min": 0.0,
+                      "max": 0.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_not_cohesive_class() {
+        check_metrics::<JavaParser>(
+            "class Mixed {
+                private int a;
+                private int b;
+                private String c;
+
+                void m1() { a++; }               // a
+                void m2(int b) { b = 2; a--; }   // a (b is shadowed)
+                void m3() { this.b = 1; }        // b
+                void m4() { print(c); }          // c
+                static void m5() {}              // -
+            }",
+            "foo.java",
+            |metric| {
+                // P = 9, Q = 1
+                insta::assert_json_snapshot!(
+                    metric.lcom,
+                    @r###"
+                    {
+                      "classes": 1.0,
+                      "sum": 8.0,
+                      "average": 8.0,
+                      "min": 8.0,
+                      "max": 8.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_class() {
+        check_metrics::<TypescriptParser>(
+            "class A {
+                private x: number = 0;
+                y: string;
+                constructor(private z: number) {}  // -
+                getX() { return this.x; }          // x
+                getY() { return this.y; }          // y
+                getZ() { return this.z + 1; }      // z
+            }",
+            "foo.ts",
+            |metric| {
+                // P = 6, Q = 0
+                insta::assert_json_snapshot!(
+                    metric.lcom,
+                    @r###"
+                    {
+                      "classes": 1.0,
+                      "sum": 6.0,
+                      "average": 6.0,
+                      "min": 6.0,
+                      "max": 6.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_class() {
+        check_metrics::<PythonParser>(
+            "class A:
+                z = 3
+
+                def __init__(self):          # x, y
+                    self.x = 1
+                    self.y = 2
+
+                def get_x(self):             # x
+                    return self.x + self.helper()
+
+                def helper(self):            # z
+                    return self.z
+
+                @staticmethod
+                def other(self):             # -
+                    return self.x",
+            "foo.py",
+            |metric| {
+                // P = 5, Q = 1
+                insta::assert_json_snapshot!(
+                    metric.lcom,
+                    @r###"
+                    {
+                      "classes": 1.0,
+                      "sum": 4.0,
+                      "average": 4.0,
+                      "min": 4.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+}
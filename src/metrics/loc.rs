@@ -5,6 +5,7 @@ use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::fmt;
 
+use crate::distribution::Distribution;
 use crate::macros::implement_metric_trait;
 use crate::*;
 
@@ -75,6 +76,9 @@ impl Sloc {
 #[derive(Debug, Clone)]
 pub struct Ploc {
     lines: HashSet<usize>,
+    // The code lines of the finalized spaces nested in a space, which are
+    // merged into it only when it is finalized
+    nested_lines: HashSet<usize>,
     ploc_min: usize,
     ploc_max: usize,
 }
@@ -83,6 +87,7 @@ impl Default for Ploc {
     fn default() -> Self {
         Self {
             lines: HashSet::default(),
+            nested_lines: HashSet::default(),
             ploc_min: usize::MAX,
             ploc_max: 0,
         }
@@ -120,6 +125,13 @@ impl Ploc {
         self.ploc_max = self.ploc_max.max(other.ploc() as usize);
     }
 
+    // Checks whether a line contains some code of a space
+    // or of a space nested in it
+    #[inline(always)]
+    fn is_code_line(&self, line: usize) -> bool {
+        self.lines.contains(&line) || self.nested_lines.contains(&line)
+    }
+
     #[inline(always)]
     pub(crate) fn compute_minmax(&mut self) {
         if self.ploc_min == usize::MAX {
@@ -255,6 +267,7 @@ pub struct Stats {
     space_count: usize,
     blank_min: usize,
     blank_max: usize,
+    sloc_distribution: Distribution,
 }
 
 impl Default for Stats {
@@ -267,6 +280,7 @@ impl Default for Stats {
             space_count: 1,
             blank_min: usize::MAX,
             blank_max: 0,
+            sloc_distribution: Distribution::default(),
         }
     }
 }
@@ -276,7 +290,7 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut st = serializer.serialize_struct("loc", 20)?;
+        let mut st = serializer.serialize_struct("loc", 25)?;
         st.serialize_field("sloc", &self.sloc())?;
         st.serialize_field("ploc", &self.ploc())?;
         st.serialize_field("lloc", &self.lloc())?;
@@ -297,6 +311,11 @@ impl Serialize for Stats {
         st.serialize_field("lloc_max", &self.lloc_max())?;
         st.serialize_field("blank_min", &self.blank_min())?;
         st.serialize_field("blank_max", &self.blank_max())?;
+        st.serialize_field("sloc_median", &self.sloc_distribution().median())?;
+        st.serialize_field("sloc_p90", &self.sloc_distribution().p90())?;
+        st.serialize_field("sloc_p95", &self.sloc_distribution().p95())?;
+        st.serialize_field("sloc_stddev", &self.sloc_distribution().stddev())?;
+        st.serialize_field("sloc_histogram", &self.sloc_distribution().histogram())?;
         st.end()
     }
 }
@@ -340,6 +359,7 @@ impl Stats {
 
         // Count spaces
         self.space_count += other.space_count;
+        self.sloc_distribution.merge(&other.sloc_distribution);
 
         // min and max
 
@@ -487,6 +507,13 @@ impl Stats {
         self.blank_max as f64
     }
 
+    /// Returns the distribution of the `Sloc` metric values
+    /// of the functions in a space
+    #[inline(always)]
+    pub fn sloc_distribution(&self) -> &Distribution {
+        &self.sloc_distribution
+    }
+
     #[inline(always)]
     pub(crate) fn compute_minmax(&mut self) {
         self.sloc.compute_minmax();
@@ -499,6 +526,19 @@ impl Stats {
             self.blank_max = self.blank_max.max(self.blank() as usize);
         }
     }
+
+    // Records the code lines of a finalized nested space, so that the comments
+    // following its code on the same line are not counted as comment lines
+    #[inline(always)]
+    pub(crate) fn add_nested_lines(&mut self, nested: &Stats) {
+        self.ploc.nested_lines.extend(&nested.ploc.lines);
+    }
+
+    // Adds the `Sloc` value of a function to the distribution
+    #[inline(always)]
+    pub(crate) fn compute_distribution(&mut self) {
+        self.sloc_distribution.add(self.sloc());
+    }
 }
 
 pub trait Loc
@@ -528,7 +568,7 @@ fn init(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool) -> (
 // a wrong count for the blank metric.
 fn add_cloc_lines(stats: &mut Stats, start: usize, end: usize) {
     let comment_diff = end - start;
-    let is_comment_after_code_line = stats.ploc.lines.contains(&start);
+    let is_comment_after_code_line = stats.ploc.is_code_line(start);
     if is_comment_after_code_line && comment_diff == 0 {
         // A comment is *entirely* next to a code line
         stats.cloc.code_comment_lines += 1;
@@ -554,7 +594,7 @@ fn add_cloc_lines(stats: &mut Stats, start: usize, end: usize) {
 // a wrong count for the blank metric.
 fn check_comment_ends_on_code_line(stats: &mut Stats, start_code_line: usize) {
     if let Some(end) = stats.cloc.comment_line_end {
-        if end == start_code_line && !stats.ploc.lines.contains(&start_code_line) {
+        if end == start_code_line && !stats.ploc.is_code_line(start_code_line) {
             // Comment entirely *before* a code line
             stats.cloc.only_comment_lines -= 1;
             stats.cloc.code_comment_lines += 1;
@@ -768,6 +808,52 @@ impl Loc for RustCode {
     }
 }
 
+impl Loc for CCode {
+    fn compute(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool) {
+        use C::*;
+
+        let (start, end) = init(node, stats, is_func_space, is_unit);
+
+        match node.kind_id().into() {
+            StringLiteral | FieldDeclarationList | TranslationUnit => {}
+            Comment => {
+                add_cloc_lines(stats, start, end);
+            }
+            WhileStatement | SwitchStatement | CaseStatement | IfStatement | ForStatement
+            | ReturnStatement | BreakStatement | ContinueStatement | GotoStatement
+            | ExpressionStatement | ExpressionStatement2 | LabeledStatement => {
+                stats.lloc.logical_lines += 1;
+            }
+            Declaration => {
+                if node.count_specific_ancestors::<CParser>(
+                    |node| {
+                        matches!(
+                            node.kind_id().into(),
+                            WhileStatement | ForStatement | IfStatement
+                        )
+                    },
+                    |node| node.kind_id() == CompoundStatement,
+                ) == 0
+                {
+                    stats.lloc.logical_lines += 1;
+                }
+            }
+            _ => {
+                check_comment_ends_on_code_line(stats, start);
+                stats.ploc.lines.insert(start);
+
+                // The argument of a macro is a single unexpanded `PreprocArg` node,
+                // so all its lines are physical lines
+                if let PreprocArg = node.kind_id().into() {
+                    (node.start_row() + 1..=node.end_row()).for_each(|line| {
+                        stats.ploc.lines.insert(line);
+                    });
+                }
+            }
+        }
+    }
+}
+
 impl Loc for CppCode {
     fn compute(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool) {
         use Cpp::*;
@@ -898,7 +984,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -940,7 +1031,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -983,7 +1079,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1014,7 +1115,14 @@ mod tests {
                       "lloc_min": 0.0,
                       "lloc_max": 0.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": 1.0,
+                      "sloc_p90": 1.0,
+                      "sloc_p95": 1.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "1-2": 1
+                      }
                     }"###
             );
         });
@@ -1056,7 +1164,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1104,7 +1217,14 @@ mod tests {
                       "lloc_min": 6.0,
                       "lloc_max": 6.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": 10.0,
+                      "sloc_p90": 10.0,
+                      "sloc_p95": 10.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1151,7 +1271,14 @@ mod tests {
                       "lloc_min": 6.0,
                       "lloc_max": 6.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": 9.0,
+                      "sloc_p90": 9.0,
+                      "sloc_p95": 9.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1199,7 +1326,14 @@ mod tests {
                       "lloc_min": 6.0,
                       "lloc_max": 6.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": 10.0,
+                      "sloc_p90": 10.0,
+                      "sloc_p95": 10.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1248,13 +1382,60 @@ mod tests {
                       "lloc_min": 6.0,
                       "lloc_max": 6.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": 11.0,
+                      "sloc_p90": 11.0,
+                      "sloc_p95": 11.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
         );
     }
 
+    #[test]
+    fn rust_comment_after_nested_space() {
+        // Checks that a comment following the code of a nested space
+        // is a comment on a code line
+        check_metrics::<RustParser>("mod m {} // mod m", "foo.rs", |metric| {
+            // Spaces: 2
+            insta::assert_json_snapshot!(
+                metric.loc,
+                @r###"
+                    {
+                      "sloc": 1.0,
+                      "ploc": 1.0,
+                      "lloc": 0.0,
+                      "cloc": 1.0,
+                      "blank": 0.0,
+                      "sloc_average": 0.5,
+                      "ploc_average": 0.5,
+                      "lloc_average": 0.0,
+                      "cloc_average": 0.5,
+                      "blank_average": 0.0,
+                      "sloc_min": 1.0,
+                      "sloc_max": 1.0,
+                      "cloc_min": 0.0,
+                      "cloc_max": 0.0,
+                      "ploc_min": 1.0,
+                      "ploc_max": 1.0,
+                      "lloc_min": 0.0,
+                      "lloc_max": 0.0,
+                      "blank_min": 0.0,
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
+                    }"###
+            );
+        });
+    }
+
     #[test]
     fn javascript_no_zero_blank() {
         // Checks that the blank metric is not equal to 0 when there are some
@@ -1297,7 +1478,14 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": 11.0,
+                      "sloc_p90": 11.0,
+                      "sloc_p95": 11.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1346,7 +1534,14 @@ mod tests {
                       "lloc_min": 6.0,
                       "lloc_max": 6.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": 11.0,
+                      "sloc_p90": 11.0,
+                      "sloc_p95": 11.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1396,7 +1591,14 @@ mod tests {
                       "lloc_min": 6.0,
                       "lloc_max": 6.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": 12.0,
+                      "sloc_p90": 12.0,
+                      "sloc_p95": 12.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1447,7 +1649,14 @@ mod tests {
                       "lloc_min": 6.0,
                       "lloc_max": 6.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": 13.0,
+                      "sloc_p90": 13.0,
+                      "sloc_p95": 13.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1495,7 +1704,14 @@ mod tests {
                       "lloc_min": 6.0,
                       "lloc_max": 6.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": 10.0,
+                      "sloc_p90": 10.0,
+                      "sloc_p95": 10.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1545,7 +1761,14 @@ mod tests {
                       "lloc_min": 6.0,
                       "lloc_max": 6.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": 12.0,
+                      "sloc_p90": 12.0,
+                      "sloc_p95": 12.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "8-16": 1
+                      }
                     }"###
                 );
             },
@@ -1586,7 +1809,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1626,7 +1854,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1666,7 +1899,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1705,7 +1943,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1746,7 +1989,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1786,7 +2034,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1824,7 +2077,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1865,7 +2123,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1904,7 +2167,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1945,7 +2213,12 @@ mod tests {
                       "lloc_min": 4.0,
                       "lloc_max": 4.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -1986,7 +2259,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2025,7 +2303,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2042,7 +2325,7 @@ mod tests {
              foo.field;",
             "foo.rs",
             |metric| {
-                // Spaces: 1
+                // Spaces: 2
                 insta::assert_json_snapshot!(
                     metric.loc,
                     @r###"
@@ -2052,21 +2335,26 @@ mod tests {
                       "lloc": 2.0,
                       "cloc": 0.0,
                       "blank": 0.0,
-                      "sloc_average": 5.0,
-                      "ploc_average": 5.0,
-                      "lloc_average": 2.0,
+                      "sloc_average": 2.5,
+                      "ploc_average": 2.5,
+                      "lloc_average": 1.0,
                       "cloc_average": 0.0,
                       "blank_average": 0.0,
-                      "sloc_min": 5.0,
-                      "sloc_max": 5.0,
+                      "sloc_min": 3.0,
+                      "sloc_max": 3.0,
                       "cloc_min": 0.0,
                       "cloc_max": 0.0,
-                      "ploc_min": 5.0,
-                      "ploc_max": 5.0,
-                      "lloc_min": 2.0,
-                      "lloc_max": 2.0,
+                      "ploc_min": 3.0,
+                      "ploc_max": 3.0,
+                      "lloc_min": 0.0,
+                      "lloc_max": 0.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2100,7 +2388,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
             );
         });
@@ -2133,7 +2426,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
             );
         });
@@ -2166,7 +2464,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
             );
         });
@@ -2199,7 +2502,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
             );
         });
@@ -2237,7 +2545,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2276,7 +2589,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2315,7 +2633,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2353,7 +2676,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2391,7 +2719,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2430,7 +2763,14 @@ mod tests {
                       "lloc_min": 0.0,
                       "lloc_max": 0.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": 1.0,
+                      "sloc_p90": 1.0,
+                      "sloc_p95": 1.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "1-2": 2
+                      }
                     }"###
                 );
             },
@@ -2472,7 +2812,14 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": 6.0,
+                      "sloc_p90": 6.0,
+                      "sloc_p95": 6.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -2524,7 +2871,14 @@ mod tests {
                       "lloc_min": 8.0,
                       "lloc_max": 8.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": 16.0,
+                      "sloc_p90": 16.0,
+                      "sloc_p95": 16.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "16-32": 1
+                      }
                     }"###
                 );
             },
@@ -2565,7 +2919,14 @@ mod tests {
                       "lloc_min": 5.0,
                       "lloc_max": 5.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": 5.0,
+                      "sloc_p90": 5.0,
+                      "sloc_p95": 5.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -2606,7 +2967,14 @@ mod tests {
                       "lloc_min": 5.0,
                       "lloc_max": 5.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": 5.0,
+                      "sloc_p90": 5.0,
+                      "sloc_p95": 5.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -2643,7 +3011,12 @@ mod tests {
                       "lloc_min": 0.0,
                       "lloc_max": 0.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2685,7 +3058,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2725,7 +3103,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 2.0,
-                      "blank_max": 2.0
+                      "blank_max": 2.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2764,7 +3147,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2803,7 +3191,12 @@ mod tests {
                       "lloc_min": 0.0,
                       "lloc_max": 0.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2837,7 +3230,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
             );
         });
@@ -2875,7 +3273,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2915,7 +3318,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -2949,7 +3357,12 @@ mod tests {
                       "lloc_min": 1.0,
                       "lloc_max": 1.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
             );
         });
@@ -2987,7 +3400,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3028,7 +3446,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3070,7 +3493,12 @@ mod tests {
                       "lloc_min": 4.0,
                       "lloc_max": 4.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3112,7 +3540,12 @@ mod tests {
                       "lloc_min": 4.0,
                       "lloc_max": 4.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3164,7 +3597,12 @@ mod tests {
                       "lloc_min": 9.0,
                       "lloc_max": 9.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3206,7 +3644,12 @@ mod tests {
                       "lloc_min": 5.0,
                       "lloc_max": 5.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3249,7 +3692,12 @@ mod tests {
                       "lloc_min": 5.0,
                       "lloc_max": 5.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3295,7 +3743,14 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": 3.0,
+                      "sloc_p90": 3.0,
+                      "sloc_p95": 3.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -3343,7 +3798,12 @@ mod tests {
                       "lloc_min": 12.0,
                       "lloc_max": 12.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3380,7 +3840,12 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3427,7 +3892,12 @@ mod tests {
                       "lloc_min": 3.0,
                       "lloc_max": 3.0,
                       "blank_min": 1.0,
-                      "blank_max": 1.0
+                      "blank_max": 1.0,
+                      "sloc_median": null,
+                      "sloc_p90": null,
+                      "sloc_p95": null,
+                      "sloc_stddev": null,
+                      "sloc_histogram": {}
                     }"###
                 );
             },
@@ -3475,7 +3945,14 @@ mod tests {
                       "lloc_min": 2.0,
                       "lloc_max": 2.0,
                       "blank_min": 0.0,
-                      "blank_max": 0.0
+                      "blank_max": 0.0,
+                      "sloc_median": 4.0,
+                      "sloc_p90": 4.0,
+                      "sloc_p95": 4.0,
+                      "sloc_stddev": 0.0,
+                      "sloc_histogram": {
+                        "4-8": 1
+                      }
                     }"###
                 );
             },
@@ -110,6 +110,7 @@ implement_metric_trait!(
     TypescriptCode,
     TsxCode,
     RustCode,
+    CCode,
     CppCode,
     PreprocCode,
     CcommentCode,
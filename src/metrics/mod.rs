@@ -1,12 +1,22 @@
 pub mod abc;
+pub mod cbo;
 pub mod cognitive;
+pub mod crap;
 pub mod cyclomatic;
+pub mod distribution;
+pub mod docs;
 pub mod exit;
 pub mod halstead;
+pub mod inheritance;
+pub mod jsx;
+pub mod lcom;
 pub mod loc;
 pub mod mi;
 pub mod nargs;
+pub mod nesting;
 pub mod nom;
 pub mod npa;
 pub mod npm;
+pub mod types;
+pub mod typing;
 pub mod wmc;
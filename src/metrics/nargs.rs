@@ -3,6 +3,7 @@ use serde::Serialize;
 use std::fmt;
 
 use crate::checker::Checker;
+use crate::distribution::Distribution;
 use crate::macros::implement_metric_trait;
 use crate::*;
 
@@ -22,6 +23,7 @@ pub struct Stats {
     closure_nargs_max: usize,
     total_functions: usize,
     total_closures: usize,
+    distribution: Distribution,
 }
 
 impl Default for Stats {
@@ -37,6 +39,7 @@ impl Default for Stats {
             closure_nargs_max: 0,
             total_functions: 0,
             total_closures: 0,
+            distribution: Distribution::default(),
         }
     }
 }
@@ -46,7 +49,7 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut st = serializer.serialize_struct("nargs", 10)?;
+        let mut st = serializer.serialize_struct("nargs", 15)?;
         st.serialize_field("total_functions", &self.fn_args_sum())?;
         st.serialize_field("total_closures", &self.closure_args_sum())?;
         st.serialize_field("average_functions", &self.fn_args_average())?;
@@ -57,6 +60,11 @@ impl Serialize for Stats {
         st.serialize_field("functions_max", &self.fn_args_max())?;
         st.serialize_field("closures_min", &self.closure_args_min())?;
         st.serialize_field("closures_max", &self.closure_args_max())?;
+        st.serialize_field("median", &self.nargs_distribution().median())?;
+        st.serialize_field("p90", &self.nargs_distribution().p90())?;
+        st.serialize_field("p95", &self.nargs_distribution().p95())?;
+        st.serialize_field("stddev", &self.nargs_distribution().stddev())?;
+        st.serialize_field("histogram", &self.nargs_distribution().histogram())?;
         st.end()
     }
 }
@@ -89,6 +97,7 @@ impl Stats {
         self.fn_nargs_max = self.fn_nargs_max.max(other.fn_nargs_max);
         self.fn_nargs_sum += other.fn_nargs_sum;
         self.closure_nargs_sum += other.closure_nargs_sum;
+        self.distribution.merge(&other.distribution);
     }
 
     /// Returns the number of function arguments in a space.
@@ -167,6 +176,14 @@ impl Stats {
         self.closure_nargs_sum += self.closure_nargs;
         self.fn_nargs_sum += self.fn_nargs;
     }
+
+    /// Returns the distribution of the `NArgs` metric values
+    /// of the functions in a space
+    #[inline(always)]
+    pub fn nargs_distribution(&self) -> &Distribution {
+        &self.distribution
+    }
+
     #[inline(always)]
     pub(crate) fn compute_minmax(&mut self) {
         self.closure_nargs_min = self.closure_nargs_min.min(self.closure_nargs);
@@ -175,6 +192,13 @@ impl Stats {
         self.fn_nargs_max = self.fn_nargs_max.max(self.fn_nargs);
         self.compute_sum();
     }
+
+    // Adds the `NArgs` value of a function to the distribution
+    #[inline(always)]
+    pub(crate) fn compute_distribution(&mut self) {
+        self.distribution
+            .add((self.fn_nargs + self.closure_nargs) as f64);
+    }
     pub(crate) fn finalize(&mut self, total_functions: usize, total_closures: usize) {
         self.total_functions = total_functions;
         self.total_closures = total_closures;
@@ -210,6 +234,44 @@ where
     }
 }
 
+impl NArgs for CCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use C::*;
+
+        if !Self::is_func(node) {
+            return;
+        }
+        // The declarator of `int *f(int a)` is a pointer declarator
+        let Some(parameters) = node
+            .child_by_field_name("declarator")
+            .and_then(|declarator| {
+                declarator.first_occurrence(|id| {
+                    matches!(
+                        C::from(id),
+                        FunctionDeclarator
+                            | FunctionDeclarator2
+                            | FunctionDeclarator3
+                            | FunctionDeclarator4
+                            | FunctionDeclarator5
+                    )
+                })
+            })
+            .and_then(|declarator| declarator.child_by_field_name("parameters"))
+        else {
+            return;
+        };
+        // The parameter of a definition has a name, except `void` in `int f(void)`
+        stats.fn_nargs += parameters
+            .children()
+            .filter(|parameter| match parameter.kind_id().into() {
+                ParameterDeclaration => parameter.child_by_field_name("declarator").is_some(),
+                VariadicParameter => true,
+                _ => false,
+            })
+            .count();
+    }
+}
+
 impl NArgs for CppCode {
     fn compute(node: &Node, stats: &mut Stats) {
         if Self::is_func(node) {
@@ -266,7 +328,12 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 0.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -289,7 +356,12 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 0.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -312,7 +384,12 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 0.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -335,7 +412,12 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 0.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": null,
+                      "p90": null,
+                      "p95": null,
+                      "stddev": null,
+                      "histogram": {}
                     }"###
             );
         });
@@ -363,7 +445,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -394,7 +483,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -425,7 +521,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -454,7 +557,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -477,8 +587,15 @@ mod tests {
                       "average": 1.0,
                       "functions_min": 0.0,
                       "functions_max": 0.0,
-                      "closures_min": 1.0,
-                      "closures_max": 1.0
+                      "closures_min": 0.0,
+                      "closures_max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
                     }"###
             );
         });
@@ -501,7 +618,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 0.0,
                       "closures_min": 0.0,
-                      "closures_max": 1.0
+                      "closures_max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.0,
+                      "p95": 1.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "1-2": 1
+                      }
                     }"###
             );
         });
@@ -526,8 +650,15 @@ mod tests {
                       "average": 2.0,
                       "functions_min": 0.0,
                       "functions_max": 0.0,
-                      "closures_min": 2.0,
-                      "closures_max": 2.0
+                      "closures_min": 0.0,
+                      "closures_max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -551,7 +682,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 0.0,
                       "closures_min": 0.0,
-                      "closures_max": 2.0
+                      "closures_max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 1
+                      }
                     }"###
             );
         });
@@ -582,7 +720,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -611,7 +756,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 3.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.5,
+                      "p90": 2.9,
+                      "p95": 2.95,
+                      "stddev": 0.5,
+                      "histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -647,7 +799,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -680,7 +839,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 3.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.5,
+                      "p90": 2.9,
+                      "p95": 2.95,
+                      "stddev": 0.5,
+                      "histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -716,7 +882,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -749,7 +922,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 3.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.5,
+                      "p90": 2.9,
+                      "p95": 2.95,
+                      "stddev": 0.5,
+                      "histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -781,7 +961,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.0,
+                      "histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -810,7 +997,14 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 3.0,
                       "closures_min": 0.0,
-                      "closures_max": 0.0
+                      "closures_max": 0.0,
+                      "median": 2.5,
+                      "p90": 2.9,
+                      "p95": 2.95,
+                      "stddev": 0.5,
+                      "histogram": {
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -842,7 +1036,15 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 2.0
+                      "closures_max": 1.0,
+                      "median": 1.0,
+                      "p90": 1.7000000000000002,
+                      "p95": 1.8499999999999996,
+                      "stddev": 0.4330127018922193,
+                      "histogram": {
+                        "1-2": 3,
+                        "2-4": 1
+                      }
                     }"###
                 );
             },
@@ -876,7 +1078,15 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 2.0
+                      "closures_max": 2.0,
+                      "median": 1.5,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.5,
+                      "histogram": {
+                        "1-2": 2,
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -907,7 +1117,15 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 3.0,
                       "closures_min": 0.0,
-                      "closures_max": 3.0
+                      "closures_max": 2.0,
+                      "median": 2.0,
+                      "p90": 2.8,
+                      "p95": 2.9,
+                      "stddev": 0.816496580927726,
+                      "histogram": {
+                        "1-2": 1,
+                        "2-4": 2
+                      }
                     }"###
                 );
             },
@@ -941,7 +1159,15 @@ mod tests {
                       "functions_min": 0.0,
                       "functions_max": 2.0,
                       "closures_min": 0.0,
-                      "closures_max": 1.0
+                      "closures_max": 1.0,
+                      "median": 2.0,
+                      "p90": 2.0,
+                      "p95": 2.0,
+                      "stddev": 0.4330127018922193,
+                      "histogram": {
+                        "1-2": 1,
+                        "2-4": 3
+                      }
                     }"###
                 );
             },
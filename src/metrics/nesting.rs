@@ -0,0 +1,553 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Nesting` metric.
+///
+/// This metric measures the maximum depth reached by the nested
+/// control structures of a space, such as conditionals, loops,
+/// exception handlers, pattern matchings and closures.
+///
+/// The depth of a control structure is counted from the
+/// beginning of the function/method containing it, and an `else if`
+/// is considered at the same depth of its `if`.
+///
+/// The average depth of the control structures of a space measures how
+/// deeply its code is nested overall, while the maximum depth only reports
+/// its most nested control structure.
+#[derive(Debug, Clone)]
+pub struct Stats {
+    nesting: usize,
+    nesting_sum: usize,
+    n: usize,
+    nesting_max: usize,
+    nesting_min: usize,
+    depth_sum: usize,
+    blocks: usize,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            nesting: 0,
+            nesting_sum: 0,
+            n: 1,
+            nesting_max: 0,
+            nesting_min: usize::MAX,
+            depth_sum: 0,
+            blocks: 0,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("nesting", 6)?;
+        st.serialize_field("sum", &self.nesting_sum())?;
+        st.serialize_field("average", &self.nesting_average())?;
+        st.serialize_field("min", &self.nesting_min())?;
+        st.serialize_field("max", &self.nesting_max())?;
+        st.serialize_field("blocks", &self.blocks())?;
+        st.serialize_field("average_depth", &self.average_depth())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}, blocks: {}, average_depth: {}",
+            self.nesting_sum(),
+            self.nesting_average(),
+            self.nesting_min(),
+            self.nesting_max(),
+            self.blocks(),
+            self.average_depth()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Nesting` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.nesting_max = self.nesting_max.max(other.nesting_max);
+        self.nesting_min = self.nesting_min.min(other.nesting_min);
+        self.nesting_sum += other.nesting_sum;
+        self.n += other.n;
+        self.depth_sum += other.depth_sum;
+        self.blocks += other.blocks;
+    }
+
+    /// Returns the `Nesting` metric value
+    ///
+    /// This value is the maximum depth of the control structures
+    /// contained in a space, excluding its subspaces.
+    pub fn nesting(&self) -> f64 {
+        self.nesting as f64
+    }
+    /// Returns the `Nesting` metric sum value
+    pub fn nesting_sum(&self) -> f64 {
+        self.nesting_sum as f64
+    }
+
+    /// Returns the `Nesting` metric average value
+    ///
+    /// This value is computed dividing the `Nesting` sum value
+    /// for the number of spaces.
+    pub fn nesting_average(&self) -> f64 {
+        self.nesting_sum() / self.n as f64
+    }
+    /// Returns the `Nesting` metric minimum value
+    pub fn nesting_min(&self) -> f64 {
+        self.nesting_min as f64
+    }
+    /// Returns the `Nesting` metric maximum value
+    pub fn nesting_max(&self) -> f64 {
+        self.nesting_max as f64
+    }
+    /// Returns the number of control structures in a space
+    pub fn blocks(&self) -> f64 {
+        self.blocks as f64
+    }
+    /// Returns the average depth of the control structures in a space
+    ///
+    /// This value is computed dividing the sum of the depths
+    /// of the control structures for their number.
+    pub fn average_depth(&self) -> f64 {
+        if self.blocks == 0 {
+            0.
+        } else {
+            self.depth_sum as f64 / self.blocks as f64
+        }
+    }
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.nesting_sum += self.nesting;
+    }
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.nesting_max = self.nesting_max.max(self.nesting);
+        self.nesting_min = self.nesting_min.min(self.nesting);
+        self.compute_sum();
+    }
+}
+
+pub trait Nesting
+where
+    Self: Checker,
+{
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+// Computes the depth of a control structure counting the control structures
+// containing it, until the beginning of the function containing it.
+// A closure is counted as a control structure even when it is a space.
+fn compute_depth<T: Checker>(node: &Node, stats: &mut Stats, is_nesting: fn(&Node) -> bool) {
+    if !is_nesting(node) {
+        return;
+    }
+    let mut depth = 1;
+    let mut parent = node.parent();
+    while let Some(node) = parent {
+        if (T::is_func(&node) || T::is_func_space(&node)) && !T::is_closure(&node) {
+            break;
+        }
+        if is_nesting(&node) {
+            depth += 1;
+        }
+        parent = node.parent();
+    }
+    stats.nesting = stats.nesting.max(depth);
+    stats.depth_sum += depth;
+    stats.blocks += 1;
+}
+
+impl Nesting for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_depth::<Self>(node, stats, |node| {
+            matches!(
+                node.kind_id().into(),
+                Python::IfStatement
+                    | Python::ForStatement
+                    | Python::WhileStatement
+                    | Python::TryStatement
+                    | Python::WithStatement
+                    | Python::MatchStatement
+                    | Python::Lambda
+            )
+        });
+    }
+}
+
+macro_rules! js_nesting {
+    ($code:ident, $language:ident) => {
+        impl Nesting for $code {
+            fn compute(node: &Node, stats: &mut Stats) {
+                compute_depth::<Self>(node, stats, |node| {
+                    use $language::*;
+
+                    match node.kind_id().into() {
+                        // `else if` is an `if` contained in an `else` clause
+                        IfStatement => node
+                            .parent()
+                            .is_none_or(|parent| parent.kind_id() != ElseClause),
+                        ForStatement | ForInStatement | WhileStatement | DoStatement
+                        | TryStatement | SwitchStatement | ArrowFunction | FunctionExpression => {
+                            true
+                        }
+                        _ => false,
+                    }
+                });
+            }
+        }
+    };
+}
+
+js_nesting!(MozjsCode, Mozjs);
+js_nesting!(JavascriptCode, Javascript);
+js_nesting!(TypescriptCode, Typescript);
+js_nesting!(TsxCode, Tsx);
+
+impl Nesting for RustCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_depth::<Self>(node, stats, |node| {
+            use Rust::*;
+
+            match node.kind_id().into() {
+                // `else if` is an `if` contained in an `else` clause
+                IfExpression => node
+                    .parent()
+                    .is_none_or(|parent| parent.kind_id() != ElseClause),
+                ForExpression | WhileExpression | LoopExpression | MatchExpression
+                | ClosureExpression => true,
+                _ => false,
+            }
+        });
+    }
+}
+
+impl Nesting for CCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_depth::<Self>(node, stats, |node| {
+            use C::*;
+
+            match node.kind_id().into() {
+                IfStatement => !Self::is_else_if(node),
+                ForStatement | WhileStatement | DoStatement | SwitchStatement => true,
+                _ => false,
+            }
+        });
+    }
+}
+
+impl Nesting for CppCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_depth::<Self>(node, stats, |node| {
+            use Cpp::*;
+
+            match node.kind_id().into() {
+                IfStatement => !Self::is_else_if(node),
+                ForStatement | ForRangeLoop | WhileStatement | DoStatement | TryStatement
+                | SwitchStatement | LambdaExpression => true,
+                _ => false,
+            }
+        });
+    }
+}
+
+impl Nesting for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_depth::<Self>(node, stats, |node| {
+            use Java::*;
+
+            match node.kind_id().into() {
+                // `else if` is an `if` being the alternative of another `if`
+                IfStatement => node.parent().is_none_or(|parent| {
+                    parent.kind_id() != IfStatement
+                        || parent
+                            .child_by_field_name("alternative")
+                            .is_none_or(|alternative| alternative.id() != node.id())
+                }),
+                ForStatement
+                | EnhancedForStatement
+                | WhileStatement
+                | DoStatement
+                | TryStatement
+                | TryWithResourcesStatement
+                | SwitchExpression
+                | LambdaExpression => true,
+                _ => false,
+            }
+        });
+    }
+}
+
+impl Nesting for KotlinCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_depth::<Self>(node, stats, |node| {
+            use Kotlin::*;
+
+            match node.kind_id().into() {
+                // `else if` is an `if` following the `else` of another `if`
+                IfExpression => node.parent().is_none_or(|parent| {
+                    parent.kind_id() != IfExpression
+                        || node
+                            .previous_sibling()
+                            .is_none_or(|prev| prev.kind_id() != Else)
+                }),
+                WhenExpression | ForStatement | WhileStatement | DoWhileStatement
+                | TryExpression | LambdaLiteral | AnonymousFunction => true,
+                _ => false,
+            }
+        });
+    }
+}
+
+implement_metric_trait!(Nesting, PreprocCode, CcommentCode);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics;
+
+    use super::*;
+
+    #[test]
+    fn python_nested_loops() {
+        check_metrics::<PythonParser>(
+            "def f(a, b):
+                 if a:  # 1
+                     for i in range(b):  # 2
+                         while i:  # 3
+                             i -= 1
+                 elif b:  # 1
+                     with open(b) as g:  # 2
+                         pass
+             def g():
+                 return lambda x: x  # 1",
+            "foo.py",
+            |metric| {
+                // nspace = 4 (2 functions, lambda and unit)
+                insta::assert_json_snapshot!(
+                    metric.nesting,
+                    @r###"
+                    {
+                      "sum": 4.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 3.0,
+                      "blocks": 5.0,
+                      "average_depth": 1.8
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_nested_function() {
+        check_metrics::<PythonParser>(
+            "def f(a):
+                 for i in a:  # 1
+                     def g(b):
+                         try:  # 1
+                             return b
+                         except:
+                             pass
+                     g(i)",
+            "foo.py",
+            |metric| {
+                // nspace = 3 (2 functions and unit)
+                insta::assert_json_snapshot!(
+                    metric.nesting,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 0.6666666666666666,
+                      "min": 0.0,
+                      "max": 1.0,
+                      "blocks": 2.0,
+                      "average_depth": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_else_if_and_closures() {
+        check_metrics::<RustParser>(
+            "fn f(a: Option<usize>, b: bool) {
+                 if b { // 1
+                 } else if let Some(a) = a { // 1
+                     match a { // 2
+                         0 => (0..a).for_each(|i| { // 3
+                             loop { // 4
+                                 break;
+                             }
+                         }),
+                         _ => {}
+                     }
+                 }
+             }",
+            "foo.rs",
+            |metric| {
+                // nspace = 3 (func, closure and unit)
+                insta::assert_json_snapshot!(
+                    metric.nesting,
+                    @r###"
+                    {
+                      "sum": 6.0,
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 4.0,
+                      "blocks": 4.0,
+                      "average_depth": 2.5
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_else_if_and_lambdas() {
+        check_metrics::<JavaParser>(
+            "class A {
+                 void f(int a) {
+                     if (a == 0) { // 1
+                     } else if (a == 1) { // 1
+                         for (int i = 0; i < a; i++) { // 2
+                             try { // 3
+                                 g(() -> { while (true) {} }); // 4, 5
+                             } catch (Exception e) {}
+                         }
+                     } else {
+                         switch (a) { // 2
+                             default: break;
+                         }
+                     }
+                 }
+             }",
+            "foo.java",
+            |metric| {
+                // nspace = 3 (method, class and unit)
+                insta::assert_json_snapshot!(
+                    metric.nesting,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 1.6666666666666667,
+                      "min": 0.0,
+                      "max": 5.0,
+                      "blocks": 6.0,
+                      "average_depth": 2.8333333333333335
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_else_if() {
+        check_metrics::<JavascriptParser>(
+            "function f(a) {
+                 if (a) { // 1
+                 } else if (!a) { // 1
+                     do { // 2
+                         a--;
+                     } while (a);
+                 }
+             }",
+            "foo.js",
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.nesting,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0,
+                      "blocks": 2.0,
+                      "average_depth": 1.5
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_average_depth() {
+        check_metrics::<PythonParser>(
+            "def f(a, b):
+                 if a:  # 1
+                     for i in b:  # 2
+                         pass
+                 if b:  # 1
+                     pass
+                 while a:  # 1
+                     a -= 1",
+            "foo.py",
+            |metric| {
+                // blocks = 4, depth sum = 5
+                insta::assert_json_snapshot!(
+                    metric.nesting,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0,
+                      "blocks": 4.0,
+                      "average_depth": 1.25
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn kotlin_else_if_and_lambdas() {
+        check_metrics::<KotlinParser>(
+            "fun f(a: Int) {
+                 if (a > 0) { // 1
+                 } else if (a < 0) { // 1
+                     for (i in 0..a) { // 2
+                         when (i) { // 3
+                             1 -> listOf(i).forEach { x -> println(x) } // 4
+                         }
+                     }
+                 } else {
+                     try { // 2
+                         do { // 3
+                         } while (false)
+                     } catch (e: Exception) {}
+                 }
+             }",
+            "foo.kt",
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.nesting,
+                    @r###"
+                    {
+                      "sum": 4.0,
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 4.0,
+                      "blocks": 6.0,
+                      "average_depth": 2.5
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -208,6 +208,7 @@ implement_metric_trait!(
     JavascriptCode,
     TypescriptCode,
     TsxCode,
+    CCode,
     CppCode,
     RustCode,
     PreprocCode,
@@ -234,17 +235,17 @@ mod tests {
              x = lambda a : a + 42",
             "foo.py",
             |metric| {
-                // Number of spaces = 4
+                // Number of spaces = 5
                 insta::assert_json_snapshot!(
                     metric.nom,
                     @r###"
                     {
                       "functions": 3.0,
                       "closures": 1.0,
-                      "functions_average": 0.75,
-                      "closures_average": 0.25,
+                      "functions_average": 0.6,
+                      "closures_average": 0.2,
                       "total": 4.0,
-                      "average": 1.0,
+                      "average": 0.8,
                       "functions_min": 0.0,
                       "functions_max": 1.0,
                       "closures_min": 0.0,
@@ -263,17 +264,17 @@ mod tests {
              let closure = |i: i32| -> i32 { i + 42 };",
             "foo.rs",
             |metric| {
-                // Number of spaces = 4
+                // Number of spaces = 6
                 insta::assert_json_snapshot!(
                     metric.nom,
                     @r###"
                     {
                       "functions": 2.0,
                       "closures": 1.0,
-                      "functions_average": 0.5,
-                      "closures_average": 0.25,
+                      "functions_average": 0.3333333333333333,
+                      "closures_average": 0.16666666666666666,
                       "total": 3.0,
-                      "average": 0.75,
+                      "average": 0.5,
                       "functions_min": 0.0,
                       "functions_max": 1.0,
                       "closures_min": 0.0,
@@ -325,17 +326,17 @@ mod tests {
              int b = [](int x) -> int { return x + 42; };",
             "foo.cpp",
             |metric| {
-                // Number of spaces = 4
+                // Number of spaces = 5
                 insta::assert_json_snapshot!(
                     metric.nom,
                     @r###"
                     {
                       "functions": 2.0,
                       "closures": 1.0,
-                      "functions_average": 0.5,
-                      "closures_average": 0.25,
+                      "functions_average": 0.4,
+                      "closures_average": 0.2,
                       "total": 3.0,
-                      "average": 0.75,
+                      "average": 0.6,
                       "functions_min": 0.0,
                       "functions_max": 1.0,
                       "closures_min": 0.0,
@@ -3,6 +3,7 @@ use serde::Serialize;
 use std::fmt;
 
 use crate::checker::Checker;
+use crate::getter::is_kotlin_public;
 use crate::langs::*;
 use crate::macros::implement_metric_trait;
 use crate::node::Node;
@@ -213,7 +214,8 @@ impl Npa for JavaCode {
         }
 
         match node.kind_id().into() {
-            ClassBody => {
+            // The fields of an enum follow its constants
+            ClassBody | EnumBodyDeclarations => {
                 stats.class_na += node
                     .children()
                     .filter(|node| matches!(node.kind_id().into(), FieldDeclaration))
@@ -255,6 +257,66 @@ impl Npa for JavaCode {
     }
 }
 
+impl Npa for KotlinCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Kotlin::*;
+
+        // Enables the `Npa` metric if computing stats of a class space
+        if Self::is_func_space(node) && stats.is_disabled() {
+            stats.is_class_space = true;
+        }
+
+        let (attributes, public_attributes) = match node.kind_id().into() {
+            // The properties of an enum follow its entries,
+            // a property can declare several variables: `val (a, b) = pair`
+            ClassBody | EnumClassBody => node
+                .children()
+                .filter(|member| member.kind_id() == PropertyDeclaration)
+                .fold((0, 0), |(attributes, public_attributes), property| {
+                    let variables = property
+                        .first_child(|id| id == MultiVariableDeclaration)
+                        .map_or(1, |declaration| {
+                            declaration
+                                .children()
+                                .filter(|variable| variable.kind_id() == VariableDeclaration)
+                                .count()
+                        });
+                    (
+                        attributes + variables,
+                        public_attributes + variables * usize::from(is_kotlin_public(&property)),
+                    )
+                }),
+            // The properties declared in a primary constructor: `class A(val a: Int)`
+            ClassParameters => node
+                .children()
+                .filter(|parameter| {
+                    parameter.kind_id() == ClassParameter
+                        && (parameter.is_child(Val as u16) || parameter.is_child(Var as u16))
+                })
+                .fold((0, 0), |(attributes, public_attributes), parameter| {
+                    (
+                        attributes + 1,
+                        public_attributes + usize::from(is_kotlin_public(&parameter)),
+                    )
+                }),
+            _ => return,
+        };
+
+        // Properties of an interface are public by default, as in a class
+        let class = match node.kind_id().into() {
+            ClassParameters => node.parent().and_then(|constructor| constructor.parent()),
+            _ => node.parent(),
+        };
+        if class.is_some_and(|class| class.is_child(Interface as u16)) {
+            stats.interface_na += attributes;
+            stats.interface_npa += public_attributes;
+        } else {
+            stats.class_na += attributes;
+            stats.class_npa += public_attributes;
+        }
+    }
+}
+
 implement_metric_trait!(
     Npa,
     PythonCode,
@@ -263,10 +325,10 @@ implement_metric_trait!(
     TypescriptCode,
     TsxCode,
     RustCode,
+    CCode,
     CppCode,
     PreprocCode,
-    CcommentCode,
-    KotlinCode
+    CcommentCode
 );
 
 #[cfg(test)]
@@ -733,4 +795,73 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn kotlin_classes() {
+        check_metrics::<KotlinParser>(
+            "class X(val a: Int, private var b: Int, c: Int) { // +1
+                val d = 1                           // +1
+                internal val e = 2
+                protected var f = 3
+                val (g, h) = Pair(4, 5)             // +2
+                var i: Int = 0                      // +1
+                    get() = field
+                    private set
+                companion object {
+                    const val J = 6                 // +1
+                    private val k = 7
+                }
+            }
+            object Y {
+                val l = 8                           // +1
+            }",
+            "foo.kt",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npa,
+                    @r###"
+                    {
+                      "classes": 7.0,
+                      "interfaces": 0.0,
+                      "class_attributes": 11.0,
+                      "interface_attributes": 0.0,
+                      "classes_average": 0.6363636363636364,
+                      "interfaces_average": null,
+                      "total": 7.0,
+                      "total_attributes": 11.0,
+                      "average": 0.6363636363636364
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn kotlin_interface() {
+        check_metrics::<KotlinParser>(
+            "interface X {
+                val a: Int      // +1
+                val b: Int      // +1
+                    get() = 1
+            }",
+            "foo.kt",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npa,
+                    @r###"
+                    {
+                      "classes": 0.0,
+                      "interfaces": 2.0,
+                      "class_attributes": 0.0,
+                      "interface_attributes": 2.0,
+                      "classes_average": null,
+                      "interfaces_average": 1.0,
+                      "total": 2.0,
+                      "total_attributes": 2.0,
+                      "average": 1.0
+                    }"###
+                );
+            },
+        );
+    }
 }
@@ -3,6 +3,7 @@ use serde::Serialize;
 use std::fmt;
 
 use crate::checker::Checker;
+use crate::getter::is_kotlin_public;
 use crate::langs::*;
 use crate::macros::implement_metric_trait;
 use crate::node::Node;
@@ -213,7 +214,8 @@ impl Npm for JavaCode {
         }
 
         match node.kind_id().into() {
-            ClassBody => {
+            // The methods of an enum follow its constants
+            ClassBody | EnumBodyDeclarations => {
                 stats.class_nm += node
                     .children()
                     .filter(|node| Self::is_func(node))
@@ -246,6 +248,50 @@ impl Npm for JavaCode {
     }
 }
 
+impl Npm for KotlinCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Kotlin::*;
+
+        // Enables the `Npm` metric if computing stats of a class space
+        if Self::is_func_space(node) && stats.is_disabled() {
+            stats.is_class_space = true;
+        }
+
+        // The methods of an enum follow its entries
+        if !matches!(node.kind_id().into(), ClassBody | EnumClassBody) {
+            return;
+        }
+        let mut methods = 0;
+        let mut public_methods = 0;
+        for member in node.children() {
+            if Self::is_func(&member) {
+                methods += 1;
+                public_methods += usize::from(is_kotlin_public(&member));
+            } else if member.kind_id() == PropertyDeclaration {
+                // The custom accessors of a property are methods,
+                // with the visibility of the property unless restricted
+                for accessor in member.children().filter(|accessor| Self::is_func(accessor)) {
+                    methods += 1;
+                    public_methods +=
+                        usize::from(is_kotlin_public(&member) && is_kotlin_public(&accessor));
+                }
+            }
+        }
+
+        // Members of an interface are public by default, as in a class
+        if node
+            .parent()
+            .is_some_and(|parent| parent.is_child(Interface as u16))
+        {
+            stats.interface_nm += methods;
+            stats.interface_npm += public_methods;
+        } else {
+            stats.class_nm += methods;
+            stats.class_npm += public_methods;
+        }
+    }
+}
+
 implement_metric_trait!(
     Npm,
     PythonCode,
@@ -254,10 +300,10 @@ implement_metric_trait!(
     TypescriptCode,
     TsxCode,
     RustCode,
+    CCode,
     CppCode,
     PreprocCode,
-    CcommentCode,
-    KotlinCode
+    CcommentCode
 );
 
 #[cfg(test)]
@@ -705,4 +751,83 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn kotlin_classes() {
+        check_metrics::<KotlinParser>(
+            "class X(val a: Int) {
+                fun a() {}              // +1
+                internal fun b() {}
+                private fun c() {}
+                var d: Int = 0
+                    get() = field       // +1
+                    private set
+                var e: Int = 0
+                    get() = field       // +1
+                    private set(value) { field = value }
+                constructor() : this(1) // +1
+                companion object {
+                    fun f() = X()       // +1
+                }
+            }
+            object Y {
+                protected fun g() {}
+                public fun h() {}       // +1
+            }
+            enum class Z {
+                A, B;
+                fun i() {}              // +1
+            }",
+            "foo.kt",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npm,
+                    @r###"
+                    {
+                      "classes": 7.0,
+                      "interfaces": 0.0,
+                      "class_methods": 11.0,
+                      "interface_methods": 0.0,
+                      "classes_average": 0.6363636363636364,
+                      "interfaces_average": null,
+                      "total": 7.0,
+                      "total_methods": 11.0,
+                      "average": 0.6363636363636364
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn kotlin_interface() {
+        check_metrics::<KotlinParser>(
+            "interface X {
+                fun a()                 // +1
+                fun b() = 1             // +1
+                private fun c() = 2
+            }
+            val y = object : X {
+                override fun a() {}     // +1
+            }",
+            "foo.kt",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npm,
+                    @r###"
+                    {
+                      "classes": 1.0,
+                      "interfaces": 2.0,
+                      "class_methods": 1.0,
+                      "interface_methods": 3.0,
+                      "classes_average": 1.0,
+                      "interfaces_average": 0.6666666666666666,
+                      "total": 3.0,
+                      "total_methods": 4.0,
+                      "average": 0.75
+                    }"###
+                );
+            },
+        );
+    }
 }
@@ -0,0 +1,305 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Types` metric.
+///
+/// This metric measures the complexity of the types written in a space,
+/// for the languages, such as `TypeScript`, whose type system allows to move
+/// some logic into the types.
+///
+/// It counts the conditional types, the `infer` declarations and the mapped
+/// types of a space, and it measures the width of its widest union and
+/// intersection types and the depth of its most nested generic type.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    conditional: usize,
+    infer: usize,
+    mapped: usize,
+    union_width: usize,
+    intersection_width: usize,
+    generic_depth: usize,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("types", 6)?;
+        st.serialize_field("conditional", &self.conditional())?;
+        st.serialize_field("infer", &self.infer())?;
+        st.serialize_field("mapped", &self.mapped())?;
+        st.serialize_field("union_width", &self.union_width())?;
+        st.serialize_field("intersection_width", &self.intersection_width())?;
+        st.serialize_field("generic_depth", &self.generic_depth())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "conditional: {}, infer: {}, mapped: {}, union_width: {}, intersection_width: {}, generic_depth: {}",
+            self.conditional(),
+            self.infer(),
+            self.mapped(),
+            self.union_width(),
+            self.intersection_width(),
+            self.generic_depth()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Types` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.conditional += other.conditional;
+        self.infer += other.infer;
+        self.mapped += other.mapped;
+        self.union_width = self.union_width.max(other.union_width);
+        self.intersection_width = self.intersection_width.max(other.intersection_width);
+        self.generic_depth = self.generic_depth.max(other.generic_depth);
+    }
+
+    /// Returns the number of conditional types, such as `T extends U ? X : Y`
+    pub fn conditional(&self) -> f64 {
+        self.conditional as f64
+    }
+
+    /// Returns the number of type variables declared by `infer`
+    pub fn infer(&self) -> f64 {
+        self.infer as f64
+    }
+
+    /// Returns the number of mapped types, such as `{ [K in keyof T]: X }`
+    pub fn mapped(&self) -> f64 {
+        self.mapped as f64
+    }
+
+    /// Returns the number of members of the widest union type
+    pub fn union_width(&self) -> f64 {
+        self.union_width as f64
+    }
+
+    /// Returns the number of members of the widest intersection type
+    pub fn intersection_width(&self) -> f64 {
+        self.intersection_width as f64
+    }
+
+    /// Returns the maximum depth of the nested type arguments
+    ///
+    /// The depth of `Map<string, Array<number>>` is 2.
+    pub fn generic_depth(&self) -> f64 {
+        self.generic_depth as f64
+    }
+
+    // Checks if the `Types` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.conditional == 0
+            && self.infer == 0
+            && self.mapped == 0
+            && self.union_width == 0
+            && self.intersection_width == 0
+            && self.generic_depth == 0
+    }
+}
+
+pub trait Types
+where
+    Self: Checker,
+{
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+// Counts the members of a union or an intersection type,
+// which are nested in the left operand when there are more than two of them.
+fn count_members(node: &Node, kind_id: u16) -> usize {
+    node.children()
+        .filter(|child| child.is_named())
+        .map(|child| {
+            if child.kind_id() == kind_id {
+                count_members(&child, kind_id)
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+macro_rules! ts_types {
+    ($code:ident, $language:ident) => {
+        impl Types for $code {
+            fn compute(node: &Node, stats: &mut Stats) {
+                use $language::*;
+
+                let kind_id = node.kind_id();
+                match kind_id.into() {
+                    ConditionalType => stats.conditional += 1,
+                    InferType => stats.infer += 1,
+                    MappedTypeClause => stats.mapped += 1,
+                    UnionType | IntersectionType
+                        if node
+                            .parent()
+                            .is_none_or(|parent| parent.kind_id() != kind_id) =>
+                    {
+                        let width = count_members(node, kind_id);
+                        if kind_id == UnionType {
+                            stats.union_width = stats.union_width.max(width);
+                        } else {
+                            stats.intersection_width = stats.intersection_width.max(width);
+                        }
+                    }
+                    TypeArguments => {
+                        let mut depth = 1;
+                        let mut parent = node.parent();
+                        while let Some(node) = parent {
+                            if Self::is_func_space(&node) {
+                                break;
+                            }
+                            if node.kind_id() == TypeArguments {
+                                depth += 1;
+                            }
+                            parent = node.parent();
+                        }
+                        stats.generic_depth = stats.generic_depth.max(depth);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+ts_types!(TypescriptCode, Typescript);
+ts_types!(TsxCode, Tsx);
+
+implement_metric_trait!(
+    Types,
+    PythonCode,
+    MozjsCode,
+    JavascriptCode,
+    RustCode,
+    CCode,
+    CppCode,
+    JavaCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics;
+
+    use super::*;
+
+    #[test]
+    fn typescript_conditional_types() {
+        check_metrics::<TypescriptParser>(
+            "type ElementType<T> = T extends Array<infer U> // conditional, infer
+                ? U
+                : T extends Promise<infer V> // conditional, infer
+                ? V
+                : never;",
+            "foo.ts",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.types,
+                    @r###"
+                    {
+                      "conditional": 2.0,
+                      "infer": 2.0,
+                      "mapped": 0.0,
+                      "union_width": 0.0,
+                      "intersection_width": 0.0,
+                      "generic_depth": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_mapped_types() {
+        check_metrics::<TypescriptParser>(
+            "type Getters<T> = {
+                [K in keyof T as `get${Capitalize<string & K>}`]: () => T[K]; // mapped
+            };
+            type Mutable<T> = { -readonly [K in keyof T]: T[K] }; // mapped",
+            "foo.ts",
+            |metric| {
+                // intersection width = 2 (`string & K`)
+                insta::assert_json_snapshot!(
+                    metric.types,
+                    @r###"
+                    {
+                      "conditional": 0.0,
+                      "infer": 0.0,
+                      "mapped": 2.0,
+                      "union_width": 0.0,
+                      "intersection_width": 2.0,
+                      "generic_depth": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_union_and_generic_types() {
+        check_metrics::<TypescriptParser>(
+            "function f(a: string | number | (boolean | null)): Map<string, Array<Set<number>>> {
+                let b: A & B & C;
+                return new Map();
+            }",
+            "foo.ts",
+            |metric| {
+                // union width = 3, intersection width = 3, generic depth = 3
+                insta::assert_json_snapshot!(
+                    metric.types,
+                    @r###"
+                    {
+                      "conditional": 0.0,
+                      "infer": 0.0,
+                      "mapped": 0.0,
+                      "union_width": 3.0,
+                      "intersection_width": 3.0,
+                      "generic_depth": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn tsx_types() {
+        check_metrics::<TsxParser>(
+            "type Props<T> = { [K in keyof T]?: T[K] | null }; // mapped
+            function C<T>(props: Props<T>): JSX.Element {
+                return <div />;
+            }",
+            "foo.tsx",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.types,
+                    @r###"
+                    {
+                      "conditional": 0.0,
+                      "infer": 0.0,
+                      "mapped": 1.0,
+                      "union_width": 2.0,
+                      "intersection_width": 0.0,
+                      "generic_depth": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::getter::Getter;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Typing` metric.
+///
+/// This metric measures the progress of the migration of a space
+/// to type annotations, for the languages, such as `Python`,
+/// whose type annotations are optional.
+///
+/// It counts the parameters and the return values of the functions of a space,
+/// its module-level and class attributes and how many of them are annotated,
+/// as well as the uses of `Any` in the annotations and the
+/// `# type: ignore` comments.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    parameters: usize,
+    annotated_parameters: usize,
+    returns: usize,
+    annotated_returns: usize,
+    attributes: usize,
+    annotated_attributes: usize,
+    any: usize,
+    type_ignores: usize,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("typing", 9)?;
+        st.serialize_field("parameters", &self.parameters())?;
+        st.serialize_field("annotated_parameters", &self.annotated_parameters())?;
+        st.serialize_field("returns", &self.returns())?;
+        st.serialize_field("annotated_returns", &self.annotated_returns())?;
+        st.serialize_field("attributes", &self.attributes())?;
+        st.serialize_field("annotated_attributes", &self.annotated_attributes())?;
+        st.serialize_field("any", &self.any())?;
+        st.serialize_field("type_ignores", &self.type_ignores())?;
+        st.serialize_field("coverage", &self.coverage())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "parameters: {}, annotated_parameters: {}, returns: {}, annotated_returns: {}, attributes: {}, annotated_attributes: {}, any: {}, type_ignores: {}, coverage: {}",
+            self.parameters(),
+            self.annotated_parameters(),
+            self.returns(),
+            self.annotated_returns(),
+            self.attributes(),
+            self.annotated_attributes(),
+            self.any(),
+            self.type_ignores(),
+            self.coverage()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Typing` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.parameters += other.parameters;
+        self.annotated_parameters += other.annotated_parameters;
+        self.returns += other.returns;
+        self.annotated_returns += other.annotated_returns;
+        self.attributes += other.attributes;
+        self.annotated_attributes += other.annotated_attributes;
+        self.any += other.any;
+        self.type_ignores += other.type_ignores;
+    }
+
+    /// Returns the number of parameters of the functions
+    ///
+    /// The `self` and `cls` parameters of the methods are not counted.
+    pub fn parameters(&self) -> f64 {
+        self.parameters as f64
+    }
+
+    /// Returns the number of annotated parameters, such as `a: int`
+    pub fn annotated_parameters(&self) -> f64 {
+        self.annotated_parameters as f64
+    }
+
+    /// Returns the number of return values, one for each function
+    pub fn returns(&self) -> f64 {
+        self.returns as f64
+    }
+
+    /// Returns the number of annotated return values, such as `-> int`
+    pub fn annotated_returns(&self) -> f64 {
+        self.annotated_returns as f64
+    }
+
+    /// Returns the number of module-level and class attributes
+    pub fn attributes(&self) -> f64 {
+        self.attributes as f64
+    }
+
+    /// Returns the number of annotated module-level and class attributes,
+    /// such as `a: int = 1`
+    pub fn annotated_attributes(&self) -> f64 {
+        self.annotated_attributes as f64
+    }
+
+    /// Returns the number of uses of `Any` in the annotations
+    pub fn any(&self) -> f64 {
+        self.any as f64
+    }
+
+    /// Returns the number of `# type: ignore` comments
+    pub fn type_ignores(&self) -> f64 {
+        self.type_ignores as f64
+    }
+
+    /// Returns the ratio of annotated parameters, return values
+    /// and attributes
+    pub fn coverage(&self) -> f64 {
+        (self.annotated_parameters + self.annotated_returns + self.annotated_attributes) as f64
+            / (self.parameters + self.returns + self.attributes) as f64
+    }
+
+    // Checks if the `Typing` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.parameters == 0 && self.returns == 0 && self.attributes == 0 && self.type_ignores == 0
+    }
+}
+
+pub trait Typing
+where
+    Self: Checker + Getter,
+{
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+// Checks whether a comment is a `# type: ignore` comment,
+// which can follow another comment, such as `# noqa  # type: ignore[attr]`.
+fn is_type_ignore(comment: &str) -> bool {
+    comment.split('#').any(|part| {
+        part.trim_start()
+            .strip_prefix("type:")
+            .is_some_and(|rest| rest.trim_start().starts_with("ignore"))
+    })
+}
+
+impl Typing for PythonCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Python::*;
+
+        // Checks whether a statement is in the body of a class
+        let in_class = |node: &Node| {
+            node.parent()
+                .filter(|parent| matches!(parent.kind_id().into(), Block | Block2))
+                .and_then(|parent| parent.parent())
+                .is_some_and(|parent| parent.kind_id() == ClassDefinition)
+        };
+
+        match node.kind_id().into() {
+            FunctionDefinition => {
+                stats.returns += 1;
+                if node.child_by_field_name("return_type").is_some() {
+                    stats.annotated_returns += 1;
+                }
+
+                // The first parameter of a method, `self` or `cls`, is not
+                // annotated, unless the method is static
+                let definition = node
+                    .parent()
+                    .filter(|parent| parent.kind_id() == DecoratedDefinition)
+                    .unwrap_or(*node);
+                let skip = usize::from(
+                    in_class(&definition)
+                        && !Self::get_decorators(node, code)
+                            .iter()
+                            .any(|decorator| decorator == "staticmethod"),
+                );
+                if let Some(parameters) = node.child_by_field_name("parameters") {
+                    for parameter in parameters
+                        .children()
+                        .filter(|parameter| {
+                            parameter.is_named()
+                                && !matches!(
+                                    parameter.kind_id().into(),
+                                    KeywordSeparator | PositionalSeparator | Comment
+                                )
+                        })
+                        .skip(skip)
+                    {
+                        stats.parameters += 1;
+                        if matches!(
+                            parameter.kind_id().into(),
+                            TypedParameter | TypedDefaultParameter
+                        ) {
+                            stats.annotated_parameters += 1;
+                        }
+                    }
+                }
+            }
+            // `a = 1` and `a: int = 1` at module-level or in the body of a class
+            Assignment
+                if node
+                    .parent()
+                    .filter(|parent| parent.kind_id() == ExpressionStatement)
+                    .is_some_and(|statement| {
+                        statement
+                            .parent()
+                            .is_some_and(|parent| parent.kind_id() == Module)
+                            || in_class(&statement)
+                    }) =>
+            {
+                stats.attributes += 1;
+                if node.child_by_field_name("type").is_some() {
+                    stats.annotated_attributes += 1;
+                }
+            }
+            // `Any` and `typing.Any`
+            Identifier if node.utf8_text(code) == Some("Any") => {
+                let mut parent = node.parent();
+                while let Some(ancestor) = parent {
+                    if ancestor.kind_id() == Type {
+                        stats.any += 1;
+                        break;
+                    }
+                    parent = ancestor.parent();
+                }
+            }
+            Comment if node.utf8_text(code).is_some_and(is_type_ignore) => {
+                stats.type_ignores += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+implement_metric_trait!(
+    Typing,
+    MozjsCode,
+    JavascriptCode,
+    TypescriptCode,
+    TsxCode,
+    RustCode,
+    CCode,
+    CppCode,
+    JavaCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics;
+
+    use super::*;
+
+    #[test]
+    fn python_functions() {
+        check_metrics::<PythonParser>(
+            "def f(a: int, b, *args: str, c: float = 1.0, **kwargs) -> int: # 3/5 parameters, 1/1 return
+                 return a
+             def g(a, /, b=1, *, c: Any = None): # 1/3 parameters, 0/1 return, 1 any
+                 x: int = 1 # not an attribute
+                 return x # type: ignore[return-value]",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.typing,
+                    @r###"
+                    {
+                      "parameters": 8.0,
+                      "annotated_parameters": 4.0,
+                      "returns": 2.0,
+                      "annotated_returns": 1.0,
+                      "attributes": 0.0,
+                      "annotated_attributes": 0.0,
+                      "any": 1.0,
+                      "type_ignores": 1.0,
+                      "coverage": 0.5
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_classes() {
+        check_metrics::<PythonParser>(
+            "VERSION: Final = 1 # annotated attribute
+             handlers = {} # attribute
+             class A:
+                 x: int # annotated attribute
+                 y = 2 # attribute
+                 def __init__(self, x: int) -> None: # 1/1 parameters, 1/1 return
+                     self.x = x
+                 @classmethod
+                 def create(cls, value: typing.Any) -> 'A': # 1/1 parameters, 1/1 return, 1 any
+                     return cls(value)  # noqa  # type: ignore
+                 @staticmethod
+                 def check(value) -> bool: # 0/1 parameters, 1/1 return
+                     return True",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.typing,
+                    @r###"
+                    {
+                      "parameters": 3.0,
+                      "annotated_parameters": 2.0,
+                      "returns": 3.0,
+                      "annotated_returns": 3.0,
+                      "attributes": 4.0,
+                      "annotated_attributes": 2.0,
+                      "any": 1.0,
+                      "type_ignores": 1.0,
+                      "coverage": 0.7
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -6,11 +6,6 @@ use crate::checker::Checker;
 use crate::macros::implement_metric_trait;
 use crate::*;
 
-// FIX ME: New Java switches are not correctly recognised by tree-sitter-java version 0.19.0
-// However, the issue has already been addressed and resolved upstream on the tree-sitter-java GitHub repository
-// Upstream issue: https://github.com/tree-sitter/tree-sitter-java/issues/69
-// Upstream PR which resolves the issue: https://github.com/tree-sitter/tree-sitter-java/pull/78
-
 /// The `Wmc` metric.
 ///
 /// This metric sums the cyclomatic complexities of all the methods defined in a class.
@@ -21,6 +16,7 @@ use crate::*;
 #[derive(Debug, Clone, Default)]
 pub struct Stats {
     cyclomatic: f64,
+    methods_cyclomatic: f64,
     class_wmc: f64,
     interface_wmc: f64,
     class_wmc_sum: f64,
@@ -56,22 +52,21 @@ impl fmt::Display for Stats {
 impl Stats {
     /// Merges a second `Wmc` metric into the first one
     pub fn merge(&mut self, other: &Stats) {
-        use SpaceKind::*;
-
-        // Merges the cyclomatic complexity of a method
-        // into the `Wmc` metric value of a class or interface
-        if let Function = other.space_kind {
-            match self.space_kind {
-                Class => self.class_wmc += other.cyclomatic,
-                Interface => self.interface_wmc += other.cyclomatic,
-                _ => {}
-            }
-        }
+        self.merge_method(other);
 
         self.class_wmc_sum += other.class_wmc_sum;
         self.interface_wmc_sum += other.interface_wmc_sum;
     }
 
+    // Merges the cyclomatic complexity of a method, which becomes part
+    // of the `Wmc` metric value of a space once its kind is known
+    #[inline(always)]
+    pub(crate) fn merge_method(&mut self, other: &Stats) {
+        if other.space_kind == SpaceKind::Function {
+            self.methods_cyclomatic += other.cyclomatic;
+        }
+    }
+
     /// Returns the `Wmc` metric value of the classes in a space.
     #[inline(always)]
     pub fn class_wmc(&self) -> f64 {
@@ -106,6 +101,15 @@ impl Stats {
     // of classes and interfaces into the sums
     #[inline(always)]
     pub(crate) fn compute_sum(&mut self) {
+        use SpaceKind::*;
+
+        // The cyclomatic complexities of the methods are the `Wmc`
+        // metric value of a class or interface, where an enum is a class
+        match self.space_kind {
+            Class | Enum => self.class_wmc = self.methods_cyclomatic,
+            Interface => self.interface_wmc = self.methods_cyclomatic,
+            _ => {}
+        }
         self.class_wmc_sum += self.class_wmc;
         self.interface_wmc_sum += self.interface_wmc;
     }
@@ -124,22 +128,35 @@ where
     fn compute(space_kind: SpaceKind, cyclomatic: &cyclomatic::Stats, stats: &mut Stats);
 }
 
-impl Wmc for JavaCode {
-    fn compute(space_kind: SpaceKind, cyclomatic: &cyclomatic::Stats, stats: &mut Stats) {
-        use SpaceKind::*;
-
-        if let Unit | Class | Interface | Function = space_kind {
-            if stats.space_kind == Unknown {
-                stats.space_kind = space_kind;
-            }
-            if space_kind == Function {
-                // Saves the cyclomatic complexity of the method
-                stats.cyclomatic = cyclomatic.cyclomatic_sum();
+// The languages where the methods are defined in classes, enums and interfaces
+macro_rules! class_wmc {
+    ($($code:ident),+) => {
+        $(
+            impl Wmc for $code {
+                fn compute(
+                    space_kind: SpaceKind,
+                    cyclomatic: &cyclomatic::Stats,
+                    stats: &mut Stats,
+                ) {
+                    use SpaceKind::*;
+
+                    if let Unit | Class | Enum | Interface | Function = space_kind {
+                        if stats.space_kind == Unknown {
+                            stats.space_kind = space_kind;
+                        }
+                        if space_kind == Function {
+                            // Saves the cyclomatic complexity of the method
+                            stats.cyclomatic = cyclomatic.cyclomatic_sum();
+                        }
+                    }
+                }
             }
-        }
-    }
+        )+
+    };
 }
 
+class_wmc!(JavaCode, KotlinCode);
+
 implement_metric_trait!(
     Wmc,
     PythonCode,
@@ -148,10 +165,10 @@ implement_metric_trait!(
     TypescriptCode,
     TsxCode,
     RustCode,
+    CCode,
     CppCode,
     PreprocCode,
-    CcommentCode,
-    KotlinCode
+    CcommentCode
 );
 
 #[cfg(test)]
@@ -399,9 +416,9 @@ mod tests {
             "abstract class AbstractClass { // wmc = 1
                 abstract void m1(); // +1
             }
-            public class TopLevelClass{ // wmc = 3
+            public class TopLevelClass{ // wmc = 4
                 public void m(){ // +1
-                    AbstractClass ac1 = new AbstractClass() {
+                    AbstractClass ac1 = new AbstractClass() { // +1, wmc = 2
                         void m1() { // +1
                             for (int i = 0; i < 5; i++) { // +1
                                 System.out.println(\"Test 1: \" + i);
@@ -413,14 +430,14 @@ mod tests {
             }",
             "foo.java",
             |metric| {
-                // 2 classes (1 + 3)
+                // 3 classes (1 + 4 + 2)
                 insta::assert_json_snapshot!(
                     metric.wmc,
                     @r###"
                     {
-                      "classes": 4.0,
+                      "classes": 7.0,
                       "interfaces": 0.0,
-                      "total": 4.0
+                      "total": 7.0
                     }"###
                 );
             },
@@ -434,17 +451,17 @@ mod tests {
                 abstract void m1(); // +1
                 abstract void m2(); // +1
             }
-            public class TopLevelClass{ // wmc = 6
+            public class TopLevelClass{ // wmc = 8
                 public void m(){ // +1
 
-                    AbstractClass ac1 = new AbstractClass() {
+                    AbstractClass ac1 = new AbstractClass() { // +1, wmc = 6
                         void m1() { // +1
                             for (int i = 0; i < 5; i++) { // +1
                                 System.out.println(\"Test 1: \" + i);
                             }
                         }
                         void m2() { // +1
-                            AbstractClass ac2 = new AbstractClass() {
+                            AbstractClass ac2 = new AbstractClass() { // +1, wmc = 2
                                 void m1() { // +1
                                     System.out.println(\"Test A\");
                                 }
@@ -461,14 +478,14 @@ mod tests {
             }",
             "foo.java",
             |metric| {
-                // 2 classes (2 + 6)
+                // 4 classes (2 + 8 + 6 + 2)
                 insta::assert_json_snapshot!(
                     metric.wmc,
                     @r###"
                     {
-                      "classes": 8.0,
+                      "classes": 18.0,
                       "interfaces": 0.0,
-                      "total": 8.0
+                      "total": 18.0
                     }"###
                 );
             },
@@ -669,4 +686,38 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn kotlin_class_and_interface() {
+        check_metrics::<KotlinParser>(
+            "interface X { // wmc = 3
+                fun a(): Int // +1
+                fun b(x: Int) = if (x > 0) 1 else 2 // +2
+            }
+            class Y : X { // wmc = 8
+                var c: Int = 0
+                    get() = field // +1
+                    set(value) { field = value ?: 0 } // +2
+                override fun a() = 1 // +1
+                fun d(x: Int) = when (x) { // +4
+                    1 -> true
+                    2 -> false
+                    else -> x > 0 && x < 10
+                }
+            }",
+            "foo.kt",
+            |metric| {
+                // 1 class 1 interface
+                insta::assert_json_snapshot!(
+                    metric.wmc,
+                    @r###"
+                    {
+                      "classes": 8.0,
+                      "interfaces": 3.0,
+                      "total": 11.0
+                    }"###
+                );
+            },
+        );
+    }
 }
@@ -34,6 +34,16 @@ impl<'a> Node<'a> {
         self.0.has_error()
     }
 
+    // Checks if a node is a syntax error or a node inserted by the parser
+    // to recover from a syntax error.
+    pub(crate) fn is_error(&self) -> bool {
+        self.0.is_error() || self.0.is_missing()
+    }
+
+    pub(crate) fn is_named(&self) -> bool {
+        self.0.is_named()
+    }
+
     pub(crate) fn id(&self) -> usize {
         self.0.id()
     }
@@ -46,7 +56,7 @@ impl<'a> Node<'a> {
         self.0.kind_id()
     }
 
-    pub(crate) fn utf8_text(&self, data: &'a [u8]) -> Option<&'a str> {
+    pub(crate) fn utf8_text<'b>(&self, data: &'b [u8]) -> Option<&'b str> {
         self.0.utf8_text(data).ok()
     }
 
@@ -108,7 +118,7 @@ impl<'a> Node<'a> {
         self.0.child_count()
     }
 
-    pub(crate) fn child_by_field_name(&self, name: &str) -> Option<Node> {
+    pub(crate) fn child_by_field_name(&self, name: &str) -> Option<Node<'a>> {
         self.0.child_by_field_name(name).map(Node)
     }
 
@@ -257,6 +267,34 @@ impl<'a> Search<'a> for Node<'a> {
         }
     }
 
+    // Like `act_on_node`, but the children of a node are visited
+    // only when the action returns `true` for it
+    fn act_on_node_pruned(&self, action: &mut dyn FnMut(&Node<'a>) -> bool) {
+        let mut cursor = self.cursor();
+        let mut stack = Vec::new();
+        let mut children = Vec::new();
+
+        stack.push(*self);
+
+        while let Some(node) = stack.pop() {
+            if !action(&node) {
+                continue;
+            }
+            cursor.reset(&node);
+            if cursor.goto_first_child() {
+                loop {
+                    children.push(cursor.node());
+                    if !cursor.goto_next_sibling() {
+                        break;
+                    }
+                }
+                for child in children.drain(..).rev() {
+                    stack.push(child);
+                }
+            }
+        }
+    }
+
     fn first_child(&self, pred: fn(u16) -> bool) -> Option<Node<'a>> {
         self.children().find(|&child| pred(child.kind_id()))
     }
@@ -6,7 +6,7 @@ use serde::Serialize;
 use crate::checker::Checker;
 use crate::getter::Getter;
 use crate::node::Node;
-use crate::spaces::SpaceKind;
+use crate::spaces::{get_header_and_body, get_qualified_name, get_stable_id, CodeSpan, SpaceKind};
 
 use crate::halstead::{Halstead, HalsteadMaps};
 
@@ -21,10 +21,33 @@ pub struct Ops {
     /// If `None`, an error is occurred in parsing
     /// the name of a function space.
     pub name: Option<String>,
+    /// The qualified name of a function space.
+    ///
+    /// If `None`, a function space has no qualified name.
+    pub qualified_name: Option<String>,
+    /// The stable identifier of a function space,
+    /// derived from its qualified name.
+    pub id: Option<String>,
     /// The first line of a function space.
     pub start_line: usize,
     /// The last line of a function space.
     pub end_line: usize,
+    /// The first column of a function space.
+    pub start_column: usize,
+    /// The column following the end of a function space.
+    pub end_column: usize,
+    /// The first byte of a function space.
+    pub start_byte: usize,
+    /// The byte following the end of a function space.
+    pub end_byte: usize,
+    /// The span of the header of a function space.
+    ///
+    /// If `None`, a function space has no body.
+    pub header: Option<CodeSpan>,
+    /// The span of the body of a function space.
+    ///
+    /// If `None`, a function space has no body.
+    pub body: Option<CodeSpan>,
     /// The space kind.
     pub kind: SpaceKind,
     /// All subspaces contained in a function space.
@@ -36,7 +59,7 @@ pub struct Ops {
 }
 
 impl Ops {
-    fn new<T: Getter>(node: &Node, code: &[u8], kind: SpaceKind) -> Self {
+    fn new<T: ParserTrait>(node: &Node, code: &[u8], kind: SpaceKind) -> Self {
         let (start_position, end_position) = match kind {
             SpaceKind::Unit => {
                 if node.child_count() == 0 {
@@ -47,12 +70,23 @@ impl Ops {
             }
             _ => (node.start_row() + 1, node.end_row() + 1),
         };
+        let span = CodeSpan::new(node, node);
+        let (header, body) = get_header_and_body::<T>(node);
+        let qualified_name = get_qualified_name::<T>(node, code);
         Self {
-            name: T::get_func_space_name(node, code).map(|name| name.to_string()),
+            name: T::Getter::get_func_space_name(node, code).map(|name| name.to_string()),
+            id: qualified_name.as_deref().map(get_stable_id),
+            qualified_name,
             spaces: Vec::new(),
             kind,
             start_line: start_position,
             end_line: end_position,
+            start_column: span.start_column,
+            end_column: span.end_column,
+            start_byte: span.start_byte,
+            end_byte: span.end_byte,
+            header,
+            body,
             operators: Vec::new(),
             operands: Vec::new(),
         }
@@ -172,13 +206,13 @@ pub fn operands_and_operators<'a, T: ParserTrait>(parser: &'a T, path: &'a Path)
             last_level = level;
         }
 
-        let kind = T::Getter::get_space_kind(&node);
+        let kind = T::Getter::get_space_kind(&node, code);
 
         let func_space = T::Checker::is_func(&node) || T::Checker::is_func_space(&node);
 
         let new_level = if func_space {
             let state = State {
-                ops: Ops::new::<T::Getter>(&node, code, kind),
+                ops: Ops::new::<T>(&node, code, kind),
                 halstead_maps: HalsteadMaps::new(),
                 primitive_types: HashSet::new(),
             };
@@ -2,16 +2,25 @@ use std::io::Write;
 use termcolor::{Color, ColorChoice, StandardStream, StandardStreamLock};
 
 use crate::abc;
+use crate::cbo;
 use crate::cognitive;
+use crate::crap;
 use crate::cyclomatic;
+use crate::docs;
 use crate::exit;
 use crate::halstead;
+use crate::inheritance;
+use crate::jsx;
+use crate::lcom;
 use crate::loc;
 use crate::mi;
 use crate::nargs;
+use crate::nesting;
 use crate::nom;
 use crate::npa;
 use crate::npm;
+use crate::types;
+use crate::typing;
 use crate::wmc;
 
 use crate::spaces::{CodeMetrics, FuncSpace};
@@ -105,6 +114,7 @@ fn dump_metrics(
     let prefix = format!("{prefix}{pref_child}");
     dump_cognitive(&metrics.cognitive, &prefix, false, stdout)?;
     dump_cyclomatic(&metrics.cyclomatic, &prefix, false, stdout)?;
+    dump_nesting(&metrics.nesting, &prefix, false, stdout)?;
     dump_nargs(&metrics.nargs, &prefix, false, stdout)?;
     dump_nexits(&metrics.nexits, &prefix, false, stdout)?;
     dump_halstead(&metrics.halstead, &prefix, false, stdout)?;
@@ -114,7 +124,15 @@ fn dump_metrics(
     dump_abc(&metrics.abc, &prefix, false, stdout)?;
     dump_wmc(&metrics.wmc, &prefix, false, stdout)?;
     dump_npm(&metrics.npm, &prefix, false, stdout)?;
-    dump_npa(&metrics.npa, &prefix, true, stdout)
+    dump_npa(&metrics.npa, &prefix, false, stdout)?;
+    dump_cbo(&metrics.cbo, &prefix, false, stdout)?;
+    dump_lcom(&metrics.lcom, &prefix, false, stdout)?;
+    dump_inheritance(&metrics.inheritance, &prefix, false, stdout)?;
+    dump_docs(&metrics.docs, &prefix, false, stdout)?;
+    dump_types(&metrics.types, &prefix, false, stdout)?;
+    dump_jsx(&metrics.jsx, &prefix, false, stdout)?;
+    dump_typing(&metrics.typing, &prefix, false, stdout)?;
+    dump_crap(&metrics.crap, &prefix, true, stdout)
 }
 
 fn dump_cognitive(
@@ -157,6 +175,36 @@ fn dump_cyclomatic(
     dump_value("average", stats.cyclomatic_average(), &prefix, true, stdout)
 }
 
+fn dump_nesting(
+    stats: &nesting::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "nesting")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+
+    dump_value("sum", stats.nesting_sum(), &prefix, false, stdout)?;
+    dump_value("average", stats.nesting_average(), &prefix, false, stdout)?;
+    dump_value("min", stats.nesting_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.nesting_max(), &prefix, false, stdout)?;
+    dump_value("blocks", stats.blocks(), &prefix, false, stdout)?;
+    dump_value(
+        "average_depth",
+        stats.average_depth(),
+        &prefix,
+        true,
+        stdout,
+    )
+}
+
 fn dump_halstead(
     stats: &halstead::Stats,
     prefix: &str,
@@ -423,6 +471,312 @@ fn dump_npa(
     dump_value("average", stats.total_cda(), &prefix, true, stdout)
 }
 
+fn dump_cbo(
+    stats: &cbo::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "cbo")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("classes", stats.classes_sum(), &prefix, false, stdout)?;
+    dump_value("sum", stats.cbo_sum(), &prefix, false, stdout)?;
+    dump_value("average", stats.cbo_average(), &prefix, false, stdout)?;
+    dump_value("min", stats.cbo_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.cbo_max(), &prefix, true, stdout)
+}
+
+fn dump_lcom(
+    stats: &lcom::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "lcom")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("classes", stats.classes_sum(), &prefix, false, stdout)?;
+    dump_value("sum", stats.lcom_sum(), &prefix, false, stdout)?;
+    dump_value("average", stats.lcom_average(), &prefix, false, stdout)?;
+    dump_value("min", stats.lcom_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.lcom_max(), &prefix, true, stdout)
+}
+
+fn dump_inheritance(
+    stats: &inheritance::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "inheritance")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("classes", stats.classes_sum(), &prefix, false, stdout)?;
+    dump_value("dit_sum", stats.dit_sum(), &prefix, false, stdout)?;
+    dump_value("dit_average", stats.dit_average(), &prefix, false, stdout)?;
+    dump_value("dit_max", stats.dit_max(), &prefix, false, stdout)?;
+    dump_value("noc_sum", stats.noc_sum(), &prefix, false, stdout)?;
+    dump_value("noc_average", stats.noc_average(), &prefix, false, stdout)?;
+    dump_value("noc_max", stats.noc_max(), &prefix, true, stdout)
+}
+
+fn dump_docs(
+    stats: &docs::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "docs")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    let items = stats.undocumented_items();
+    dump_value("public", stats.public(), &prefix, false, stdout)?;
+    dump_value("documented", stats.documented(), &prefix, false, stdout)?;
+    dump_value("undocumented", stats.undocumented(), &prefix, false, stdout)?;
+    dump_value(
+        "coverage",
+        stats.coverage(),
+        &prefix,
+        items.is_empty(),
+        stdout,
+    )?;
+
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}`- ")?;
+
+    intense_color(stdout, Color::Magenta)?;
+    writeln!(stdout, "undocumented_items")?;
+
+    let prefix = format!("{prefix}   ");
+    for (i, item) in items.iter().enumerate() {
+        let pref = if i == items.len() - 1 { "`- " } else { "|- " };
+
+        color(stdout, Color::Blue)?;
+        write!(stdout, "{prefix}{pref}")?;
+
+        color(stdout, Color::White)?;
+        writeln!(stdout, "{} (@{})", item.name, item.start_line)?;
+    }
+
+    Ok(())
+}
+
+fn dump_types(
+    stats: &types::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "types")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+
+    dump_value("conditional", stats.conditional(), &prefix, false, stdout)?;
+    dump_value("infer", stats.infer(), &prefix, false, stdout)?;
+    dump_value("mapped", stats.mapped(), &prefix, false, stdout)?;
+    dump_value("union_width", stats.union_width(), &prefix, false, stdout)?;
+    dump_value(
+        "intersection_width",
+        stats.intersection_width(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value(
+        "generic_depth",
+        stats.generic_depth(),
+        &prefix,
+        true,
+        stdout,
+    )
+}
+
+fn dump_jsx(
+    stats: &jsx::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "jsx")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+
+    dump_value("elements", stats.elements(), &prefix, false, stdout)?;
+    dump_value("depth", stats.depth(), &prefix, false, stdout)?;
+    dump_value("branches", stats.branches(), &prefix, false, stdout)?;
+    dump_value("handlers", stats.handlers(), &prefix, false, stdout)?;
+    dump_value("props", stats.props(), &prefix, true, stdout)
+}
+
+fn dump_typing(
+    stats: &typing::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "typing")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+
+    dump_value("parameters", stats.parameters(), &prefix, false, stdout)?;
+    dump_value(
+        "annotated_parameters",
+        stats.annotated_parameters(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("returns", stats.returns(), &prefix, false, stdout)?;
+    dump_value(
+        "annotated_returns",
+        stats.annotated_returns(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("attributes", stats.attributes(), &prefix, false, stdout)?;
+    dump_value(
+        "annotated_attributes",
+        stats.annotated_attributes(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("any", stats.any(), &prefix, false, stdout)?;
+    dump_value("type_ignores", stats.type_ignores(), &prefix, false, stdout)?;
+    dump_value("coverage", stats.coverage(), &prefix, true, stdout)
+}
+
+fn dump_crap(
+    stats: &crap::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "crap")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("lines", stats.lines(), &prefix, false, stdout)?;
+    dump_value(
+        "covered_lines",
+        stats.covered_lines(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value(
+        "line_coverage",
+        stats.line_coverage(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("branches", stats.branches(), &prefix, false, stdout)?;
+    dump_value(
+        "covered_branches",
+        stats.covered_branches(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value(
+        "branch_coverage",
+        stats.branch_coverage(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("crap", stats.crap(), &prefix, false, stdout)?;
+    dump_value("sum", stats.crap_sum(), &prefix, false, stdout)?;
+    dump_value("average", stats.crap_average(), &prefix, false, stdout)?;
+    dump_value("max", stats.crap_max(), &prefix, true, stdout)
+}
+
 fn dump_value(
     name: &str,
     val: f64,
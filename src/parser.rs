@@ -3,17 +3,24 @@ use std::path::Path;
 use std::sync::Arc;
 
 use crate::abc::Abc;
+use crate::cbo::Cbo;
 use crate::checker::Checker;
 use crate::cognitive::Cognitive;
 use crate::cyclomatic::Cyclomatic;
+use crate::docs::Docs;
 use crate::exit::Exit;
 use crate::halstead::Halstead;
+use crate::jsx::Jsx;
+use crate::lcom::Lcom;
 use crate::loc::Loc;
 use crate::mi::Mi;
 use crate::nargs::NArgs;
+use crate::nesting::Nesting;
 use crate::nom::Nom;
 use crate::npa::Npa;
 use crate::npm::Npm;
+use crate::types::Types;
+use crate::typing::Typing;
 use crate::wmc::Wmc;
 
 use crate::alterator::Alterator;
@@ -32,16 +39,23 @@ pub struct Parser<
         + Checker
         + Getter
         + Abc
+        + Cbo
         + Cognitive
         + Cyclomatic
+        + Docs
         + Exit
         + Halstead
+        + Jsx
+        + Lcom
         + Loc
         + Mi
         + NArgs
+        + Nesting
         + Nom
         + Npa
         + Npm
+        + Types
+        + Typing
         + Wmc,
 > {
     code: Vec<u8>,
@@ -83,7 +97,7 @@ fn get_fake_code<T: LanguageInfo>(
 ) -> Option<Vec<u8>> {
     if let Some(pr) = pr {
         match T::get_lang() {
-            LANG::Cpp => {
+            LANG::C | LANG::Cpp => {
                 let macros = get_macros(path, &pr.files);
                 c_macro::replace(code, &macros)
             }
@@ -101,16 +115,23 @@ impl<
             + Checker
             + Getter
             + Abc
+            + Cbo
             + Cognitive
             + Cyclomatic
+            + Docs
             + Exit
             + Halstead
+            + Jsx
+            + Lcom
             + Loc
             + Mi
             + NArgs
+            + Nesting
             + Nom
             + Npa
             + Npm
+            + Types
+            + Typing
             + Wmc,
     > ParserTrait for Parser<T>
 {
@@ -123,11 +144,18 @@ impl<
     type Nom = T;
     type Mi = T;
     type NArgs = T;
+    type Nesting = T;
     type Exit = T;
     type Wmc = T;
     type Abc = T;
     type Npm = T;
     type Npa = T;
+    type Cbo = T;
+    type Lcom = T;
+    type Docs = T;
+    type Types = T;
+    type Jsx = T;
+    type Typing = T;
 
     fn new(code: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>) -> Self {
         let fake_code = get_fake_code::<T>(&code, path, pr);
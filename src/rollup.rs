@@ -0,0 +1,102 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::distribution::Distribution;
+use crate::spaces::FuncSpace;
+
+/// The distributions of the metrics of the functions contained
+/// in the files of a project.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Rollup {
+    /// The number of files
+    pub files: usize,
+    /// The number of functions
+    pub functions: usize,
+    /// The distribution of the `Cyclomatic` metric
+    pub cyclomatic: Distribution,
+    /// The distribution of the `Cognitive Complexity` metric
+    pub cognitive: Distribution,
+    /// The distribution of the `Sloc` metric
+    pub sloc: Distribution,
+    /// The distribution of the `NArgs` metric
+    pub nargs: Distribution,
+    /// The distribution of the `NExit` metric
+    pub nexits: Distribution,
+}
+
+impl Rollup {
+    /// Adds the functions contained in the function space of a file.
+    pub fn add(&mut self, space: &FuncSpace) {
+        let metrics = &space.metrics;
+        self.files += 1;
+        self.cyclomatic
+            .merge(metrics.cyclomatic.cyclomatic_distribution());
+        self.cognitive
+            .merge(metrics.cognitive.cognitive_distribution());
+        self.sloc.merge(metrics.loc.sloc_distribution());
+        self.nargs.merge(metrics.nargs.nargs_distribution());
+        self.nexits.merge(metrics.nexits.exit_distribution());
+        self.functions = self.cyclomatic.count();
+    }
+}
+
+impl fmt::Display for Rollup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "files: {}, functions: {}", self.files, self.functions)?;
+        writeln!(f, "cyclomatic: {}", self.cyclomatic)?;
+        writeln!(f, "cognitive: {}", self.cognitive)?;
+        writeln!(f, "sloc: {}", self.sloc)?;
+        writeln!(f, "nargs: {}", self.nargs)?;
+        writeln!(f, "nexits: {}", self.nexits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{metrics, ParserTrait, PythonParser};
+
+    use super::*;
+
+    fn space(source: &str) -> FuncSpace {
+        let path = Path::new("foo.py");
+        let parser = PythonParser::new(source.as_bytes().to_vec(), path, None);
+        metrics(&parser, path).unwrap()
+    }
+
+    #[test]
+    fn python_rollup() {
+        let mut rollup = Rollup::default();
+        rollup.add(&space(
+            "def f(a, b):
+    if a:
+        return 1
+    return 2
+
+class A:
+    def g(self):
+        pass
+",
+        ));
+        rollup.add(&space("def h():\n    pass\n"));
+        rollup.add(&space("x = 1\n"));
+
+        assert_eq!(rollup.files, 3);
+        assert_eq!(rollup.functions, 3);
+        assert_eq!(rollup.cyclomatic.median(), 1.);
+        assert_eq!(rollup.cyclomatic.p95(), 1.9);
+        assert_eq!(rollup.nargs.p90(), 1.8);
+        assert_eq!(rollup.nexits.median(), 0.);
+        assert_eq!(rollup.sloc.median(), 2.);
+        let buckets: Vec<_> = rollup
+            .sloc
+            .histogram()
+            .buckets
+            .iter()
+            .map(|bucket| (bucket.start, bucket.count))
+            .collect();
+        assert_eq!(buckets, [(2., 2), (4., 1)]);
+    }
+}
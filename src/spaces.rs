@@ -2,23 +2,36 @@ use std::collections::HashMap;
 
 use serde::Serialize;
 use std::fmt;
+use std::hash::Hasher;
 use std::path::{Path, PathBuf};
 
 use crate::checker::Checker;
+use crate::commented_code::commented_code;
+use crate::function::Template;
 use crate::node::Node;
+use crate::tools::Fnv1aHasher;
 
 use crate::abc::{self, Abc};
+use crate::cbo::{self, Cbo};
 use crate::cognitive::{self, Cognitive};
+use crate::crap;
 use crate::cyclomatic::{self, Cyclomatic};
+use crate::docs::{self, Docs};
 use crate::exit::{self, Exit};
 use crate::getter::Getter;
 use crate::halstead::{self, Halstead, HalsteadMaps};
+use crate::inheritance;
+use crate::jsx::{self, Jsx};
+use crate::lcom::{self, Lcom};
 use crate::loc::{self, Loc};
 use crate::mi::{self, Mi};
 use crate::nargs::{self, NArgs};
+use crate::nesting::{self, Nesting};
 use crate::nom::{self, Nom};
 use crate::npa::{self, Npa};
 use crate::npm::{self, Npm};
+use crate::types::{self, Types};
+use crate::typing::{self, Typing};
 use crate::wmc::{self, Wmc};
 
 use crate::dump_metrics::*;
@@ -37,16 +50,22 @@ pub enum SpaceKind {
     Class,
     /// A struct space
     Struct,
+    /// An enum space
+    Enum,
     /// A `Rust` trait space
     Trait,
     /// A `Rust` implementation space
     Impl,
     /// A general space
     Unit,
-    /// A `C/C++` namespace
+    /// A `C/C++` namespace, a `Rust` module or a `TypeScript` namespace
     Namespace,
     /// An interface
     Interface,
+    /// A `TypeScript` type alias
+    TypeAlias,
+    /// A `React` function component
+    Component,
 }
 
 impl fmt::Display for SpaceKind {
@@ -56,11 +75,14 @@ impl fmt::Display for SpaceKind {
             SpaceKind::Function => "function",
             SpaceKind::Class => "class",
             SpaceKind::Struct => "struct",
+            SpaceKind::Enum => "enum",
             SpaceKind::Trait => "trait",
             SpaceKind::Impl => "impl",
             SpaceKind::Unit => "unit",
             SpaceKind::Namespace => "namespace",
             SpaceKind::Interface => "interface",
+            SpaceKind::TypeAlias => "typealias",
+            SpaceKind::Component => "component",
         };
         write!(f, "{s}")
     }
@@ -76,6 +98,8 @@ pub struct CodeMetrics {
     pub cognitive: cognitive::Stats,
     /// `Cyclomatic` data
     pub cyclomatic: cyclomatic::Stats,
+    /// `Nesting` data
+    pub nesting: nesting::Stats,
     /// `Halstead` data
     pub halstead: halstead::Stats,
     /// `Loc` data
@@ -95,6 +119,30 @@ pub struct CodeMetrics {
     /// `Npa` data
     #[serde(skip_serializing_if = "npa::Stats::is_disabled")]
     pub npa: npa::Stats,
+    /// `Cbo` data
+    #[serde(skip_serializing_if = "cbo::Stats::is_disabled")]
+    pub cbo: cbo::Stats,
+    /// `Lcom` data
+    #[serde(skip_serializing_if = "lcom::Stats::is_disabled")]
+    pub lcom: lcom::Stats,
+    /// `Inheritance` data
+    #[serde(skip_serializing_if = "inheritance::Stats::is_disabled")]
+    pub inheritance: inheritance::Stats,
+    /// `Docs` data
+    #[serde(skip_serializing_if = "docs::Stats::is_disabled")]
+    pub docs: docs::Stats,
+    /// `Types` data
+    #[serde(skip_serializing_if = "types::Stats::is_disabled")]
+    pub types: types::Stats,
+    /// `Jsx` data
+    #[serde(skip_serializing_if = "jsx::Stats::is_disabled")]
+    pub jsx: jsx::Stats,
+    /// `Typing` data
+    #[serde(skip_serializing_if = "typing::Stats::is_disabled")]
+    pub typing: typing::Stats,
+    /// `Crap` data
+    #[serde(skip_serializing_if = "crap::Stats::is_disabled")]
+    pub crap: crap::Stats,
 }
 
 impl fmt::Display for CodeMetrics {
@@ -103,6 +151,7 @@ impl fmt::Display for CodeMetrics {
         writeln!(f, "{}", self.nexits)?;
         writeln!(f, "{}", self.cognitive)?;
         writeln!(f, "{}", self.cyclomatic)?;
+        writeln!(f, "{}", self.nesting)?;
         writeln!(f, "{}", self.halstead)?;
         writeln!(f, "{}", self.loc)?;
         writeln!(f, "{}", self.nom)?;
@@ -114,6 +163,7 @@ impl CodeMetrics {
     pub fn merge(&mut self, other: &CodeMetrics) {
         self.cognitive.merge(&other.cognitive);
         self.cyclomatic.merge(&other.cyclomatic);
+        self.nesting.merge(&other.nesting);
         self.halstead.merge(&other.halstead);
         self.loc.merge(&other.loc);
         self.nom.merge(&other.nom);
@@ -124,9 +174,66 @@ impl CodeMetrics {
         self.wmc.merge(&other.wmc);
         self.npm.merge(&other.npm);
         self.npa.merge(&other.npa);
+        self.cbo.merge(&other.cbo);
+        self.lcom.merge(&other.lcom);
+        self.inheritance.merge(&other.inheritance);
+        self.docs.merge(&other.docs);
+        self.types.merge(&other.types);
+        self.jsx.merge(&other.jsx);
+        self.typing.merge(&other.typing);
+        self.crap.merge(&other.crap);
     }
 }
 
+/// The region of a code spanned by some nodes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct CodeSpan {
+    /// The first line of a span
+    pub start_line: usize,
+    /// The last line of a span
+    pub end_line: usize,
+    /// The first column of a span
+    pub start_column: usize,
+    /// The column following the end of a span
+    pub end_column: usize,
+    /// The first byte of a span
+    pub start_byte: usize,
+    /// The byte following the end of a span
+    pub end_byte: usize,
+}
+
+impl CodeSpan {
+    // Returns the span going from the start of the first node
+    // to the end of the last one
+    pub(crate) fn new(first: &Node, last: &Node) -> Self {
+        let (start_row, start_column) = first.start_position();
+        let (end_row, end_column) = last.end_position();
+        Self {
+            start_line: start_row + 1,
+            end_line: end_row + 1,
+            start_column: start_column + 1,
+            end_column: end_column + 1,
+            start_byte: first.start_byte(),
+            end_byte: last.end_byte(),
+        }
+    }
+}
+
+// Returns the span of the header of a space, such as the signature
+// of a function, and the span of its body.
+// Both are `None` when a space has no body.
+pub(crate) fn get_header_and_body<T: ParserTrait>(
+    node: &Node,
+) -> (Option<CodeSpan>, Option<CodeSpan>) {
+    let Some(body) = T::Getter::get_space_body(node) else {
+        return (None, None);
+    };
+    let header = body
+        .previous_sibling()
+        .map(|last_header_node| CodeSpan::new(node, &last_header_node));
+    (header, Some(CodeSpan::new(&body, &body)))
+}
+
 /// Function space data.
 #[derive(Debug, Clone, Serialize)]
 pub struct FuncSpace {
@@ -135,20 +242,66 @@ pub struct FuncSpace {
     /// If `None`, an error is occurred in parsing
     /// the name of a function space
     pub name: Option<String>,
+    /// The qualified name of a function space
+    ///
+    /// It is made of the names of the package, of the namespaces
+    /// and of the spaces containing a function space, followed by
+    /// its name and, when they are written, by the types of its parameters.
+    /// If `None`, a function space has no qualified name,
+    /// such as a unit without a package
+    pub qualified_name: Option<String>,
+    /// The stable identifier of a function space
+    ///
+    /// It is derived from the qualified name of a function space,
+    /// so it does not change when the space is moved in a file
+    pub id: Option<String>,
     /// The first line of a function space
     pub start_line: usize,
     /// The last line of a function space
     pub end_line: usize,
+    /// The first column of a function space
+    pub start_column: usize,
+    /// The column following the end of a function space
+    pub end_column: usize,
+    /// The first byte of a function space
+    pub start_byte: usize,
+    /// The byte following the end of a function space
+    pub end_byte: usize,
+    /// The span of the header of a function space, such as
+    /// the name and the parameters of a function
+    ///
+    /// If `None`, a function space has no body
+    pub header: Option<CodeSpan>,
+    /// The span of the body of a function space
+    ///
+    /// If `None`, a function space has no body
+    pub body: Option<CodeSpan>,
     /// The space kind
     pub kind: SpaceKind,
+    /// The decorators of a function space, such as the ones
+    /// of a `Python` function or class, without their markers
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<String>,
+    /// The template declaring a function space, such as
+    /// a `C++` template function or class
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<Template>,
+    /// The number of captures listed by a closure, such as a `C++` lambda
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub captures: Option<usize>,
     /// All subspaces contained in a function space
     pub spaces: Vec<FuncSpace>,
-    /// All metrics of a function space
+    /// All metrics of a function space, including the ones
+    /// of its subspaces
     pub metrics: CodeMetrics,
+    /// The metrics of the code of a function space which is not
+    /// contained in its subspaces, such as the top-level code of a module
+    /// or the code of a function without its closures
+    pub exclusive_metrics: CodeMetrics,
 }
 
 impl FuncSpace {
-    fn new<T: Getter>(node: &Node, code: &[u8], kind: SpaceKind) -> Self {
+    fn new<T: ParserTrait>(node: &Node, code: &[u8], kind: SpaceKind) -> Self {
         let (start_position, end_position) = match kind {
             SpaceKind::Unit => {
                 if node.child_count() == 0 {
@@ -160,69 +313,222 @@ impl FuncSpace {
             _ => (node.start_row() + 1, node.end_row() + 1),
         };
 
+        let span = CodeSpan::new(node, node);
+        let (header, body) = get_header_and_body::<T>(node);
+        let qualified_name = get_qualified_name::<T>(node, code);
         Self {
-            name: T::get_func_space_name(node, code)
+            name: T::Getter::get_func_space_name(node, code)
                 .map(|name| name.split_whitespace().collect::<Vec<_>>().join(" ")),
+            id: qualified_name.as_deref().map(get_stable_id),
+            qualified_name,
             spaces: Vec::new(),
             metrics: CodeMetrics::default(),
+            exclusive_metrics: CodeMetrics::default(),
             kind,
+            decorators: T::Getter::get_decorators(node, code),
+            template: T::Getter::get_template(node),
+            captures: T::Getter::get_captures(node),
             start_line: start_position,
             end_line: end_position,
+            start_column: span.start_column,
+            end_column: span.end_column,
+            start_byte: span.start_byte,
+            end_byte: span.end_byte,
+            header,
+            body,
         }
     }
 }
 
+const ANONYMOUS: &str = "<anonymous>";
+
 #[inline(always)]
-fn compute_halstead_mi_and_wmc<T: ParserTrait>(state: &mut State) {
-    state
-        .halstead_maps
-        .finalize(&mut state.space.metrics.halstead);
+fn is_space<T: ParserTrait>(node: &Node) -> bool {
+    T::Checker::is_func(node) || T::Checker::is_func_space(node)
+}
+
+// Returns the name of an anonymous space in a qualified name, numbered
+// after its position among the anonymous spaces directly contained in
+// its enclosing space, such as `<anonymous#2>`, so that they have distinct ids
+fn get_anonymous_name<T: ParserTrait>(node: &Node, code: &[u8]) -> String {
+    let mut scope = node.parent();
+    while let Some(parent) = scope {
+        if is_space::<T>(&parent) {
+            break;
+        }
+        scope = parent.parent();
+    }
+    let Some(scope) = scope else {
+        return "<anonymous#1>".to_string();
+    };
+
+    let mut ordinal = 0;
+    let mut stack: Vec<_> = scope.children().collect();
+    stack.reverse();
+    while let Some(child) = stack.pop() {
+        if is_space::<T>(&child) {
+            if T::Getter::get_qualified_space_name(&child, code)
+                .is_none_or(|name| name == ANONYMOUS)
+            {
+                ordinal += 1;
+            }
+            if child.id() == node.id() {
+                break;
+            }
+            continue;
+        }
+        let mut children: Vec<_> = child.children().collect();
+        children.reverse();
+        stack.extend(children);
+    }
+    format!("<anonymous#{ordinal}>")
+}
+
+// Returns the qualified name of a space: the package of a file, the names of
+// the namespaces and of the spaces containing the space, its name and
+// the types of its parameters.
+pub(crate) fn get_qualified_name<T: ParserTrait>(node: &Node, code: &[u8]) -> Option<String> {
+    let mut names = Vec::new();
+    let mut root = *node;
+    while let Some(parent) = root.parent() {
+        if root.id() != node.id() {
+            if is_space::<T>(&root) {
+                names.push(
+                    T::Getter::get_qualified_space_name(&root, code)
+                        .filter(|name| name != ANONYMOUS)
+                        .unwrap_or_else(|| get_anonymous_name::<T>(&root, code)),
+                );
+            } else if let Some(name) = T::Getter::get_namespace_name(&root, code) {
+                names.push(name.to_string());
+            }
+        }
+        root = parent;
+    }
+    if let Some(package) = T::Getter::get_package_name(&root, code) {
+        names.push(package.to_string());
+    }
+    if root.id() != node.id() {
+        let mut name = T::Getter::get_qualified_space_name(node, code)?;
+        if name == ANONYMOUS {
+            name = get_anonymous_name::<T>(node, code);
+        }
+        if let Some(types) = T::Getter::get_func_signature(node, code) {
+            name = format!("{name}({})", types.join(", "));
+        }
+        names.insert(0, name);
+    }
+    if names.is_empty() {
+        return None;
+    }
+    names.reverse();
+    Some(names.join(T::Getter::get_qualified_name_separator()))
+}
+
+// Returns a stable identifier for a qualified name, computed
+// as its 64-bit FNV-1a hash
+pub(crate) fn get_stable_id(qualified_name: &str) -> String {
+    let mut hasher = Fnv1aHasher::default();
+    hasher.write(qualified_name.as_bytes());
+    format!("{:016x}", hasher.finish())
+}
+
+#[inline(always)]
+fn compute_halstead_mi_and_wmc<T: ParserTrait>(
+    metrics: &mut CodeMetrics,
+    kind: SpaceKind,
+    halstead_maps: &HalsteadMaps,
+) {
+    halstead_maps.finalize(&mut metrics.halstead);
     T::Mi::compute(
-        &state.space.metrics.loc,
-        &state.space.metrics.cyclomatic,
-        &state.space.metrics.halstead,
-        &mut state.space.metrics.mi,
-    );
-    T::Wmc::compute(
-        state.space.kind,
-        &state.space.metrics.cyclomatic,
-        &mut state.space.metrics.wmc,
+        &metrics.loc,
+        &metrics.cyclomatic,
+        &metrics.halstead,
+        &mut metrics.mi,
     );
+    T::Wmc::compute(kind, &metrics.cyclomatic, &mut metrics.wmc);
 }
 
 #[inline(always)]
-fn compute_averages(state: &mut State) {
-    let nom_functions = state.space.metrics.nom.functions_sum() as usize;
-    let nom_closures = state.space.metrics.nom.closures_sum() as usize;
-    let nom_total = state.space.metrics.nom.total() as usize;
+fn compute_averages(metrics: &mut CodeMetrics) {
+    let nom_functions = metrics.nom.functions_sum() as usize;
+    let nom_closures = metrics.nom.closures_sum() as usize;
+    let nom_total = metrics.nom.total() as usize;
     // Cognitive average
-    state.space.metrics.cognitive.finalize(nom_total);
+    metrics.cognitive.finalize(nom_total);
     // Nexit average
-    state.space.metrics.nexits.finalize(nom_total);
+    metrics.nexits.finalize(nom_total);
     // Nargs average
-    state
-        .space
-        .metrics
-        .nargs
-        .finalize(nom_functions, nom_closures);
+    metrics.nargs.finalize(nom_functions, nom_closures);
 }
 
 #[inline(always)]
-fn compute_minmax(state: &mut State) {
-    state.space.metrics.cyclomatic.compute_minmax();
-    state.space.metrics.nexits.compute_minmax();
-    state.space.metrics.cognitive.compute_minmax();
-    state.space.metrics.nargs.compute_minmax();
-    state.space.metrics.nom.compute_minmax();
-    state.space.metrics.loc.compute_minmax();
-    state.space.metrics.abc.compute_minmax();
+fn compute_minmax(metrics: &mut CodeMetrics) {
+    metrics.cyclomatic.compute_minmax();
+    metrics.nesting.compute_minmax();
+    metrics.nexits.compute_minmax();
+    metrics.cognitive.compute_minmax();
+    metrics.nargs.compute_minmax();
+    metrics.nom.compute_minmax();
+    metrics.loc.compute_minmax();
+    metrics.abc.compute_minmax();
+    metrics.cbo.compute_minmax();
+    metrics.lcom.compute_minmax();
 }
 
 #[inline(always)]
-fn compute_sum(state: &mut State) {
-    state.space.metrics.wmc.compute_sum();
-    state.space.metrics.npm.compute_sum();
-    state.space.metrics.npa.compute_sum();
+fn compute_sum(metrics: &mut CodeMetrics) {
+    metrics.wmc.compute_sum();
+    metrics.npm.compute_sum();
+    metrics.npa.compute_sum();
+}
+
+#[inline(always)]
+fn compute_distributions(metrics: &mut CodeMetrics, kind: SpaceKind) {
+    // Only the values of the functions are distributed,
+    // the ones of the other spaces are already aggregates
+    if matches!(kind, SpaceKind::Function | SpaceKind::Component) {
+        metrics.cyclomatic.compute_distribution();
+        metrics.cognitive.compute_distribution();
+        metrics.loc.compute_distribution();
+        metrics.nargs.compute_distribution();
+        metrics.nexits.compute_distribution();
+    }
+}
+
+#[inline(always)]
+fn compute_all<T: ParserTrait>(
+    metrics: &mut CodeMetrics,
+    kind: SpaceKind,
+    halstead_maps: &HalsteadMaps,
+) {
+    compute_minmax(metrics);
+    // The `Wmc` metric needs the kind of a space to compute its sum
+    compute_halstead_mi_and_wmc::<T>(metrics, kind, halstead_maps);
+    compute_sum(metrics);
+    compute_distributions(metrics, kind);
+    compute_averages(metrics);
+}
+
+fn finalize_space<T: ParserTrait>(state: &mut State) {
+    let space = &mut state.space;
+
+    // The exclusive metrics contain only the code of a space,
+    // so they are computed before merging its subspaces,
+    // except for the methods of a class which define its `Wmc`
+    let mut exclusive_metrics = space.metrics.clone();
+    for subspace in &space.spaces {
+        exclusive_metrics.wmc.merge_method(&subspace.metrics.wmc);
+    }
+    compute_all::<T>(&mut exclusive_metrics, space.kind, &state.halstead_maps);
+    space.exclusive_metrics = exclusive_metrics;
+
+    state.halstead_maps.merge(&state.nested_halstead_maps);
+
+    // Merge function spaces
+    for subspace in &space.spaces {
+        space.metrics.merge(&subspace.metrics);
+    }
+    compute_all::<T>(&mut space.metrics, space.kind, &state.halstead_maps);
 }
 
 fn finalize<T: ParserTrait>(state_stack: &mut Vec<State>, diff_level: usize) {
@@ -232,24 +538,19 @@ fn finalize<T: ParserTrait>(state_stack: &mut Vec<State>, diff_level: usize) {
     for _ in 0..diff_level {
         if state_stack.len() == 1 {
             let last_state = state_stack.last_mut().unwrap();
-            compute_minmax(last_state);
-            compute_sum(last_state);
-            compute_halstead_mi_and_wmc::<T>(last_state);
-            compute_averages(last_state);
+            finalize_space::<T>(last_state);
             break;
         } else {
             let mut state = state_stack.pop().unwrap();
-            compute_minmax(&mut state);
-            compute_sum(&mut state);
-            compute_halstead_mi_and_wmc::<T>(&mut state);
-            compute_averages(&mut state);
+            finalize_space::<T>(&mut state);
 
             let last_state = state_stack.last_mut().unwrap();
-            last_state.halstead_maps.merge(&state.halstead_maps);
-            compute_halstead_mi_and_wmc::<T>(last_state);
-
-            // Merge function spaces
-            last_state.space.metrics.merge(&state.space.metrics);
+            last_state.nested_halstead_maps.merge(&state.halstead_maps);
+            last_state
+                .space
+                .metrics
+                .loc
+                .add_nested_lines(&state.space.metrics.loc);
             last_state.space.spaces.push(state.space);
         }
     }
@@ -258,7 +559,11 @@ fn finalize<T: ParserTrait>(state_stack: &mut Vec<State>, diff_level: usize) {
 #[derive(Debug, Clone)]
 struct State<'a> {
     space: FuncSpace,
+    // The operators and the operands of the code of a space
+    // and, once it is finalized, of its subspaces
     halstead_maps: HalsteadMaps<'a>,
+    // The operators and the operands of the subspaces of a space
+    nested_halstead_maps: HalsteadMaps<'a>,
 }
 
 /// Returns all function spaces data of a code. This function needs a parser to
@@ -284,6 +589,179 @@ struct State<'a> {
 /// metrics(&parser, &path).unwrap();
 /// ```
 pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a Path) -> Option<FuncSpace> {
+    metrics_with_options(parser, path, &MetricsOptions::default())
+}
+
+/// Returns all function spaces data of a code, where the comments
+/// containing code are not counted by the `cloc` metric.
+///
+/// The lines of these comments are counted as blank lines.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{metrics_without_commented_code, ParserTrait, PythonParser};
+///
+/// let source_code = "# x = compute(1, 2)\ny = 1\n";
+/// let path = Path::new("foo.py");
+/// let parser = PythonParser::new(source_code.as_bytes().to_vec(), path, None);
+///
+/// let space = metrics_without_commented_code(&parser, path).unwrap();
+/// assert_eq!(space.metrics.loc.cloc(), 0.);
+/// ```
+pub fn metrics_without_commented_code<'a, T: ParserTrait>(
+    parser: &'a T,
+    path: &'a Path,
+) -> Option<FuncSpace> {
+    let options = MetricsOptions {
+        exclude_commented_code: true,
+        ..MetricsOptions::default()
+    };
+    metrics_with_options(parser, path, &options)
+}
+
+/// Options for computing the metrics of a code.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MetricsOptions {
+    /// Whether the comments containing code are counted as blank lines
+    /// instead of comments by the `cloc` metric
+    pub exclude_commented_code: bool,
+    /// Whether the arguments of the macro invocations are re-parsed as code,
+    /// so that the control flow they contain is counted
+    ///
+    /// The arguments which cannot be re-parsed are counted as tokens.
+    pub expand_macros: bool,
+}
+
+/// Returns all function spaces data of a code computed with some options.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{metrics_with_options, MetricsOptions, ParserTrait, RustParser};
+///
+/// let source_code = "fn f(a: bool) { assert!(if a { 1 } else { 2 } > 0); }";
+/// let path = Path::new("foo.rs");
+/// let parser = RustParser::new(source_code.as_bytes().to_vec(), path, None);
+///
+/// let options = MetricsOptions {
+///     expand_macros: true,
+///     ..MetricsOptions::default()
+/// };
+/// let space = metrics_with_options(&parser, path, &options).unwrap();
+/// assert_eq!(space.metrics.cognitive.cognitive_sum(), 2.);
+/// ```
+pub fn metrics_with_options<'a, T: ParserTrait>(
+    parser: &'a T,
+    path: &'a Path,
+    options: &MetricsOptions,
+) -> Option<FuncSpace> {
+    let excluded: Vec<_> = if options.exclude_commented_code {
+        commented_code(parser, path)
+            .comments
+            .iter()
+            .map(|comment| (comment.start_byte, comment.end_byte))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    compute_metrics(parser, path, &excluded, options.expand_macros)
+}
+
+// Computes the metrics describing the control flow of a node,
+// which do not depend on the text of the code.
+#[inline(always)]
+fn compute_control_flow<T: ParserTrait>(
+    node: &Node,
+    metrics: &mut CodeMetrics,
+    nesting_map: &mut HashMap<usize, (usize, usize, usize)>,
+) {
+    T::Cognitive::compute(node, &mut metrics.cognitive, nesting_map);
+    T::Cyclomatic::compute(node, &mut metrics.cyclomatic);
+    T::Nesting::compute(node, &mut metrics.nesting);
+    T::Nom::compute(node, &mut metrics.nom);
+    T::NArgs::compute(node, &mut metrics.nargs);
+    T::Exit::compute(node, &mut metrics.nexits);
+    T::Abc::compute(node, &mut metrics.abc);
+}
+
+// Re-parses the arguments of a macro invocation as code and computes
+// the metrics describing their control flow.
+// Returns `false` if the arguments cannot be re-parsed.
+fn compute_macro_control_flow<T: ParserTrait>(
+    arguments: &Node,
+    code: &[u8],
+    path: &Path,
+    metrics: &mut CodeMetrics,
+    nesting_map: &mut HashMap<usize, (usize, usize, usize)>,
+) -> bool {
+    // The delimiters of the arguments are not re-parsed
+    let (start, end) = (arguments.start_byte() + 1, arguments.end_byte());
+    if start >= end {
+        return false;
+    }
+    let end = end - 1;
+    // The code preceding the arguments is blanked, so that they keep their lines
+    let blanks = code[..start]
+        .iter()
+        .map(|byte| if *byte == b'\n' { b'\n' } else { b' ' });
+    // The nodes wrapping the arguments are nested as the macro invocation
+    let nesting = arguments
+        .parent()
+        .and_then(|parent| nesting_map.get(&parent.id()).copied())
+        .unwrap_or_default();
+
+    for (prefix, suffix) in T::Getter::get_macro_wrappers() {
+        let mut macro_code = prefix.as_bytes().to_vec();
+        macro_code.extend(blanks.clone());
+        macro_code.extend_from_slice(&code[start..end]);
+        macro_code.extend_from_slice(suffix.as_bytes());
+        let parser = T::new(macro_code, path, None);
+        let root = parser.get_root();
+        if root.has_error() {
+            continue;
+        }
+
+        let (start, end) = (start + prefix.len(), end + prefix.len());
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            let mut expanded = None;
+            if node.start_byte() >= start && node.end_byte() <= end {
+                compute_control_flow::<T>(&node, metrics, nesting_map);
+                expanded = T::Getter::get_macro_arguments(&node).filter(|arguments| {
+                    compute_macro_control_flow::<T>(
+                        arguments,
+                        parser.get_code(),
+                        path,
+                        metrics,
+                        nesting_map,
+                    )
+                });
+            } else {
+                nesting_map.insert(node.id(), nesting);
+            }
+            stack.extend(
+                node.children()
+                    .filter(|child| Some(child.id()) != expanded.map(|arguments| arguments.id())),
+            );
+        }
+        return true;
+    }
+    false
+}
+
+// Computes the metrics of a code, where the nodes contained in the `excluded`
+// byte ranges are not counted by the `Loc` metric.
+fn compute_metrics<'a, T: ParserTrait>(
+    parser: &'a T,
+    path: &'a Path,
+    excluded: &[(usize, usize)],
+    expand_macros: bool,
+) -> Option<FuncSpace> {
     let code = parser.get_code();
     let node = parser.get_root();
     let mut cursor = node.cursor();
@@ -295,23 +773,26 @@ pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a Path) -> Option<Func
     // Three type of nesting info: conditionals, functions and lambdas
     let mut nesting_map = HashMap::<usize, (usize, usize, usize)>::default();
     nesting_map.insert(node.id(), (0, 0, 0));
-    stack.push((node, 0));
+    // The nodes contained in the re-parsed arguments of a macro invocation
+    // are expanded: their control flow has already been computed
+    stack.push((node, 0, false));
 
-    while let Some((node, level)) = stack.pop() {
+    while let Some((node, level, expanded)) = stack.pop() {
         if level < last_level {
             finalize::<T>(&mut state_stack, last_level - level);
             last_level = level;
         }
 
-        let kind = T::Getter::get_space_kind(&node);
+        let kind = T::Getter::get_space_kind(&node, code);
 
         let func_space = T::Checker::is_func(&node) || T::Checker::is_func_space(&node);
         let unit = kind == SpaceKind::Unit;
 
         let new_level = if func_space {
             let state = State {
-                space: FuncSpace::new::<T::Getter>(&node, code, kind),
+                space: FuncSpace::new::<T>(&node, code, kind),
                 halstead_maps: HalsteadMaps::new(),
+                nested_halstead_maps: HalsteadMaps::new(),
             };
             state_stack.push(state);
             last_level = level + 1;
@@ -320,24 +801,48 @@ pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a Path) -> Option<Func
             level
         };
 
+        let mut expanded_arguments = None;
         if let Some(state) = state_stack.last_mut() {
             let last = &mut state.space;
-            T::Cognitive::compute(&node, &mut last.metrics.cognitive, &mut nesting_map);
-            T::Cyclomatic::compute(&node, &mut last.metrics.cyclomatic);
+            if !expanded {
+                compute_control_flow::<T>(&node, &mut last.metrics, &mut nesting_map);
+                if expand_macros {
+                    expanded_arguments =
+                        T::Getter::get_macro_arguments(&node).filter(|arguments| {
+                            compute_macro_control_flow::<T>(
+                                arguments,
+                                code,
+                                path,
+                                &mut last.metrics,
+                                &mut nesting_map,
+                            )
+                        });
+                }
+            }
             T::Halstead::compute(&node, code, &mut state.halstead_maps);
-            T::Loc::compute(&node, &mut last.metrics.loc, func_space, unit);
-            T::Nom::compute(&node, &mut last.metrics.nom);
-            T::NArgs::compute(&node, &mut last.metrics.nargs);
-            T::Exit::compute(&node, &mut last.metrics.nexits);
-            T::Abc::compute(&node, &mut last.metrics.abc);
+            if !excluded
+                .iter()
+                .any(|(start, end)| (*start..*end).contains(&node.start_byte()))
+            {
+                T::Loc::compute(&node, &mut last.metrics.loc, func_space, unit);
+            }
             T::Npm::compute(&node, &mut last.metrics.npm);
             T::Npa::compute(&node, &mut last.metrics.npa);
+            T::Cbo::compute(&node, code, &mut last.metrics.cbo);
+            T::Lcom::compute(&node, code, &mut last.metrics.lcom);
+            T::Docs::compute(&node, code, &mut last.metrics.docs);
+            T::Types::compute(&node, &mut last.metrics.types);
+            T::Jsx::compute(&node, code, &mut last.metrics.jsx);
+            T::Typing::compute(&node, code, &mut last.metrics.typing);
         }
 
         cursor.reset(&node);
         if cursor.goto_first_child() {
             loop {
-                children.push((cursor.node(), new_level));
+                let child = cursor.node();
+                let expanded = expanded
+                    || expanded_arguments.is_some_and(|arguments| arguments.id() == child.id());
+                children.push((child, new_level, expanded));
                 if !cursor.goto_next_sibling() {
                     break;
                 }
@@ -383,7 +888,33 @@ impl Callback for Metrics {
 
 #[cfg(test)]
 mod tests {
-    use crate::{check_func_space, CppParser};
+    use std::path::Path;
+
+    use crate::getter::Getter;
+    use crate::traits::{ParserTrait, Search};
+    use crate::{
+        check_func_space, metrics_with_options, CParser, CodeSpan, CppParser, FuncSpace,
+        JavaParser, JavascriptParser, KotlinParser, MetricsOptions, Python, PythonCode,
+        PythonParser, RustParser, SpaceKind, Template, TsxParser, TypescriptParser,
+    };
+
+    use super::get_stable_id;
+
+    fn names(space: &FuncSpace, names: &mut Vec<String>) {
+        for subspace in &space.spaces {
+            names.push(subspace.name.clone().unwrap_or_default());
+            self::names(subspace, names);
+        }
+    }
+
+    fn qualified_names(space: &FuncSpace, names: &mut Vec<String>) {
+        if let Some(name) = &space.qualified_name {
+            names.push(name.clone());
+        }
+        for subspace in &space.spaces {
+            qualified_names(subspace, names);
+        }
+    }
 
     #[test]
     fn c_scope_resolution_operator() {
@@ -400,4 +931,711 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn rust_qualified_names() {
+        check_func_space::<RustParser, _>(
+            "mod m {
+                impl<T> A<T> {
+                    fn new(&self, x: u32, mut y: &str) {}
+                }
+                impl T for A {
+                    fn t(self: Box<Self>) {}
+                }
+            }",
+            "foo.rs",
+            |func_space| {
+                let mut names = Vec::new();
+                qualified_names(&func_space, &mut names);
+                assert_eq!(
+                    names,
+                    [
+                        "m",
+                        "m::<impl A<T>>",
+                        "m::<impl A<T>>::new(&self, u32, &str)",
+                        "m::<A as T>",
+                        "m::<A as T>::t(Box<Self>)",
+                    ]
+                );
+                assert_eq!(func_space.qualified_name, None);
+                assert_eq!(func_space.id, None);
+            },
+        );
+    }
+
+    #[test]
+    fn rust_anonymous_closures_ids() {
+        check_func_space::<RustParser, _>(
+            "fn main() {
+                let a: Vec<_> = v.iter().map(|x| x + 1).collect();
+                let b: Vec<_> = v.iter().map(|x| x * 2).collect();
+            }",
+            "foo.rs",
+            |func_space| {
+                let mut names = Vec::new();
+                qualified_names(&func_space, &mut names);
+                assert_eq!(
+                    names,
+                    ["main()", "main::<anonymous#1>", "main::<anonymous#2>"]
+                );
+                let closures = &func_space.spaces[0].spaces;
+                assert_eq!(closures[0].name.as_deref(), Some("<anonymous>"));
+                assert_ne!(closures[0].id, closures[1].id);
+            },
+        );
+    }
+
+    #[test]
+    fn rust_struct_and_impl_ids() {
+        check_func_space::<RustParser, _>(
+            "struct Foo {
+                x: u32,
+            }
+            impl Foo {
+                fn x(&self) -> u32 {
+                    self.x
+                }
+            }",
+            "foo.rs",
+            |func_space| {
+                let (foo, foo_impl) = (&func_space.spaces[0], &func_space.spaces[1]);
+                assert_eq!(foo.qualified_name.as_deref(), Some("Foo"));
+                assert_eq!(foo_impl.qualified_name.as_deref(), Some("<impl Foo>"));
+                assert!(foo.id.is_some());
+                assert_ne!(foo.id, foo_impl.id);
+            },
+        );
+    }
+
+    #[test]
+    fn java_overloads_ids() {
+        check_func_space::<JavaParser, _>(
+            "package a.b;
+            class A {
+                void f(int x) {}
+                void f(final String... y) {}
+            }",
+            "foo.java",
+            |func_space| {
+                let mut names = Vec::new();
+                qualified_names(&func_space, &mut names);
+                assert_eq!(
+                    names,
+                    ["a.b", "a.b.A", "a.b.A.f(int)", "a.b.A.f(String...)"]
+                );
+
+                let methods = &func_space.spaces[0].spaces;
+                assert_ne!(methods[0].id, methods[1].id);
+                assert_eq!(
+                    methods[0].id.as_deref(),
+                    Some(get_stable_id("a.b.A.f(int)").as_str())
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_qualified_names() {
+        check_func_space::<TypescriptParser, _>(
+            "namespace N {
+                export class A {
+                    f(a: number, b?: string, ...c: string[]): void {}
+                    g(x, { y }, ...z) {}
+                }
+            }",
+            "foo.ts",
+            |func_space| {
+                let mut names = Vec::new();
+                qualified_names(&func_space, &mut names);
+                assert_eq!(
+                    names,
+                    [
+                        "N",
+                        "N.A",
+                        "N.A.f(number, string, ...string[])",
+                        "N.A.g(x, _, ...z)"
+                    ]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_item_spaces() {
+        check_func_space::<TypescriptParser, _>(
+            "declare module \"m\";
+            namespace N {
+                abstract class A {
+                    abstract f(): void;
+                }
+                enum E {
+                    X,
+                    Y = 2,
+                }
+                type T<U> = U extends string ? U : never;
+            }",
+            "foo.ts",
+            |func_space| {
+                let n = &func_space.spaces[0];
+                assert_eq!(func_space.spaces.len(), 1);
+                assert_eq!(n.kind, SpaceKind::Namespace);
+                assert_eq!(n.name.as_deref(), Some("N"));
+                let spaces: Vec<_> = n
+                    .spaces
+                    .iter()
+                    .map(|space| (space.kind, space.qualified_name.as_deref().unwrap()))
+                    .collect();
+                assert_eq!(
+                    spaces,
+                    [
+                        (SpaceKind::Class, "N.A"),
+                        (SpaceKind::Enum, "N.E"),
+                        (SpaceKind::TypeAlias, "N.T"),
+                    ]
+                );
+                assert_eq!(n.spaces[2].metrics.types.conditional(), 1.);
+                assert_eq!(func_space.metrics.types.conditional(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn python_header_and_body() {
+        check_func_space::<PythonParser, _>(
+            "def f(a,
+      b):
+    return a
+",
+            "foo.py",
+            |func_space| {
+                assert_eq!(func_space.header, None);
+                assert_eq!(func_space.body, None);
+
+                let f = &func_space.spaces[0];
+                assert_eq!((f.start_line, f.end_line), (1, 3));
+                assert_eq!((f.start_column, f.end_column), (1, 13));
+                assert_eq!((f.start_byte, f.end_byte), (0, 31));
+                assert_eq!(
+                    f.header,
+                    Some(CodeSpan {
+                        start_line: 1,
+                        end_line: 2,
+                        start_column: 1,
+                        end_column: 10,
+                        start_byte: 0,
+                        end_byte: 18,
+                    })
+                );
+                assert_eq!(
+                    f.body,
+                    Some(CodeSpan {
+                        start_line: 3,
+                        end_line: 3,
+                        start_column: 5,
+                        end_column: 13,
+                        start_byte: 23,
+                        end_byte: 31,
+                    })
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_header_and_body() {
+        check_func_space::<RustParser, _>(
+            "fn f() -> u32 {
+    1
+}",
+            "foo.rs",
+            |func_space| {
+                let f = &func_space.spaces[0];
+                assert_eq!((f.start_column, f.end_column), (1, 2));
+                let header = f.header.unwrap();
+                assert_eq!((header.start_byte, header.end_byte), (0, 13));
+                assert_eq!((header.end_line, header.end_column), (1, 14));
+                let body = f.body.unwrap();
+                assert_eq!((body.start_byte, body.end_byte), (14, 23));
+                assert_eq!((body.start_line, body.end_line), (1, 3));
+            },
+        );
+    }
+
+    #[test]
+    fn python_exclusive_metrics() {
+        check_func_space::<PythonParser, _>(
+            "x = 1
+if x:
+    x = 2
+def f(a):
+    if a:
+        return 1
+    return 2
+",
+            "foo.py",
+            |func_space| {
+                let inclusive = &func_space.metrics;
+                let exclusive = &func_space.exclusive_metrics;
+                assert_eq!(inclusive.cyclomatic.cyclomatic_sum(), 4.);
+                assert_eq!(exclusive.cyclomatic.cyclomatic_sum(), 2.);
+                assert_eq!(inclusive.nom.functions_sum(), 1.);
+                assert_eq!(exclusive.nom.functions_sum(), 0.);
+                assert_eq!(inclusive.nexits.exit_sum(), 2.);
+                assert_eq!(exclusive.nexits.exit_sum(), 0.);
+                assert_eq!(inclusive.loc.ploc(), 7.);
+                assert_eq!(exclusive.loc.ploc(), 3.);
+
+                let f = &func_space.spaces[0];
+                assert_eq!(f.exclusive_metrics.cyclomatic.cyclomatic_sum(), 2.);
+                assert_eq!(f.exclusive_metrics.nom.functions_sum(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn java_exclusive_wmc() {
+        check_func_space::<JavaParser, _>(
+            "class A {
+                int f(int a) {
+                    return a > 0 ? a : 0;
+                }
+                void g() {}
+                class B {
+                    void h(boolean b) {
+                        if (b) {}
+                    }
+                }
+            }",
+            "foo.java",
+            |func_space| {
+                let a = &func_space.spaces[0];
+                // The methods of `A` only, without the ones of `B`
+                assert_eq!(a.exclusive_metrics.wmc.class_wmc_sum(), 3.);
+                assert_eq!(a.metrics.wmc.class_wmc_sum(), 5.);
+                assert_eq!(func_space.exclusive_metrics.wmc.class_wmc_sum(), 0.);
+                assert_eq!(func_space.metrics.wmc.class_wmc_sum(), 5.);
+            },
+        );
+    }
+
+    #[test]
+    fn rust_item_spaces() {
+        check_func_space::<RustParser, _>(
+            "mod a;
+            mod m {
+                struct S {
+                    x: u32,
+                }
+                enum E {
+                    A,
+                    B(u32),
+                }
+                fn f() {}
+            }",
+            "foo.rs",
+            |func_space| {
+                let m = &func_space.spaces[0];
+                assert_eq!(func_space.spaces.len(), 1);
+                assert_eq!(m.kind, SpaceKind::Namespace);
+                assert_eq!(m.name.as_deref(), Some("m"));
+                assert_eq!((m.start_line, m.end_line), (2, 11));
+                let spaces: Vec<_> = m
+                    .spaces
+                    .iter()
+                    .map(|space| (space.kind, space.qualified_name.as_deref().unwrap()))
+                    .collect();
+                assert_eq!(
+                    spaces,
+                    [
+                        (SpaceKind::Struct, "m::S"),
+                        (SpaceKind::Enum, "m::E"),
+                        (SpaceKind::Function, "m::f()"),
+                    ]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_expand_macros() {
+        let source = "fn f(a: bool, b: bool) {
+            assert!(if a { 1 } else { 2 } > 0);
+            quote! {
+                if a && b {
+                    match a {
+                        true => 1,
+                        _ => 2,
+                    }
+                }
+            };
+            select! { x = y => {} }
+        }";
+        let path = Path::new("foo.rs");
+        let parser = RustParser::new(source.as_bytes().to_vec(), path, None);
+        let compute = |expand_macros| {
+            let options = MetricsOptions {
+                expand_macros,
+                ..MetricsOptions::default()
+            };
+            metrics_with_options(&parser, path, &options)
+                .unwrap()
+                .spaces[0]
+                .metrics
+                .clone()
+        };
+
+        // The `if` and `&&` tokens are counted, but not the arms of a `match`
+        let tokens = compute(false);
+        assert_eq!(tokens.cyclomatic.cyclomatic(), 4.);
+        assert_eq!(tokens.cognitive.cognitive(), 0.);
+
+        // `select!` cannot be re-parsed, so it is counted as tokens
+        let expanded = compute(true);
+        assert_eq!(expanded.cyclomatic.cyclomatic(), 6.);
+        assert_eq!(expanded.cognitive.cognitive(), 6.);
+        assert_eq!(expanded.nesting.nesting(), 2.);
+        assert_eq!(expanded.loc.sloc(), tokens.loc.sloc());
+        assert_eq!(expanded.halstead.operands(), tokens.halstead.operands());
+    }
+
+    #[test]
+    fn rust_closure_exclusive_metrics() {
+        check_func_space::<RustParser, _>(
+            "fn f(a: bool) -> u32 {
+    let g = |x: bool| if x { 1 } else { 2 };
+    g(a)
+}",
+            "foo.rs",
+            |func_space| {
+                let f = &func_space.spaces[0];
+                assert_eq!(f.metrics.cyclomatic.cyclomatic_sum(), 3.);
+                assert_eq!(f.exclusive_metrics.cyclomatic.cyclomatic_sum(), 1.);
+                assert_eq!(f.metrics.nom.closures_sum(), 1.);
+                assert_eq!(f.exclusive_metrics.nom.closures_sum(), 0.);
+            },
+        );
+    }
+
+    #[test]
+    fn stable_id() {
+        assert_eq!(get_stable_id(""), "cbf29ce484222325");
+        assert_eq!(get_stable_id("foo"), "dcb27518fed9d577");
+    }
+
+    #[test]
+    fn tsx_anonymous_names() {
+        check_func_space::<TsxParser, _>(
+            "const Button = ({ label }) => {
+                const onClick = () => {};
+                return <button onClick={() => {}}>{label}</button>;
+            };
+            obj.foo = function() {};
+            const handlers = { onHover: () => {}, \"on-leave\": function() {} };
+            class Form {
+                submit = async () => {};
+            }",
+            "foo.tsx",
+            |func_space| {
+                let mut found = Vec::new();
+                names(&func_space, &mut found);
+                assert_eq!(
+                    found,
+                    [
+                        "Button",
+                        "onClick",
+                        "<anonymous>",
+                        "obj.foo",
+                        "onHover",
+                        "on-leave",
+                        "Form",
+                        "submit"
+                    ]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_anonymous_names() {
+        check_func_space::<JavascriptParser, _>(
+            "var f = function() {};
+            module.exports.g = () => {};
+            class A {
+                h = function() {};
+            }",
+            "foo.js",
+            |func_space| {
+                let mut found = Vec::new();
+                names(&func_space, &mut found);
+                assert_eq!(found, ["f", "module.exports.g", "A", "h"]);
+            },
+        );
+    }
+
+    #[test]
+    fn rust_closure_names() {
+        check_func_space::<RustParser, _>(
+            "fn main() {
+                let f = |x: u32| x;
+                let mut g = move || {};
+                g = || {};
+                call(|a| a);
+            }",
+            "foo.rs",
+            |func_space| {
+                let mut found = Vec::new();
+                names(&func_space, &mut found);
+                assert_eq!(found, ["main", "f", "g", "g", "<anonymous>"]);
+            },
+        );
+    }
+
+    #[test]
+    fn python_lambda_names() {
+        let source = "f = lambda x: x\ng = sorted(l, key=lambda x: x)\n";
+        let path = Path::new("foo.py");
+        let parser = PythonParser::new(source.as_bytes().to_vec(), path, None);
+        let root = parser.get_root();
+        let lambdas: Vec<_> = root
+            .first_occurrence(|id| id == Python::Assignment)
+            .into_iter()
+            .chain(root.children().nth(1))
+            .filter_map(|node| node.first_occurrence(|id| id == Python::Lambda))
+            .map(|lambda| PythonCode::get_func_space_name(&lambda, source.as_bytes()))
+            .collect();
+        assert_eq!(lambdas, [Some("f"), Some("<anonymous>")]);
+    }
+
+    #[test]
+    fn java_item_spaces() {
+        check_func_space::<JavaParser, _>(
+            "enum E {
+                A, B;
+                int f() { return 0; }
+            }
+            record R(int x) {
+                R {
+                    if (x < 0) throw new IllegalArgumentException();
+                }
+            }
+            @interface N {
+                String value();
+            }
+            class C {
+                Runnable r = new Runnable() {
+                    public void run() {}
+                };
+            }",
+            "foo.java",
+            |func_space| {
+                let spaces: Vec<_> = func_space
+                    .spaces
+                    .iter()
+                    .map(|space| (space.kind, space.qualified_name.as_deref().unwrap()))
+                    .collect();
+                assert_eq!(
+                    spaces,
+                    [
+                        (SpaceKind::Enum, "E"),
+                        (SpaceKind::Class, "R"),
+                        (SpaceKind::Interface, "N"),
+                        (SpaceKind::Class, "C"),
+                    ]
+                );
+                let constructor = &func_space.spaces[1].spaces[0];
+                assert_eq!(constructor.kind, SpaceKind::Function);
+                assert_eq!(constructor.metrics.cyclomatic.cyclomatic(), 2.);
+                let anonymous = &func_space.spaces[3].spaces[0];
+                assert_eq!(anonymous.kind, SpaceKind::Class);
+                assert_eq!(anonymous.spaces[0].name.as_deref(), Some("run"));
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_component_spaces() {
+        check_func_space::<JavascriptParser, _>(
+            "function List({ items }) {
+                return <ul>{items.map(item => <li>{item}</li>)}</ul>;
+            }
+            const Empty = () => null;
+            function render(root) {
+                root.render(<List items={[]} />);
+            }",
+            "foo.js",
+            |func_space| {
+                let spaces: Vec<_> = func_space
+                    .spaces
+                    .iter()
+                    .map(|space| (space.kind, space.name.as_deref().unwrap()))
+                    .collect();
+                assert_eq!(
+                    spaces,
+                    [
+                        (SpaceKind::Component, "List"),
+                        (SpaceKind::Function, "Empty"),
+                        (SpaceKind::Function, "render"),
+                    ]
+                );
+                assert_eq!(func_space.spaces[0].spaces[0].kind, SpaceKind::Function);
+                assert_eq!(func_space.metrics.jsx.elements(), 3.);
+                assert_eq!(func_space.spaces[0].metrics.jsx.branches(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn python_decorators_and_lambdas() {
+        check_func_space::<PythonParser, _>(
+            "@dataclass(frozen=True)
+class A:
+    @property
+    @cache
+    def f(self):
+        return sorted(self.x, key=lambda x: -x)",
+            "foo.py",
+            |func_space| {
+                let a = &func_space.spaces[0];
+                assert_eq!(a.decorators, ["dataclass(frozen=True)"]);
+                let f = &a.spaces[0];
+                assert_eq!(f.decorators, ["property", "cache"]);
+                assert_eq!(f.spaces[0].kind, SpaceKind::Function);
+                assert_eq!(f.spaces[0].name.as_deref(), Some("<anonymous>"));
+                assert_eq!(f.metrics.nom.closures_sum(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_templates_and_lambdas() {
+        check_func_space::<CppParser, _>(
+            "template <typename T, int N>
+class A {};
+template <>
+class A<int, 1> {};
+template <typename T>
+void B<T>::f() {}
+template <>
+void g<int>(int x) {}
+void h() {
+    auto l = [=, &x, y = 2](int a) { return a + x + y; };
+    std::sort(v.begin(), v.end(), [](int a, int b) { return a < b; });
+}",
+            "foo.cpp",
+            |func_space| {
+                let template = |parameters, specialization| {
+                    Some(Template {
+                        parameters,
+                        specialization,
+                    })
+                };
+                let spaces = &func_space.spaces;
+                assert_eq!(spaces[0].template, template(2, false));
+                assert_eq!(spaces[1].template, template(0, true));
+                assert_eq!(spaces[2].template, template(1, false));
+                assert_eq!(spaces[3].template, template(0, true));
+                let h = &spaces[4];
+                assert_eq!(h.template, None);
+                assert_eq!(h.captures, None);
+                assert_eq!(h.spaces.len(), 2);
+                assert_eq!(h.spaces[0].kind, SpaceKind::Function);
+                assert_eq!(h.spaces[0].name.as_deref(), Some("l"));
+                assert_eq!(h.spaces[0].captures, Some(3));
+                assert_eq!(h.spaces[1].name.as_deref(), Some("<anonymous>"));
+                assert_eq!(h.spaces[1].captures, Some(0));
+            },
+        );
+    }
+
+    #[test]
+    fn c_spaces() {
+        check_func_space::<CParser, _>(
+            "struct point { int x, y; };
+struct point origin;
+static int *new(int class, int template) {
+    return class > template ? &class : &template;
+}
+int main(void) { return 0; }",
+            "foo.c",
+            |func_space| {
+                assert_eq!(func_space.kind, SpaceKind::Unit);
+                let spaces = &func_space.spaces;
+                assert_eq!(spaces.len(), 3);
+                assert_eq!(spaces[0].kind, SpaceKind::Struct);
+                assert_eq!(spaces[0].name.as_deref(), Some("point"));
+                assert_eq!(spaces[1].kind, SpaceKind::Function);
+                assert_eq!(spaces[1].name.as_deref(), Some("new"));
+                assert_eq!(spaces[1].metrics.nargs.fn_args(), 2.);
+                assert_eq!(spaces[1].metrics.cyclomatic.cyclomatic(), 2.);
+                assert_eq!(spaces[2].name.as_deref(), Some("main"));
+                assert_eq!(spaces[2].metrics.nargs.fn_args(), 0.);
+            },
+        );
+    }
+
+    #[test]
+    fn kotlin_space_kinds() {
+        check_func_space::<KotlinParser, _>(
+            "interface I
+class A(val x: Int) {
+    var p: Int = 0
+        set(value) { field = value }
+    constructor() : this(1)
+    companion object Factory {}
+}
+object O
+enum class E { X }
+fun f() {}
+class C {
+    companion object {}
+}",
+            "foo.kt",
+            |func_space| {
+                assert_eq!(func_space.kind, SpaceKind::Unit);
+                let spaces = &func_space.spaces;
+                assert_eq!(spaces[0].kind, SpaceKind::Interface);
+                assert_eq!(spaces[1].kind, SpaceKind::Class);
+                let members = &spaces[1].spaces;
+                assert_eq!(members[0].kind, SpaceKind::Function);
+                assert_eq!(members[0].name.as_deref(), Some("p"));
+                assert_eq!(members[1].name.as_deref(), Some("A"));
+                assert_eq!(members[2].kind, SpaceKind::Class);
+                assert_eq!(members[2].name.as_deref(), Some("Factory"));
+                assert_eq!(spaces[2].kind, SpaceKind::Class);
+                assert_eq!(spaces[3].kind, SpaceKind::Enum);
+                assert_eq!(spaces[4].kind, SpaceKind::Function);
+                assert_eq!(spaces[4].name.as_deref(), Some("f"));
+                let companion = &spaces[5].spaces[0];
+                assert_eq!(companion.kind, SpaceKind::Class);
+                assert_eq!(companion.name.as_deref(), Some("Companion"));
+                assert_eq!(companion.qualified_name.as_deref(), Some("C.Companion"));
+            },
+        );
+    }
+
+    #[test]
+    fn python_typing() {
+        check_func_space::<PythonParser, _>(
+            "TIMEOUT: int = 10
+class A:
+    x = 1
+    def f(self, a: int) -> int:
+        return a
+def g(a, b):
+    return a",
+            "foo.py",
+            |func_space| {
+                let a = &func_space.spaces[0];
+                assert_eq!(a.metrics.typing.parameters(), 1.);
+                assert_eq!(a.metrics.typing.attributes(), 1.);
+                assert_eq!(a.metrics.typing.coverage(), 2. / 3.);
+                assert_eq!(a.spaces[0].metrics.typing.annotated_returns(), 1.);
+                let g = &func_space.spaces[1];
+                assert_eq!(g.metrics.typing.coverage(), 0.);
+                assert_eq!(func_space.metrics.typing.parameters(), 3.);
+                assert_eq!(func_space.metrics.typing.annotated_attributes(), 1.);
+                assert_eq!(func_space.metrics.typing.coverage(), 3. / 7.);
+            },
+        );
+    }
 }
@@ -138,10 +138,14 @@ static RE1_EMACS: OnceLock<Regex> = OnceLock::new();
 static RE2_EMACS: OnceLock<Regex> = OnceLock::new();
 static RE1_VIM: OnceLock<Regex> = OnceLock::new();
 
+// constructs which only exist in C++ headers
+static RE_CPP_HEADER: OnceLock<Regex> = OnceLock::new();
+
 // Regular expressions
 const FIRST_EMACS_EXPRESSION: &str = r"(?i)-\*-.*[^-\w]mode\s*:\s*([^:;\s]+)";
 const SECOND_EMACS_EXPRESSION: &str = r"-\*-\s*([^:;\s]+)\s*-\*-";
 const VIM_EXPRESSION: &str = r"(?i)vim\s*:.*[^\w]ft\s*=\s*([^:\s]+)";
+const CPP_HEADER_EXPRESSION: &str = r"(?m)^\s*(class\s+\w+\s*[:{;]|namespace\b|template\s*<|using\s|(public|protected|private)\s*:|#\s*include\s*<\w+>)|\w::~?\w";
 
 #[inline(always)]
 fn get_regex<'a>(
@@ -182,6 +186,16 @@ fn get_emacs_mode(buf: &[u8]) -> Option<String> {
     None
 }
 
+// A `.h` header is shared by `C` and `C++`, so it is a `C` header
+// unless it contains some `C++` constructs
+fn get_header_language(buf: &[u8]) -> LANG {
+    if get_regex(&RE_CPP_HEADER, buf, CPP_HEADER_EXPRESSION).is_some() {
+        LANG::Cpp
+    } else {
+        LANG::C
+    }
+}
+
 /// Guesses the language of a code.
 ///
 /// Returns a tuple containing a [`LANG`] as first argument
@@ -218,6 +232,13 @@ pub fn guess_language<'a, P: AsRef<Path>>(buf: &[u8], path: P) -> (Option<LANG>,
 
     let from_mode = get_from_emacs_mode(&mode);
 
+    // The mode of a `C` or `C++` file selects its dialect
+    let from_ext = match (from_ext, from_mode) {
+        (Some(LANG::C | LANG::Cpp), Some(lang_mode @ (LANG::C | LANG::Cpp))) => Some(lang_mode),
+        (Some(LANG::Cpp), None) if ext == "h" => Some(get_header_language(buf)),
+        _ => from_ext,
+    };
+
     if let Some(lang_ext) = from_ext {
         if let Some(lang_mode) = from_mode {
             if lang_ext == lang_mode {
@@ -297,6 +318,29 @@ pub(crate) fn get_paths_dist(path1: &Path, path2: &Path) -> Option<usize> {
     None
 }
 
+// A 64-bit FNV-1a hasher: unlike `DefaultHasher`, its output
+// does not change between runs and Rust releases
+#[derive(Debug)]
+pub(crate) struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl std::hash::Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
 pub(crate) fn guess_file<S: ::std::hash::BuildHasher>(
     current_path: &Path,
     include_path: &str,
@@ -457,4 +501,29 @@ mod tests {
             (Some(LANG::Cpp), "obj-c/c++")
         );
     }
+
+    #[test]
+    fn test_guess_header_language() {
+        let buf = b"#ifndef FOO_H\n#define FOO_H\n#include <stdio.h>\nstruct foo { int a; };\nint f(struct foo *);\n#endif\n";
+        assert_eq!(guess_language(buf, "foo.h"), (Some(LANG::C), "c"));
+
+        let buf = b"#include <vector>\nint f(std::vector<int> v);\n";
+        assert_eq!(guess_language(buf, "foo.h"), (Some(LANG::Cpp), "c/c++"));
+
+        let buf = b"namespace foo {\nint f();\n}\n";
+        assert_eq!(guess_language(buf, "foo.h"), (Some(LANG::Cpp), "c/c++"));
+
+        let buf = b"class A : public B {\n  public:\n    A();\n};\n";
+        assert_eq!(guess_language(buf, "foo.h"), (Some(LANG::Cpp), "c/c++"));
+
+        let buf = b"// -*- mode: c++ -*-\nint f(void);\n";
+        assert_eq!(guess_language(buf, "foo.h"), (Some(LANG::Cpp), "c/c++"));
+
+        let buf = b"/* vim: set ft=c: */\nint f(void);\n";
+        assert_eq!(guess_language(buf, "foo.c"), (Some(LANG::C), "c"));
+
+        let buf = b"int f(void);\n";
+        assert_eq!(guess_language(buf, "foo.c"), (Some(LANG::C), "c"));
+        assert_eq!(guess_language(buf, "foo.hpp"), (Some(LANG::Cpp), "c/c++"));
+    }
 }
@@ -3,22 +3,29 @@ use std::sync::Arc;
 
 use crate::abc::Abc;
 use crate::alterator::Alterator;
+use crate::cbo::Cbo;
 use crate::checker::Checker;
 use crate::cognitive::Cognitive;
 use crate::cyclomatic::Cyclomatic;
+use crate::docs::Docs;
 use crate::exit::Exit;
 use crate::getter::Getter;
 use crate::halstead::Halstead;
+use crate::jsx::Jsx;
 use crate::langs::*;
+use crate::lcom::Lcom;
 use crate::loc::Loc;
 use crate::mi::Mi;
 use crate::nargs::NArgs;
+use crate::nesting::Nesting;
 use crate::node::Node;
 use crate::nom::Nom;
 use crate::npa::Npa;
 use crate::npm::Npm;
 use crate::parser::Filter;
 use crate::preproc::PreprocResults;
+use crate::types::Types;
+use crate::typing::Typing;
 use crate::wmc::Wmc;
 
 /// A trait for callback functions.
@@ -53,11 +60,18 @@ pub trait ParserTrait {
     type Nom: Nom;
     type Mi: Mi;
     type NArgs: NArgs;
+    type Nesting: Nesting;
     type Exit: Exit;
     type Wmc: Wmc;
     type Abc: Abc;
     type Npm: Npm;
     type Npa: Npa;
+    type Cbo: Cbo;
+    type Lcom: Lcom;
+    type Docs: Docs;
+    type Types: Types;
+    type Jsx: Jsx;
+    type Typing: Typing;
 
     fn new(code: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>) -> Self;
     fn get_language(&self) -> LANG;
@@ -69,6 +83,7 @@ pub trait ParserTrait {
 pub(crate) trait Search<'a> {
     fn first_occurrence(&self, pred: fn(u16) -> bool) -> Option<Node<'a>>;
     fn act_on_node(&self, pred: &mut dyn FnMut(&Node<'a>));
+    fn act_on_node_pruned(&self, pred: &mut dyn FnMut(&Node<'a>) -> bool);
     fn first_child(&self, pred: fn(u16) -> bool) -> Option<Node<'a>>;
     fn act_on_child(&self, action: &mut dyn FnMut(&Node<'a>));
 }